@@ -2,6 +2,7 @@ use crate::*;
 use bevy::prelude::*;
 use konstruo_distribution::{Distribution, FlexBuilder};
 use konstruo_ui::EntityState;
+use serde::{Deserialize, Serialize};
 
 /// A modular building formed of [`BuildingModuleStack`] and [`BuildingModule`].
 #[derive(Component, Default)]
@@ -9,10 +10,85 @@ use konstruo_ui::EntityState;
 pub struct ModularBuilding;
 
 /// A definition to horizontally array vertical stacks of [`BuildingModule`].
+#[derive(Asset, Clone, Debug, Deserialize, Serialize, TypePath)]
 pub struct ModularBuildingInfo {
     pub stacks: Vec<BuildingModuleStackInfo>,
 }
 
+impl ModularBuildingInfo {
+    /// Get the approximate width of the building from left to right.
+    ///
+    /// Each stack is as wide as its widest module. Margins are ignored.
+    #[must_use]
+    pub fn get_width(&self) -> f32 {
+        self.stacks
+            .iter()
+            .map(|stack| {
+                stack
+                    .modules
+                    .iter()
+                    .map(|module| module.width)
+                    .fold(0.0, f32::max)
+            })
+            .sum()
+    }
+
+    /// Get the approximate length of the building from front to back.
+    ///
+    /// The building is as long as its longest module. Margins are ignored.
+    #[must_use]
+    pub fn get_length(&self) -> f32 {
+        self.get_modules()
+            .map(|module| module.length)
+            .fold(0.0, f32::max)
+    }
+
+    /// Get the number of storeys above ground.
+    ///
+    /// Pitched modules forming the roof are not counted.
+    #[must_use]
+    pub fn get_storeys(&self) -> usize {
+        self.stacks
+            .iter()
+            .map(|stack| {
+                stack
+                    .modules
+                    .iter()
+                    .filter(|module| module.level >= 0 && module.pitch.is_none())
+                    .count()
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Get the total number of openings in every module.
+    #[must_use]
+    pub fn get_openings_count(&self) -> usize {
+        self.get_modules()
+            .filter_map(|module| module.openings.as_ref())
+            .flatten()
+            .map(|distribution| distribution.openings.len())
+            .sum()
+    }
+
+    /// Get every module of every stack.
+    pub fn get_modules(&self) -> impl Iterator<Item = &BuildingModuleInfo> {
+        self.stacks.iter().flat_map(|stack| stack.modules.iter())
+    }
+
+    /// Mirror the building from left to right.
+    ///
+    /// The order of the stacks is reversed and each module is mirrored.
+    pub fn mirror(&mut self) {
+        self.stacks.reverse();
+        for stack in &mut self.stacks {
+            for module in &mut stack.modules {
+                module.mirror();
+            }
+        }
+    }
+}
+
 impl ModularBuilding {
     /// Create a bundle for [`ModularBuildingInfo`].
     fn bundle() -> impl Bundle {
@@ -43,3 +119,106 @@ impl ModularBuildingFactory<'_> {
         plot
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Pitch;
+    use konstruo_geometry::{Orientation, Vec6};
+
+    fn module(level: isize, width: f32, length: f32, pitch: Option<Pitch>) -> BuildingModuleInfo {
+        BuildingModuleInfo {
+            level,
+            width,
+            length,
+            pitch,
+            ..default()
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn get_dimensions() {
+        // Arrange
+        let building = ModularBuildingInfo {
+            stacks: vec![
+                BuildingModuleStackInfo {
+                    modules: vec![
+                        module(0, 5.0, 8.0, None),
+                        module(1, 5.0, 8.0, None),
+                        module(2, 5.0, 8.0, Some(Pitch::LeftToRight)),
+                    ],
+                },
+                BuildingModuleStackInfo {
+                    modules: vec![module(-1, 3.0, 10.0, None), module(0, 4.0, 6.0, None)],
+                },
+            ],
+        };
+
+        // Act
+        let width = building.get_width();
+        let length = building.get_length();
+        let storeys = building.get_storeys();
+        let modules = building.get_modules().count();
+
+        // Assert
+        assert_eq!(width, 9.0);
+        assert_eq!(length, 10.0);
+        assert_eq!(storeys, 2);
+        assert_eq!(modules, 5);
+    }
+
+    fn opening(width: f32) -> OpeningInfo {
+        OpeningInfo {
+            width,
+            height: 1.0,
+            margin: Some(Vec6::default().with_left(0.5)),
+        }
+    }
+
+    #[test]
+    fn mirror() {
+        // Arrange
+        let openings = |side| OpeningDistribution {
+            side,
+            justify_content: JustifyContent::FlexStart,
+            openings: vec![opening(1.0), opening(2.0)],
+        };
+        let mut building = ModularBuildingInfo {
+            stacks: vec![
+                BuildingModuleStackInfo {
+                    modules: vec![BuildingModuleInfo {
+                        margin: Some(Vec6::default().with_left(1.0)),
+                        openings: Some(vec![
+                            openings(Orientation::Front),
+                            openings(Orientation::Left),
+                        ]),
+                        ..module(0, 5.0, 8.0, None)
+                    }],
+                },
+                BuildingModuleStackInfo {
+                    modules: vec![module(0, 3.0, 8.0, None)],
+                },
+            ],
+        };
+
+        // Act
+        building.mirror();
+
+        // Assert
+        let widths: Vec<f32> = building.get_modules().map(|module| module.width).collect();
+        assert_eq!(widths, vec![3.0, 5.0]);
+        let mirrored = building.get_modules().last().expect("module should exist");
+        assert_eq!(mirrored.margin, Some(Vec6::default().with_right(1.0)));
+        let [front, right] = mirrored.openings.as_deref().expect("openings should exist") else {
+            unreachable!()
+        };
+        assert_eq!(front.side, Orientation::Front);
+        assert_eq!(right.side, Orientation::Right);
+        assert_eq!(front.justify_content, JustifyContent::FlexEnd);
+        let widths: Vec<f32> = front.openings.iter().map(|opening| opening.width).collect();
+        assert_eq!(widths, vec![2.0, 1.0]);
+        let margin = front.openings.first().and_then(|opening| opening.margin);
+        assert_eq!(margin, Some(Vec6::default().with_right(0.5)));
+    }
+}
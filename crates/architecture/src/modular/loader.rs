@@ -0,0 +1,173 @@
+use crate::*;
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use ron::de::from_bytes;
+use ron::error::SpannedError;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::Error as IoError;
+
+/// An index of [`ModularBuildingInfo`] templates.
+///
+/// Each template listed in the index is loaded as a dependency.
+#[derive(Asset, Debug, TypePath)]
+pub struct BuildingTemplateIndex {
+    #[dependency]
+    pub templates: Vec<Handle<ModularBuildingInfo>>,
+}
+
+/// The serialized form of [`BuildingTemplateIndex`].
+#[derive(Deserialize)]
+struct BuildingTemplateIndexInfo {
+    /// Asset paths of each template.
+    templates: Vec<String>,
+}
+
+/// An [`AssetLoader`] for [`ModularBuildingInfo`] from `*.building.ron` or `*.building.json` files.
+#[derive(Default, TypePath)]
+pub struct ModularBuildingInfoLoader;
+
+/// An [`AssetLoader`] for [`BuildingTemplateIndex`] from `*.index.ron` or `*.index.json` files.
+#[derive(Default, TypePath)]
+pub struct BuildingTemplateIndexLoader;
+
+#[derive(Debug)]
+pub enum TemplateLoaderError {
+    Io(IoError),
+    Ron(SpannedError),
+    Json(serde_json::Error),
+    UnsupportedExtension(String),
+}
+
+impl AssetLoader for ModularBuildingInfoLoader {
+    type Asset = ModularBuildingInfo;
+    type Settings = ();
+    type Error = TemplateLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let extension = get_extension(load_context);
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(TemplateLoaderError::Io)?;
+        deserialize(&bytes, &extension)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["building.ron", "building.json"]
+    }
+}
+
+impl AssetLoader for BuildingTemplateIndexLoader {
+    type Asset = BuildingTemplateIndex;
+    type Settings = ();
+    type Error = TemplateLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let extension = get_extension(load_context);
+        let mut bytes = Vec::new();
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(TemplateLoaderError::Io)?;
+        let info: BuildingTemplateIndexInfo = deserialize(&bytes, &extension)?;
+        let templates = info
+            .templates
+            .into_iter()
+            .map(|path| load_context.load(path))
+            .collect();
+        Ok(BuildingTemplateIndex { templates })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["index.ron", "index.json"]
+    }
+}
+
+/// Get the final extension of the asset being loaded.
+fn get_extension(load_context: &LoadContext<'_>) -> String {
+    load_context
+        .path()
+        .path()
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_owned()
+}
+
+/// Deserialize RON or JSON according to the file extension.
+fn deserialize<T: DeserializeOwned>(
+    bytes: &[u8],
+    extension: &str,
+) -> Result<T, TemplateLoaderError> {
+    match extension {
+        "ron" => from_bytes(bytes).map_err(TemplateLoaderError::Ron),
+        "json" => serde_json::from_slice(bytes).map_err(TemplateLoaderError::Json),
+        _ => Err(TemplateLoaderError::UnsupportedExtension(
+            extension.to_owned(),
+        )),
+    }
+}
+
+impl Display for TemplateLoaderError {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            TemplateLoaderError::Io(e) => format!("Failed to read template: {e}"),
+            TemplateLoaderError::Ron(e) => format!("Failed to parse RON template: {e}"),
+            TemplateLoaderError::Json(e) => format!("Failed to parse JSON template: {e}"),
+            TemplateLoaderError::UnsupportedExtension(extension) => {
+                format!("Unsupported template extension: {extension}")
+            }
+        };
+        formatter.write_str(&reason)
+    }
+}
+
+impl Error for TemplateLoaderError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::{read, read_dir};
+
+    #[test]
+    fn deserialize_building_templates() {
+        // Arrange
+        let directory = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../assets/templates/buildings"
+        );
+        let paths: Vec<_> = read_dir(directory)
+            .expect("templates directory should exist")
+            .map(|entry| entry.expect("entry should be valid").path())
+            .collect();
+
+        // Act
+        let templates: Vec<ModularBuildingInfo> = paths
+            .iter()
+            .map(|path| {
+                let bytes = read(path).expect("template should be readable");
+                deserialize(&bytes, "ron").expect("template should be valid")
+            })
+            .collect();
+
+        // Assert
+        assert_eq!(templates.len(), 8);
+        assert!(templates.iter().all(|template| !template.stacks.is_empty()));
+    }
+}
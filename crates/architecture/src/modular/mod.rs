@@ -1,5 +1,6 @@
 pub use building::*;
 pub use factory::*;
+pub use loader::*;
 pub use materials::*;
 pub use meshes::*;
 pub use module::*;
@@ -9,10 +10,10 @@ pub use templates::*;
 
 mod building;
 mod factory;
+mod loader;
 mod materials;
 mod meshes;
 mod module;
 mod plugin;
 mod stack;
-#[allow(clippy::too_many_lines)]
 mod templates;
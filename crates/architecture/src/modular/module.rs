@@ -3,8 +3,10 @@ use crate::*;
 use bevy::prelude::*;
 use konstruo_distribution::Distributable;
 use konstruo_geometry::Cuboid;
+use konstruo_geometry::Polygon;
 use konstruo_geometry::*;
 use konstruo_ui::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use Orientation::*;
 
@@ -17,7 +19,8 @@ const MESH_TO_BUILDING_GENERATIONS: usize = 3;
 pub struct BuildingModule;
 
 /// A definition for creating a [`BuildingModule`].
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
 pub struct BuildingModuleInfo {
     /// Level number
     /// 0: Ground
@@ -53,6 +56,16 @@ impl Default for BuildingModuleInfo {
 }
 
 impl BuildingModuleInfo {
+    /// Mirror the module from left to right.
+    ///
+    /// The margins and openings are mirrored. Pitched roofs are symmetric so are unchanged.
+    pub(crate) fn mirror(&mut self) {
+        self.margin = self.margin.map(Vec6::mirror_x);
+        for openings in self.openings.iter_mut().flatten() {
+            openings.mirror();
+        }
+    }
+
     /// Get the scale of [`BuildingModule`].
     fn get_scale(&self) -> Vec3 {
         Vec3::new(self.width, self.length, self.height)
@@ -87,39 +100,39 @@ impl BuildingModuleInfo {
         Some(openings)
     }
 
-    /// Get the rectangle geometry forming each face by subtracting the openings.
-    fn get_face_rectangles(
+    /// Get the triangle geometry forming each face by subtracting the openings.
+    fn get_face_triangles(
         &self,
         openings: &HashMap<Orientation, Option<Vec<Cuboid>>>,
-    ) -> Vec<[Vec3; 4]> {
+    ) -> TriangleList {
         let cuboid = Cuboid::new(Transform::from_scale(self.get_scale()));
-        let mut rectangles = Vec::new();
+        let mut triangles = TriangleList::default();
         for side in Orientation::get_all() {
             let face = cuboid.get_face(side);
             let Some(Some(openings)) = openings.get(&side) else {
-                rectangles.push(face);
+                triangles.merge(TriangleList::from_rectangles(vec![face]));
                 continue;
             };
-            let mut opening_rectangles = Vec::new();
-            for opening in openings {
-                opening_rectangles.push(opening.get_face_reversed(Front));
-            }
+            let opening_polygons = openings
+                .iter()
+                .filter_map(|opening| Polygon::from_open(opening.get_face(Front).to_vec()))
+                .collect();
             let (right, up, _back) = side.to_elevation_axis();
             let subdivision = Subdivision {
-                bounds: face,
-                openings: opening_rectangles,
+                bounds: Polygon::from_open(face.to_vec()).expect("face should be valid"),
+                openings: opening_polygons,
                 main_axis: right,
                 cross_axis: up,
             };
             match subdivision.execute() {
-                Ok(mut s) => rectangles.append(&mut s),
+                Ok(s) => triangles.merge(s),
                 Err(e) => {
-                    rectangles.push(face);
+                    triangles.merge(TriangleList::from_rectangles(vec![face]));
                     warn!("Failed to create openings in BuildingModule {side} facade: {e:?}");
                 }
             }
         }
-        rectangles
+        triangles
     }
 }
 
@@ -141,14 +154,11 @@ impl ModularBuildingFactory<'_> {
     /// Spawn a [`BuildingModule`] with  edge and face geometry and openings.
     fn spawn_cuboid(&mut self, module: &BuildingModuleInfo, order: usize, parent: Entity) {
         let openings = module.get_openings();
-        let rectangles = module.get_face_rectangles(&openings);
-        let openings = openings
-            .into_iter()
-            .filter_map(|(_, values)| values)
-            .flatten();
+        let triangles = module.get_face_triangles(&openings);
+        let openings = openings.into_values().flatten().flatten();
         let module_bundle = Self::module_bundle(module, order, parent);
         let module_edge_bundle = self.module_edges_bundle(module);
-        let modules_faces_bundle = self.cuboid_faces_bundle(rectangles);
+        let modules_faces_bundle = self.cuboid_faces_bundle(triangles);
         let module_entity = self
             .commands
             .spawn(module_bundle)
@@ -187,6 +197,7 @@ impl ModularBuildingFactory<'_> {
             order,
             size: Some(module.get_scale()),
             margin: module.margin,
+            ..default()
         };
         (
             Name::new("Building Module"),
@@ -200,8 +211,8 @@ impl ModularBuildingFactory<'_> {
     }
 
     /// Create a bundle for the cuboid solid geometry of [`BuildingModule`] with subtracted openings.
-    fn cuboid_faces_bundle(&mut self, rectangles: Vec<[Vec3; 4]>) -> impl Bundle {
-        let mesh = TriangleList::from_rectangles(rectangles).to_mesh();
+    fn cuboid_faces_bundle(&mut self, triangles: TriangleList) -> impl Bundle {
+        let mesh = triangles.to_mesh();
         (
             Name::new("Faces of Building Module"),
             Solid,
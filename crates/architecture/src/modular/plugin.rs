@@ -1,12 +1,28 @@
 use crate::*;
 use bevy::app::{App, Startup};
 use bevy::prelude::*;
+use konstruo_distribution::{Distribution, DistributionSystems};
 
 pub struct ModularBuildingsPlugin;
 
 impl Plugin for ModularBuildingsPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, BuildingMaterials::startup_system)
-            .add_systems(Startup, BuildingMeshes::startup_system);
+        app.init_asset::<ModularBuildingInfo>()
+            .init_asset::<BuildingTemplateIndex>()
+            .init_asset_loader::<ModularBuildingInfoLoader>()
+            .init_asset_loader::<BuildingTemplateIndexLoader>()
+            .init_resource::<BuildingTemplates>()
+            .add_systems(Startup, BuildingMaterials::startup_system)
+            .add_systems(Startup, BuildingMeshes::startup_system)
+            .add_systems(Startup, BuildingTemplates::startup_system)
+            .add_systems(Update, BuildingTemplates::on_index_loaded)
+            .add_systems(
+                Update,
+                ModularBuilding::on_template_modified.before(Distribution::changed_system),
+            )
+            .add_systems(
+                Update,
+                BuildingFill::changed_system.in_set(DistributionSystems::Fill),
+            );
     }
 }
@@ -2,6 +2,7 @@ use super::*;
 use bevy::prelude::*;
 use konstruo_distribution::{Distributable, Distribution, FlexBuilder};
 use konstruo_ui::EntityState;
+use serde::{Deserialize, Serialize};
 
 /// A vertical stack of [`BuildingModuleInfo`].
 #[derive(Clone, Component, Debug)]
@@ -9,6 +10,7 @@ use konstruo_ui::EntityState;
 pub struct BuildingModuleStack;
 
 /// A defintion to create a [`BuildingModuleStack`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct BuildingModuleStackInfo {
     pub modules: Vec<BuildingModuleInfo>,
 }
@@ -1,889 +1,309 @@
 use crate::*;
-use bevy::prelude::{default, JustifyContent};
-use konstruo_geometry::{Orientation, Vec6};
+use bevy::prelude::*;
+use konstruo_distribution::{Distributable, Distribution};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::iter::once;
+use std::path::Path;
 
-const ROOF_HEIGHT: f32 = 1.800;
+/// Asset path of the [`BuildingTemplateIndex`] listing every building template.
+pub const BUILDING_TEMPLATES_INDEX: &str = "templates/buildings.index.ron";
 
-pub struct BuildingTemplates;
-pub struct StackTemplates;
+/// Maximum number of buildings spawned in a single fill of a [`Distribution`].
+const MAX_FILL: usize = 1000;
+
+/// A registry of [`ModularBuildingInfo`] templates keyed by name.
+///
+/// Templates are loaded from `*.building.ron` or `*.building.json` assets listed in
+/// [`BUILDING_TEMPLATES_INDEX`]. The name of a template is its file name without extensions.
+#[derive(Default, Resource)]
+pub struct BuildingTemplates {
+    index: Handle<BuildingTemplateIndex>,
+    templates: BTreeMap<String, Handle<ModularBuildingInfo>>,
+}
+
+/// The name of the template a [`ModularBuilding`] was spawned from.
+///
+/// Buildings with this component are respawned when the template asset is modified.
+#[derive(Clone, Component, Debug)]
+pub struct BuildingTemplate {
+    pub name: String,
+    /// Was the template mirrored from left to right?
+    pub is_mirrored: bool,
+}
+
+/// The templates a [`Distribution`] of [`ModularBuilding`] is filled with.
+///
+/// Templates are repeated in turn until the bounds of the [`Distribution`] are filled.
+/// Buildings are added or removed whenever the [`Distribution`] changes.
+#[derive(Clone, Component, Debug, Default, Deserialize, Serialize)]
+#[require(Distribution)]
+pub struct BuildingFill {
+    /// Names of the templates in [`BuildingTemplates`].
+    pub templates: Vec<String>,
+}
 
 impl BuildingTemplates {
-    /// Beds: 2
-    /// Persons: 4
-    /// Storeys: 2
-    /// Garage bays: 0
-    /// NIA: 75 sq m
-    /// Orientation: Parallel
+    /// Have the templates been loaded?
     #[must_use]
-    pub fn _2b4p1s0g() -> ModularBuildingInfo {
-        ModularBuildingInfo {
-            stacks: vec![StackTemplates::_2b4p1s()],
-        }
+    pub fn is_loaded(&self) -> bool {
+        !self.templates.is_empty()
     }
 
-    /// Beds: 2
-    /// Persons: 4
-    /// Storeys: 2
-    /// Garage bays: 0
-    /// NIA: 75 sq m
-    /// Orientation: Perpendicular
+    /// Get the names of all templates in alphabetical order.
     #[must_use]
-    pub fn _2b4p1s0g_perp() -> ModularBuildingInfo {
-        ModularBuildingInfo {
-            stacks: vec![StackTemplates::_2b4p1s_perp()],
-        }
+    pub fn get_names(&self) -> Vec<&str> {
+        self.templates.keys().map(String::as_str).collect()
     }
 
-    /// Beds: 3
-    /// Persons: 6
-    /// Storeys: 2
-    /// Garage bays: 2
-    /// NIA: 104 sq m
-    /// Orientation: Parallel
+    /// Get the handle of a template by name.
     #[must_use]
-    pub fn _3b6p2s2g() -> ModularBuildingInfo {
-        ModularBuildingInfo {
-            stacks: vec![StackTemplates::_3b6p2s(), StackTemplates::_2g()],
-        }
+    pub fn get_handle(&self, name: &str) -> Option<&Handle<ModularBuildingInfo>> {
+        self.templates.get(name)
     }
 
-    /// Beds: 3
-    /// Persons: 6
-    /// Storeys: 2
-    /// Garage bays: 2
-    /// NIA: 104 sq m
-    /// Orientation: Perpendicular
+    /// Get a template by name.
     #[must_use]
-    pub fn _3b6p2s2g_perp() -> ModularBuildingInfo {
-        ModularBuildingInfo {
-            stacks: vec![StackTemplates::_3b6p2s_perp(), StackTemplates::_2g()],
-        }
+    pub fn get<'a>(
+        &self,
+        name: &str,
+        assets: &'a Assets<ModularBuildingInfo>,
+    ) -> Option<&'a ModularBuildingInfo> {
+        assets.get(self.templates.get(name)?)
     }
 
-    /// Beds: 4
-    /// Persons: 8
-    /// Storeys: 2
-    /// Garage bays: 2
-    /// NIA: 127 sq m
-    /// Orientation: Parallel
+    /// Get the name of a template by asset id.
     #[must_use]
-    pub fn _4b8p2s2g() -> ModularBuildingInfo {
-        ModularBuildingInfo {
-            stacks: vec![StackTemplates::_4b8p2s(), StackTemplates::_2g()],
-        }
+    pub fn get_name(&self, id: AssetId<ModularBuildingInfo>) -> Option<&str> {
+        self.templates
+            .iter()
+            .find(|(_, handle)| handle.id() == id)
+            .map(|(name, _)| name.as_str())
     }
 
-    /// Beds: 4
-    /// Persons: 8
-    /// Storeys: 2
-    /// Garage bays: 2
-    /// NIA: 127 sq m
-    /// Orientation: Perpendicular
-    #[must_use]
-    pub fn _4b8p2s2g_perp() -> ModularBuildingInfo {
-        ModularBuildingInfo {
-            stacks: vec![StackTemplates::_4b8p2s_perp(), StackTemplates::_2g()],
-        }
+    /// System to load the [`BuildingTemplateIndex`] on startup.
+    pub(super) fn startup_system(mut templates: ResMut<Self>, assets: Res<AssetServer>) {
+        templates.index = assets.load(BUILDING_TEMPLATES_INDEX);
     }
-}
 
-impl StackTemplates {
-    /// Beds: 2
-    /// Persons: 4
-    /// Storeys: 1
-    /// NIA: 75 sq m
-    /// Orientation: Parallel
-    #[must_use]
-    fn _2b4p1s() -> BuildingModuleStackInfo {
-        BuildingModuleStackInfo {
-            modules: vec![
-                BuildingModuleInfo {
-                    level: 0,
-                    width: 10.200,
-                    length: 8.400,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.800,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.900,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.800,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 2.600,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 1,
-                    width: 10.200,
-                    length: 8.400,
-                    height: ROOF_HEIGHT,
-                    pitch: Some(Pitch::FrontToBack),
-                    ..default()
-                },
-            ],
+    /// System to populate the registry once the [`BuildingTemplateIndex`] and all its templates are loaded.
+    pub(super) fn on_index_loaded(
+        mut events: MessageReader<AssetEvent<BuildingTemplateIndex>>,
+        mut templates: ResMut<Self>,
+        indexes: Res<Assets<BuildingTemplateIndex>>,
+    ) {
+        for event in events.read() {
+            let (AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id }) = event
+            else {
+                continue;
+            };
+            if *id != templates.index.id() {
+                continue;
+            }
+            let Some(index) = indexes.get(*id) else {
+                warn!("Failed to get building template index");
+                continue;
+            };
+            templates.templates = index
+                .templates
+                .iter()
+                .filter_map(|handle| {
+                    let name = get_template_name(handle)?;
+                    Some((name, handle.clone()))
+                })
+                .collect();
+            trace!("Loaded {} building templates", templates.templates.len());
         }
     }
+}
 
-    /// Beds: 2
-    /// Persons: 4
-    /// Storeys: 1
-    /// NIA: 75 sq m
-    /// Orientation: Perpendicular
-    #[must_use]
-    fn _2b4p1s_perp() -> BuildingModuleStackInfo {
-        BuildingModuleStackInfo {
-            modules: vec![
-                BuildingModuleInfo {
-                    level: 0,
-                    width: 8.400,
-                    length: 10.200,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.900,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 2.600,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 1,
-                    width: 8.400,
-                    length: 10.200,
-                    height: ROOF_HEIGHT,
-                    pitch: Some(Pitch::LeftToRight),
-                    ..default()
-                },
-            ],
-        }
-    }
-    /// Beds: 2
-    /// Persons: 4
-    /// Storeys: 1
-    /// NIA: 75 sq m
-    /// Orientation: Parallel
-    #[must_use]
-    fn _2b4p1s_long() -> BuildingModuleStackInfo {
-        BuildingModuleStackInfo {
-            modules: vec![
-                BuildingModuleInfo {
-                    level: 0,
-                    width: 12.000,
-                    length: 7.200,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.800,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.900,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.800,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 2.600,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 1,
-                    width: 12.000,
-                    length: 7.200,
-                    height: ROOF_HEIGHT,
-                    pitch: Some(Pitch::FrontToBack),
-                    ..default()
-                },
-            ],
-        }
+impl ModularBuildingFactory<'_> {
+    /// Spawn a [`ModularBuilding`] from a named template.
+    pub fn spawn_template(&mut self, name: &str, building: ModularBuildingInfo) -> Entity {
+        let entity = self.spawn(building);
+        self.commands.entity(entity).insert(BuildingTemplate {
+            name: name.to_owned(),
+            is_mirrored: false,
+        });
+        entity
     }
 
-    /// Beds: 2
-    /// Persons: 4
-    /// Storeys: 1
-    /// NIA: 75 sq m
-    /// Orientation: Perpendicular
-    #[must_use]
-    fn _2b4p1s_long_perp() -> BuildingModuleStackInfo {
-        BuildingModuleStackInfo {
-            modules: vec![
-                BuildingModuleInfo {
-                    level: 0,
-                    width: 7.200,
-                    length: 12.000,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.900,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 2.600,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 1,
-                    width: 7.200,
-                    length: 12.000,
-                    height: ROOF_HEIGHT,
-                    pitch: Some(Pitch::LeftToRight),
-                    ..default()
-                },
-            ],
-        }
+    /// Spawn a [`ModularBuilding`] from a named template mirrored from left to right.
+    pub fn spawn_mirrored_template(
+        &mut self,
+        name: &str,
+        mut building: ModularBuildingInfo,
+    ) -> Entity {
+        building.mirror();
+        let entity = self.spawn(building);
+        self.commands.entity(entity).insert(BuildingTemplate {
+            name: name.to_owned(),
+            is_mirrored: true,
+        });
+        entity
     }
 
-    /// Beds: 3
-    /// Persons: 6
-    /// Storeys: 2
-    /// NIA: 104 sq m
-    /// Orientation: Parallel
-    #[must_use]
-    fn _3b6p2s() -> BuildingModuleStackInfo {
-        BuildingModuleStackInfo {
-            modules: vec![
-                BuildingModuleInfo {
-                    level: 0,
-                    width: 10.200,
-                    length: 6.000,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.900,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300).with_left(0.800)),
-                                },
-                                OpeningInfo {
-                                    width: 2.700,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300).with_right(0.800)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 1,
-                    width: 10.200,
-                    length: 6.000,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.600,
-                                    height: 1.000,
-                                    margin: Some(Vec6::new(0.150, 0.150, 0.0, 0.0, 0.0, 0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.600,
-                                    height: 1.000,
-                                    margin: Some(Vec6::new(0.150, 0.150, 0.0, 0.0, 0.0, 0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 2,
-                    width: 10.200,
-                    length: 6.000,
-                    height: ROOF_HEIGHT,
-                    pitch: Some(Pitch::FrontToBack),
-                    ..default()
-                },
-            ],
+    /// Replace the [`BuildingModuleStack`] of a [`ModularBuilding`] with those of a template.
+    ///
+    /// The root [`Distribution`] should then be marked as changed so the building is
+    /// distributed again.
+    pub fn respawn_template(
+        &mut self,
+        entity: Entity,
+        stacks: impl IntoIterator<Item = Entity>,
+        template: BuildingTemplate,
+        mut building: ModularBuildingInfo,
+    ) {
+        for stack in stacks {
+            self.commands.entity(stack).despawn();
         }
-    }
-
-    /// Beds: 3
-    /// Persons: 6
-    /// Storeys: 2
-    /// NIA: 104 sq m
-    /// Orientation: Perpendicular
-    #[must_use]
-    fn _3b6p2s_perp() -> BuildingModuleStackInfo {
-        BuildingModuleStackInfo {
-            modules: vec![
-                BuildingModuleInfo {
-                    level: 0,
-                    width: 6.000,
-                    length: 10.200,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.900,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 2.600,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300).with_left(0.100)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 1,
-                    width: 6.000,
-                    length: 10.200,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.600,
-                                    height: 1.000,
-                                    margin: Some(Vec6::new(0.150, 0.150, 0.0, 0.0, 0.0, 0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.600,
-                                    height: 1.000,
-                                    margin: Some(Vec6::new(0.150, 0.150, 0.0, 0.0, 0.0, 0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 2,
-                    width: 6.000,
-                    length: 10.200,
-                    height: ROOF_HEIGHT,
-                    pitch: Some(Pitch::LeftToRight),
-                    ..default()
-                },
-            ],
+        if template.is_mirrored {
+            building.mirror();
         }
-    }
-
-    /// Beds: 4
-    /// Persons: 8
-    /// Storeys: 2
-    /// NIA: 127 sq m
-    /// Orientation: Parallel
-    #[must_use]
-    fn _4b8p2s() -> BuildingModuleStackInfo {
-        BuildingModuleStackInfo {
-            modules: vec![
-                BuildingModuleInfo {
-                    level: 0,
-                    width: 10.200,
-                    length: 7.200,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.900,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300).with_left(0.800)),
-                                },
-                                OpeningInfo {
-                                    width: 2.700,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300).with_right(0.800)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 1,
-                    width: 10.200,
-                    length: 7.200,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.600,
-                                    height: 1.000,
-                                    margin: Some(Vec6::new(0.150, 0.150, 0.0, 0.0, 0.0, 0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.600,
-                                    height: 1.000,
-                                    margin: Some(Vec6::new(0.150, 0.150, 0.0, 0.0, 0.0, 0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 2,
-                    width: 10.200,
-                    length: 7.200,
-                    pitch: Some(Pitch::FrontToBack),
-                    ..default()
-                },
-            ],
+        for (index, stack) in building.stacks.into_iter().enumerate() {
+            self.spawn_stack(stack, index, entity);
         }
+        self.commands.entity(entity).insert(template);
     }
+}
 
-    /// Beds: 4
-    /// Persons: 8
-    /// Storeys: 2
-    /// NIA: 127 sq m
-    /// Orientation: Perpendicular
-    #[must_use]
-    fn _4b8p2s_perp() -> BuildingModuleStackInfo {
-        BuildingModuleStackInfo {
-            modules: vec![
-                BuildingModuleInfo {
-                    level: 0,
-                    width: 7.200,
-                    length: 10.200,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.900,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 2.900,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300).with_left(0.100)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 1,
-                    width: 7.200,
-                    length: 10.200,
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.600,
-                                    height: 1.000,
-                                    margin: Some(Vec6::new(0.150, 0.150, 0.0, 0.0, 0.0, 0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 0.600,
-                                    height: 1.000,
-                                    margin: Some(Vec6::new(0.150, 0.150, 0.0, 0.0, 0.0, 0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.200,
-                                    height: 1.500,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 2,
-                    width: 7.200,
-                    length: 10.200,
-                    pitch: Some(Pitch::LeftToRight),
-                    ..default()
-                },
-            ],
+impl ModularBuilding {
+    /// System to respawn the stacks of each [`ModularBuilding`] when its template is modified.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn on_template_modified(
+        mut events: MessageReader<AssetEvent<ModularBuildingInfo>>,
+        commands: Commands,
+        meshes: ResMut<Assets<Mesh>>,
+        building_meshes: Res<BuildingMeshes>,
+        materials: Res<BuildingMaterials>,
+        templates: Res<BuildingTemplates>,
+        assets: Res<Assets<ModularBuildingInfo>>,
+        buildings: Query<(Entity, &BuildingTemplate, Option<&Children>), With<ModularBuilding>>,
+        stacks: Query<(), With<BuildingModuleStack>>,
+        parents: Query<&ChildOf>,
+        mut roots: Query<&mut Distribution, Without<Distributable>>,
+    ) {
+        let mut factory = ModularBuildingFactory {
+            commands,
+            meshes,
+            building_meshes,
+            materials,
+        };
+        for event in events.read() {
+            let AssetEvent::Modified { id } = event else {
+                continue;
+            };
+            let Some(name) = templates.get_name(*id) else {
+                continue;
+            };
+            let Some(info) = assets.get(*id) else {
+                warn!("Failed to get modified building template: {name}");
+                continue;
+            };
+            for (entity, template, children) in buildings.iter() {
+                if template.name != name {
+                    continue;
+                }
+                let children = children.into_iter().flatten().copied();
+                let stacks = children.filter(|child| stacks.contains(*child));
+                factory.respawn_template(entity, stacks, template.clone(), info.clone());
+                let root = once(entity)
+                    .chain(parents.iter_ancestors(entity))
+                    .find(|ancestor| roots.contains(*ancestor));
+                if let Some(mut distribution) = root.and_then(|root| roots.get_mut(root).ok()) {
+                    distribution.set_changed();
+                }
+                trace!("Respawned building {entity} from template: {name}");
+            }
         }
     }
+}
 
-    /// Garage bays: 2
-    /// NIA: 26 sq m
-    /// Orientation: Parallel
-    #[must_use]
-    fn _2g() -> BuildingModuleStackInfo {
-        BuildingModuleStackInfo {
-            modules: vec![
-                BuildingModuleInfo {
-                    level: 0,
-                    width: 6.000,
-                    length: 5.400,
-                    margin: Some(Vec6::default().with_back(0.600)),
-                    openings: Some(vec![
-                        OpeningDistribution {
-                            side: Orientation::Front,
-                            justify_content: JustifyContent::Center,
-                            openings: vec![OpeningInfo {
-                                width: 4.800,
-                                height: 2.100,
-                                margin: Some(Vec6::default().with_top(0.300)),
-                            }],
-                        },
-                        OpeningDistribution {
-                            side: Orientation::Back,
-                            justify_content: JustifyContent::SpaceEvenly,
-                            openings: vec![
-                                OpeningInfo {
-                                    width: 0.900,
-                                    height: 2.100,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                                OpeningInfo {
-                                    width: 1.800,
-                                    height: 1.200,
-                                    margin: Some(Vec6::default().with_top(0.300)),
-                                },
-                            ],
-                        },
-                    ]),
-                    ..default()
-                },
-                BuildingModuleInfo {
-                    level: 1,
-                    width: 6.000,
-                    length: 5.400,
-                    height: ROOF_HEIGHT,
-                    margin: Some(Vec6::default().with_back(0.600)),
-                    pitch: Some(Pitch::FrontToBack),
-                    ..default()
-                },
-            ],
+impl BuildingFill {
+    /// System to add or remove buildings so each changed [`Distribution`] is filled.
+    ///
+    /// Buildings are kept in order until the next would exceed the bounds.
+    /// The remainder are despawned or, if there is space, templates are spawned in turn.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn changed_system(
+        commands: Commands,
+        meshes: ResMut<Assets<Mesh>>,
+        building_meshes: Res<BuildingMeshes>,
+        materials: Res<BuildingMaterials>,
+        templates: Res<BuildingTemplates>,
+        assets: Res<Assets<ModularBuildingInfo>>,
+        fills: Query<
+            (Entity, &BuildingFill, &Distribution, Option<&Children>),
+            Changed<Distribution>,
+        >,
+        buildings: Query<(&Distributable, &BuildingTemplate)>,
+    ) {
+        if fills.is_empty() || !templates.is_loaded() {
+            return;
+        }
+        let mut factory = ModularBuildingFactory {
+            commands,
+            meshes,
+            building_meshes,
+            materials,
+        };
+        for (entity, fill, distribution, children) in fills.iter() {
+            let Some(bounds) = distribution.flex.bounds else {
+                continue;
+            };
+            let get_width = |name: &str| {
+                templates
+                    .get(name, &assets)
+                    .map_or(0.0, ModularBuildingInfo::get_width)
+            };
+            let mut existing: Vec<(Entity, usize, f32)> = children
+                .into_iter()
+                .flatten()
+                .filter_map(|child| {
+                    let (distributable, template) = buildings.get(*child).ok()?;
+                    Some((*child, distributable.order, get_width(&template.name)))
+                })
+                .collect();
+            existing.sort_by_key(|(_, order, _)| *order);
+            let mut total = 0.0;
+            let mut count = 0;
+            let mut next_order = 0;
+            for (child, order, width) in existing {
+                if total + width > bounds.x {
+                    factory.commands.entity(child).despawn();
+                    continue;
+                }
+                total += width;
+                count += 1;
+                next_order = order + 1;
+            }
+            let remaining = fill.templates.iter().cycle().skip(count);
+            for (order, name) in (next_order..).zip(remaining.take(MAX_FILL)) {
+                let Some(info) = templates.get(name, &assets) else {
+                    warn!("Failed to get building template: {name}");
+                    break;
+                };
+                let width = info.get_width();
+                if width <= 0.0 || total + width > bounds.x {
+                    break;
+                }
+                total += width;
+                let plot = factory.spawn_template(name, info.clone());
+                factory
+                    .commands
+                    .entity(plot)
+                    .insert((Distributable { order, ..default() }, ChildOf(entity)));
+            }
         }
     }
 }
+
+/// Get the name of a template from its asset path.
+///
+/// The name is the file name without any extensions.
+fn get_template_name(handle: &Handle<ModularBuildingInfo>) -> Option<String> {
+    let path = handle.path()?;
+    let file_name = Path::new(path.path()).file_name()?.to_str()?;
+    let name = file_name.split('.').next()?;
+    Some(name.to_owned())
+}
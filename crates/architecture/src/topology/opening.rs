@@ -1,6 +1,7 @@
 use bevy::prelude::*;
 use konstruo_distribution::{Container, Distributable, FlexBuilder};
 use konstruo_geometry::{Orientation, Vec6};
+use serde::{Deserialize, Serialize};
 
 const DEPTH: f32 = 0.300;
 
@@ -9,7 +10,7 @@ const DEPTH: f32 = 0.300;
 pub struct Opening;
 
 /// Dimensions of an [`Opening`].
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct OpeningInfo {
     /// Left to right width
     pub width: f32,
@@ -20,7 +21,7 @@ pub struct OpeningInfo {
 }
 
 /// A set of [`Opening`] and the logic to distribute them.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct OpeningDistribution {
     /// Side to apply openings.
     pub side: Orientation,
@@ -31,6 +32,29 @@ pub struct OpeningDistribution {
 }
 
 impl OpeningDistribution {
+    /// Mirror the openings from left to right.
+    ///
+    /// Openings on the left side are moved to the right and vice versa. On every side the
+    /// order, margins and justification of the openings are reversed.
+    pub fn mirror(&mut self) {
+        self.side = match self.side {
+            Orientation::Left => Orientation::Right,
+            Orientation::Right => Orientation::Left,
+            side => side,
+        };
+        self.openings.reverse();
+        for opening in &mut self.openings {
+            opening.margin = opening.margin.map(Vec6::mirror_x);
+        }
+        self.justify_content = match self.justify_content {
+            JustifyContent::Default | JustifyContent::FlexStart => JustifyContent::FlexEnd,
+            JustifyContent::FlexEnd => JustifyContent::FlexStart,
+            JustifyContent::Start => JustifyContent::End,
+            JustifyContent::End => JustifyContent::Start,
+            justify_content => justify_content,
+        };
+    }
+
     /// Distribute the openings.
     #[must_use]
     pub fn distribute(&self, bounds: Vec3, right: Vec3, up: Vec3) -> Container {
@@ -44,6 +68,7 @@ impl OpeningDistribution {
                     order,
                     size: Some(scale),
                     margin: opening.margin,
+                    ..default()
                 }
             })
             .collect();
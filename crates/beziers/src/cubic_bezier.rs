@@ -7,11 +7,12 @@ use kurbo::{
     BezPath, ParamCurve, ParamCurveArclen, ParamCurveCurvature, ParamCurveDeriv, ParamCurveExtrema,
     ParamCurveNearest,
 };
+use serde::{Deserialize, Serialize};
 use ControlType::*;
 use CubicBezierError::*;
 
 /// A single cubic bezier curve of four control points.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
 pub struct CubicBezier {
     pub(super) start: Vec3,
     pub(super) start_handle: Vec3,
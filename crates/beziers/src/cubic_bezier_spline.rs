@@ -1,8 +1,12 @@
+use crate::constants::LENGTH_ACCURACY;
 use crate::CubicBezier;
 use crate::*;
 use bevy::prelude::*;
-use konstruo_geometry::Polyline;
+use konstruo_geometry::{Line, Polyline};
 use kurbo::{flatten, stroke, Cap, Join, PathEl, Stroke, StrokeOptLevel, StrokeOpts};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::iter::{once, repeat_n};
 use ControlType::*;
 use CubicBezierSplineError::*;
 
@@ -10,9 +14,31 @@ use CubicBezierSplineError::*;
 pub const CONNECTION_TOLERANCE: f32 = 0.010;
 
 /// A spline formed of one or more connected [`CubicBezier`].
-#[derive(Clone, Debug, Default)]
+///
+/// A closed spline forms a loop so the end of the last curve is the start of the first.
+///
+/// Deserialized splines are validated as if created with [`CubicBezierSpline::new`].
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(try_from = "CubicBezierSplineData")]
 pub struct CubicBezierSpline {
     curves: Vec<CubicBezier>,
+    /// Continuity of each node.
+    ///
+    /// A node without an entry is [`NodeContinuity::Smooth`].
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    continuities: Vec<NodeContinuity>,
+    /// Is the end connected to the start?
+    is_closed: bool,
+}
+
+/// The fields of a [`CubicBezierSpline`] before they are validated on deserialization.
+#[derive(Deserialize)]
+struct CubicBezierSplineData {
+    curves: Vec<CubicBezier>,
+    #[serde(default)]
+    continuities: Vec<NodeContinuity>,
+    #[serde(default)]
+    is_closed: bool,
 }
 
 #[derive(Debug)]
@@ -20,12 +46,17 @@ pub enum CubicBezierSplineError {
     NoCurves,
     InvalidCounts(usize, usize),
     NotConnected(usize, Vec3, Vec3),
+    InvalidParam(f32),
+    InvalidNode(usize),
+    Closed,
     Curve(CubicBezierError),
     Conversion(F32ConversionError),
 }
 
 impl CubicBezierSpline {
     /// Create a new [`CubicBezierSpline`].
+    ///
+    /// Every node is [`NodeContinuity::Smooth`].
     #[allow(clippy::indexing_slicing)]
     pub fn new(curves: Vec<CubicBezier>) -> Result<Self, CubicBezierSplineError> {
         if curves.is_empty() {
@@ -38,13 +69,23 @@ impl CubicBezierSpline {
                 return Err(NotConnected(i, start, end));
             }
         }
-        Ok(Self { curves })
+        Ok(Self {
+            curves,
+            continuities: Vec::new(),
+            is_closed: false,
+        })
     }
 
-    /// Create a new [`CubicBezierSpline`].
+    /// Create a new [`CubicBezierSpline`] without checking the curves are connected.
+    ///
+    /// Every node is [`NodeContinuity::Smooth`].
     #[must_use]
     pub fn new_unchecked(curves: Vec<CubicBezier>) -> Self {
-        Self { curves }
+        Self {
+            curves,
+            continuities: Vec::new(),
+            is_closed: false,
+        }
     }
 
     /// Create a new [`CubicBezierSpline`] from lists of origins and handles.
@@ -149,6 +190,92 @@ impl CubicBezierSpline {
             .get_control(End)
     }
 
+    /// Is the end connected to the start so the spline forms a loop?
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+
+    /// Close the spline so the end is connected to the start.
+    ///
+    /// If the end is not already at the start then a straight curve is added between them.
+    pub fn close(&mut self) -> Result<(), CubicBezierSplineError> {
+        if self.is_closed {
+            return Ok(());
+        }
+        let start = self.get_start();
+        let end = self.get_end();
+        let mut continuities = self.get_continuities();
+        if start.abs_diff_eq(end, CONNECTION_TOLERANCE) {
+            let last = self.curves.last_mut().expect("curves should not be empty");
+            last.end_handle += start - last.end;
+            last.end = start;
+            continuities.pop();
+        } else {
+            let curve = CubicBezier::from_line(Line::new(end, start)).map_err(Curve)?;
+            self.curves.push(curve);
+        }
+        self.continuities = continuities;
+        self.is_closed = true;
+        Ok(())
+    }
+
+    /// Get the number of nodes.
+    ///
+    /// A node is an anchor shared by adjacent curves so an open spline has one more node than
+    /// curve and a closed spline has one node per curve.
+    #[must_use]
+    pub fn get_node_count(&self) -> usize {
+        self.curves.len() + usize::from(!self.is_closed)
+    }
+
+    /// Get the nodes.
+    ///
+    /// A node is an anchor shared by adjacent curves. The start of a closed spline is only
+    /// included once.
+    #[must_use]
+    pub fn get_nodes(&self) -> Vec<Vec3> {
+        let mut nodes = vec![self.get_start()];
+        nodes.extend(self.curves.iter().map(|curve| curve.end));
+        nodes.truncate(self.get_node_count());
+        nodes
+    }
+
+    /// Get the continuity of the node at `index`.
+    #[must_use]
+    pub fn get_continuity(&self, index: usize) -> NodeContinuity {
+        self.continuities.get(index).copied().unwrap_or_default()
+    }
+
+    /// Get the continuity of every node.
+    #[must_use]
+    pub fn get_continuities(&self) -> Vec<NodeContinuity> {
+        (0..self.get_node_count())
+            .map(|index| self.get_continuity(index))
+            .collect()
+    }
+
+    /// Set the continuity of the node at `index`.
+    ///
+    /// The outgoing handle is unchanged and the incoming handle is moved to satisfy the
+    /// continuity.
+    pub fn set_continuity(
+        &mut self,
+        index: usize,
+        continuity: NodeContinuity,
+    ) -> Result<(), CubicBezierSplineError> {
+        let mut continuities = self.get_continuities();
+        let Some(entry) = continuities.get_mut(index) else {
+            return Err(InvalidNode(index));
+        };
+        *entry = continuity;
+        self.continuities = continuities;
+        if index < self.curves.len() {
+            self.constrain_opposite_handle(StartHandle, index);
+        }
+        Ok(())
+    }
+
     /// Get the control points.
     #[must_use]
     pub fn get_controls(&self) -> Vec<Vec3> {
@@ -187,6 +314,11 @@ impl CubicBezierSpline {
     #[must_use]
     #[allow(clippy::as_conversions, clippy::cast_precision_loss, clippy::panic)]
     pub fn get_param_at_length(&self, length: f32, accuracy: f32) -> Option<f32> {
+        let length = if self.is_closed {
+            length.rem_euclid(self.get_length(accuracy))
+        } else {
+            length
+        };
         let mut preceding_length = 0.0;
         for (index, curve) in self.curves.iter().enumerate() {
             let curve_length = curve.get_length(accuracy);
@@ -220,6 +352,9 @@ impl CubicBezierSpline {
     }
 
     /// Get the curve at the param and recalculate the param so it's relative to the curve.
+    ///
+    /// The param of a closed spline wraps around so `1.0` is the start.
+    /// Otherwise `1.0` is the end of the last curve.
     #[must_use]
     #[allow(
         clippy::as_conversions,
@@ -229,8 +364,13 @@ impl CubicBezierSpline {
         clippy::cast_precision_loss
     )]
     fn get_curve_index_at_param(&self, param: f32) -> (usize, f32) {
+        let param = if self.is_closed {
+            param.rem_euclid(1.0)
+        } else {
+            param
+        };
         let scaled_param = param * self.curves.len() as f32;
-        let index = scaled_param.floor() as usize;
+        let index = (scaled_param.floor() as usize).min(self.curves.len().saturating_sub(1));
         let param = scaled_param - index as f32;
         (index, param)
     }
@@ -282,58 +422,99 @@ impl CubicBezierSpline {
     /// Update the location of a control point at `index`.
     ///
     /// If the control point is:
-    /// -  an anchor: the next or previous anchor and handles are moved.
-    /// -  a handle: the opposing handle is rotated but its distance from anchor unchanged.
+    /// -  an anchor: the next or previous anchor and handles are moved, wrapping around if the
+    ///    spline is closed.
+    /// -  a handle: the opposing handle is constrained by the [`NodeContinuity`] of the node.
     #[allow(clippy::indexing_slicing, clippy::integer_division)]
     pub fn update_control(&mut self, control_type: ControlType, curve: usize, point: Vec3) {
         if curve >= self.curves.len() {
             error!("Failed to update control point. Curve index is out of range: {curve}");
             return;
         }
+        let count = self.curves.len();
         let is_first = curve == 0;
-        let is_last = curve == self.curves.len() - 1;
+        let is_last = curve == count - 1;
         match control_type {
             Start => {
                 let translation = point - self.curves[curve].start;
                 self.curves[curve].start = point;
                 self.curves[curve].start_handle += translation;
-                if !is_first {
-                    self.curves[curve - 1].end = point;
-                    self.curves[curve - 1].end_handle += translation;
+                if !is_first || self.is_closed {
+                    let previous = (curve + count - 1) % count;
+                    self.curves[previous].end = point;
+                    self.curves[previous].end_handle += translation;
                 }
             }
             StartHandle => {
                 self.curves[curve].start_handle = point;
-                if !is_first {
-                    let anchor = self.curves[curve].start;
-                    let direction = (anchor - point).normalize();
-                    let distance = self.curves[curve - 1].end_handle.distance(anchor);
-                    self.curves[curve - 1].end_handle = anchor + direction * distance;
-                }
+                self.constrain_opposite_handle(StartHandle, curve);
             }
             EndHandle => {
                 self.curves[curve].end_handle = point;
-                if !is_last {
-                    let anchor = self.curves[curve].end;
-                    let direction = (anchor - point).normalize();
-                    let distance = self.curves[curve + 1].start_handle.distance(anchor);
-                    self.curves[curve + 1].start_handle = anchor + direction * distance;
-                }
+                self.constrain_opposite_handle(EndHandle, curve);
             }
             End => {
                 let translation = point - self.curves[curve].end;
                 self.curves[curve].end = point;
                 self.curves[curve].end_handle += translation;
-                if !is_last {
-                    self.curves[curve + 1].start = point;
-                    self.curves[curve + 1].start_handle += translation;
+                if !is_last || self.is_closed {
+                    let next = (curve + 1) % count;
+                    self.curves[next].start = point;
+                    self.curves[next].start_handle += translation;
+                }
+            }
+        }
+    }
+
+    /// Move the handle opposite to the handle of `curve` according to the [`NodeContinuity`]
+    /// of the node between them.
+    ///
+    /// If the handle is at the start or end of an open spline there is no opposite handle.
+    #[allow(clippy::indexing_slicing)]
+    fn constrain_opposite_handle(&mut self, control_type: ControlType, curve: usize) {
+        let count = self.curves.len();
+        let (node, opposite) = match control_type {
+            StartHandle if curve > 0 => (curve, curve - 1),
+            StartHandle if self.is_closed => (0, count - 1),
+            EndHandle if curve + 1 < count => (curve + 1, curve + 1),
+            EndHandle if self.is_closed => (0, 0),
+            _ => return,
+        };
+        let continuity = self.get_continuity(node);
+        let (anchor, handle) = if control_type == StartHandle {
+            (self.curves[curve].start, self.curves[curve].start_handle)
+        } else {
+            (self.curves[curve].end, self.curves[curve].end_handle)
+        };
+        let opposite_handle = if control_type == StartHandle {
+            &mut self.curves[opposite].end_handle
+        } else {
+            &mut self.curves[opposite].start_handle
+        };
+        match continuity {
+            NodeContinuity::Corner => {}
+            NodeContinuity::Smooth => {
+                let direction = (anchor - handle).normalize_or_zero();
+                if direction == Vec3::ZERO {
+                    return;
                 }
+                let distance = opposite_handle.distance(anchor);
+                *opposite_handle = anchor + direction * distance;
+            }
+            NodeContinuity::Symmetric => {
+                *opposite_handle = anchor + (anchor - handle);
             }
         }
     }
 
     /// Reverse the direction of the spline.
     pub fn reverse(&mut self) {
+        let mut continuities = self.get_continuities();
+        continuities.reverse();
+        if self.is_closed {
+            continuities.rotate_right(1);
+        }
+        self.continuities = continuities;
         self.curves.reverse();
         for curve in self.curves.iter_mut() {
             curve.reverse();
@@ -344,21 +525,127 @@ impl CubicBezierSpline {
     /// - <https://en.wikipedia.org/wiki/De_Casteljau%27s_algorithm>
     ///
     /// May fail if the param is too close to the start or end of the spline.
+    ///
+    /// If the spline is closed then both parts are open and meet at the start.
     pub fn split_at_param(
         mut self,
         param: f32,
     ) -> Result<[CubicBezierSpline; 2], CubicBezierSplineError> {
         let (index, param) = self.get_curve_index_at_param(param);
+        let mut continuities = self.get_continuities();
+        if self.is_closed {
+            continuities.push(self.get_continuity(0));
+        }
         let mut right: Vec<_> = self.curves.drain(index + 1..).collect();
         let curve = self.curves.pop().expect("Vec should not be empty");
         let mut left = self.curves;
         let [c0, c1] = curve.split_at_param(param).map_err(Curve)?;
         left.push(c0);
         right.insert(0, c1);
-        Ok([
-            CubicBezierSpline::new(left)?,
-            CubicBezierSpline::new(right)?,
-        ])
+        let mut left = CubicBezierSpline::new(left)?;
+        left.continuities = continuities
+            .iter()
+            .take(index + 1)
+            .copied()
+            .chain(once(NodeContinuity::Smooth))
+            .collect();
+        let mut right = CubicBezierSpline::new(right)?;
+        right.continuities = once(NodeContinuity::Smooth)
+            .chain(continuities.iter().skip(index + 1).copied())
+            .collect();
+        Ok([left, right])
+    }
+
+    /// Insert a node at param by splitting the curve in two.
+    ///
+    /// The shape of the spline is unchanged.
+    ///
+    /// Returns the index of the inserted node.
+    pub fn insert_node(&mut self, param: f32) -> Result<usize, CubicBezierSplineError> {
+        if param <= 0.0 || param >= 1.0 {
+            return Err(InvalidParam(param));
+        }
+        let (index, curve_param) = self.get_curve_index_at_param(param);
+        let curve = self.curves.get(index).ok_or(InvalidParam(param))?.clone();
+        let curves = curve.split_at_param(curve_param).map_err(Curve)?;
+        let mut continuities = self.get_continuities();
+        continuities.insert(index + 1, NodeContinuity::Smooth);
+        self.continuities = continuities;
+        self.curves.splice(index..=index, curves);
+        Ok(index + 1)
+    }
+
+    /// Remove the node at `index` by merging the curves either side of it.
+    ///
+    /// The outer handles are scaled by the proportion of the length either side of the node
+    /// so removing a node that was inserted into a uniform curve restores the original.
+    ///
+    /// The first or last node of an open spline is removed along with its curve.
+    #[allow(clippy::indexing_slicing)]
+    pub fn remove_node(&mut self, index: usize) -> Result<(), CubicBezierSplineError> {
+        let count = self.curves.len();
+        if index >= self.get_node_count() {
+            return Err(InvalidNode(index));
+        }
+        let minimum = if self.is_closed { 3 } else { 2 };
+        if count < minimum {
+            return Err(NoCurves);
+        }
+        let mut continuities = self.get_continuities();
+        continuities.remove(index);
+        if self.is_closed && index == 0 {
+            let curve = merge_curves(&self.curves[count - 1], &self.curves[0])?;
+            self.curves.remove(0);
+            self.curves[count - 2] = curve;
+        } else if index == 0 {
+            self.curves.remove(0);
+        } else if index == count {
+            self.curves.pop();
+        } else {
+            let curve = merge_curves(&self.curves[index - 1], &self.curves[index])?;
+            self.curves.splice(index - 1..=index, [curve]);
+        }
+        self.continuities = continuities;
+        Ok(())
+    }
+
+    /// Append the curves of `other` to the end of the spline.
+    ///
+    /// The start of `other` must be connected to the end of the spline and neither may be closed.
+    pub fn append(&mut self, other: CubicBezierSpline) -> Result<(), CubicBezierSplineError> {
+        if self.is_closed || other.is_closed {
+            return Err(Closed);
+        }
+        let end = self.get_end();
+        let start = other.get_start();
+        if !start.abs_diff_eq(end, CONNECTION_TOLERANCE) {
+            return Err(NotConnected(self.curves.len() - 1, start, end));
+        }
+        let mut continuities = self.get_continuities();
+        continuities.extend(other.get_continuities().into_iter().skip(1));
+        self.continuities = continuities;
+        self.curves.extend(other.curves);
+        Ok(())
+    }
+
+    /// Prepend the curves of `other` to the start of the spline.
+    ///
+    /// The end of `other` must be connected to the start of the spline and neither may be closed.
+    pub fn prepend(&mut self, other: CubicBezierSpline) -> Result<(), CubicBezierSplineError> {
+        if self.is_closed || other.is_closed {
+            return Err(Closed);
+        }
+        let end = other.get_end();
+        let start = self.get_start();
+        if !start.abs_diff_eq(end, CONNECTION_TOLERANCE) {
+            return Err(NotConnected(other.curves.len() - 1, start, end));
+        }
+        let mut continuities = other.get_continuities();
+        continuities.pop();
+        continuities.extend(self.get_continuities());
+        self.continuities = continuities;
+        self.curves.splice(0..0, other.curves);
+        Ok(())
     }
 
     /// Flatten a [`CubicBezier`] into a polyline.
@@ -383,17 +670,31 @@ impl CubicBezierSpline {
     /// - <https://raphlinus.github.io/curves/2022/09/09/parallel-beziers.html>
     ///
     /// Kurbo's algorithm only handles offseting smooth curves.
+    ///
+    /// The continuity of each node is kept and the nodes added between offset curves are smooth.
     pub fn offset(
         &self,
         distance: f32,
         accuracy: f32,
     ) -> Result<CubicBezierSpline, CubicBezierSplineError> {
         let mut curves = Vec::new();
-        for curve in self.curves.iter() {
+        let mut continuities = Vec::new();
+        for (index, curve) in self.curves.iter().enumerate() {
             let mut offset = curve.offset(distance, accuracy).map_err(Conversion)?;
+            continuities.push(self.get_continuity(index));
+            continuities.extend(repeat_n(
+                NodeContinuity::Smooth,
+                offset.len().saturating_sub(1),
+            ));
             curves.append(&mut offset);
         }
-        CubicBezierSpline::new(curves)
+        continuities.push(self.get_continuity(self.curves.len()));
+        let mut spline = CubicBezierSpline::new(curves)?;
+        spline.continuities = continuities;
+        if self.is_closed {
+            spline.close()?;
+        }
+        Ok(spline)
     }
 
     /// Expand a stroke into a fill.
@@ -464,6 +765,87 @@ impl CubicBezierSpline {
     }
 }
 
+impl TryFrom<CubicBezierSplineData> for CubicBezierSpline {
+    type Error = CubicBezierSplineError;
+
+    /// Validate the curves, closure and continuities of a deserialized spline.
+    fn try_from(data: CubicBezierSplineData) -> Result<Self, Self::Error> {
+        let curves = data
+            .curves
+            .into_iter()
+            .map(|curve| {
+                CubicBezier::new(curve.start, curve.start_handle, curve.end_handle, curve.end)
+                    .map_err(Curve)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut spline = CubicBezierSpline::new(curves)?;
+        if data.is_closed {
+            let start = spline.get_start();
+            let end = spline.get_end();
+            if !start.abs_diff_eq(end, CONNECTION_TOLERANCE) {
+                return Err(NotConnected(spline.curves.len() - 1, start, end));
+            }
+            spline.is_closed = true;
+        }
+        if data.continuities.len() > spline.get_node_count() {
+            return Err(InvalidNode(data.continuities.len() - 1));
+        }
+        spline.continuities = data.continuities;
+        Ok(spline)
+    }
+}
+
+impl Display for CubicBezierSplineError {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            NoCurves => "Spline has no curves".to_owned(),
+            InvalidCounts(origins, handles) => {
+                format!("Unable to create a spline from {origins} origins and {handles} handles")
+            }
+            NotConnected(index, start, end) => {
+                format!("Curve {index} ends at {end} but the next curve starts at {start}")
+            }
+            InvalidParam(param) => format!("Param is out of range: {param}"),
+            InvalidNode(index) => format!("Node is out of range: {index}"),
+            Closed => "Spline is closed".to_owned(),
+            Curve(e) => format!("Curve is invalid: {e:?}"),
+            Conversion(e) => format!("Failed to convert curve: {e:?}"),
+        };
+        formatter.write_str(&reason)
+    }
+}
+
+/// Merge two adjacent curves into one.
+///
+/// The outer handles are scaled by the proportion of the length of each curve.
+///
+/// If either curve has no length the handles of the other curve are kept unscaled.
+fn merge_curves(
+    previous: &CubicBezier,
+    next: &CubicBezier,
+) -> Result<CubicBezier, CubicBezierSplineError> {
+    let previous_length = previous.get_length(LENGTH_ACCURACY);
+    let next_length = next.get_length(LENGTH_ACCURACY);
+    if next_length < LENGTH_ACCURACY {
+        return CubicBezier::new(
+            previous.start,
+            previous.start_handle,
+            previous.end_handle,
+            next.end,
+        )
+        .map_err(Curve);
+    }
+    if previous_length < LENGTH_ACCURACY {
+        return CubicBezier::new(previous.start, next.start_handle, next.end_handle, next.end)
+            .map_err(Curve);
+    }
+    let ratio = previous_length / (previous_length + next_length);
+    let start_handle = previous.start + (previous.start_handle - previous.start) / ratio;
+    let end_handle = next.end + (next.end_handle - next.end) / (1.0 - ratio);
+    CubicBezier::new(previous.start, start_handle, end_handle, next.end).map_err(Curve)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -515,4 +897,361 @@ mod tests {
         assert_eq!(result.get_curves().len(), 2);
         Ok(())
     }
+
+    #[test]
+    #[allow(clippy::indexing_slicing)]
+    fn insert_node() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        let before = spline.get_point_at_param(0.25);
+
+        // Act
+        let index = spline.insert_node(0.25)?;
+
+        // Assert
+        assert_eq!(index, 1);
+        assert_eq!(spline.get_curves().len(), 3);
+        let node = spline.get_nodes()[1];
+        assert!(node.abs_diff_eq(before, CONNECTION_TOLERANCE));
+        assert!(CubicBezierSpline::new(spline.to_curves()).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn insert_node_at_end() {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+
+        // Act
+        let result = spline.insert_node(1.0);
+
+        // Assert
+        assert!(matches!(result, Err(InvalidParam(_))));
+        assert_eq!(spline.get_curves().len(), 2);
+    }
+
+    #[test]
+    fn remove_inserted_node() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let line = Line::new(Vec3::ZERO, Vec3::new(30.0, 0.0, 0.0));
+        let curve = CubicBezier::from_line(line).map_err(Curve)?;
+        let mut spline = CubicBezierSpline::new(vec![curve.clone()])?;
+        let index = spline.insert_node(0.4)?;
+
+        // Act
+        spline.remove_node(index)?;
+
+        // Assert
+        assert_eq!(spline.get_curves().len(), 1);
+        for (actual, expected) in spline.get_controls().iter().zip(curve.get_controls()) {
+            assert!(actual.abs_diff_eq(expected, CONNECTION_TOLERANCE));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn merge_zero_length_curves() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let line = Line::new(Vec3::ZERO, Vec3::new(30.0, 0.0, 0.0));
+        let curve = CubicBezier::from_line(line).map_err(Curve)?;
+        let before = CubicBezier {
+            start: Vec3::ZERO,
+            start_handle: Vec3::ZERO,
+            end_handle: Vec3::ZERO,
+            end: Vec3::ZERO,
+        };
+        let after = CubicBezier {
+            start: curve.end,
+            start_handle: curve.end,
+            end_handle: curve.end,
+            end: curve.end,
+        };
+
+        // Act
+        let merged_before = merge_curves(&before, &curve)?;
+        let merged_after = merge_curves(&curve, &after)?;
+
+        // Assert
+        assert_eq!(merged_before.get_controls(), curve.get_controls());
+        assert_eq!(merged_after.get_controls(), curve.get_controls());
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::indexing_slicing)]
+    fn remove_end_nodes() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        let middle = spline.get_nodes()[1];
+
+        // Act
+        spline.remove_node(2)?;
+        let result = spline.remove_node(0);
+
+        // Assert
+        assert!(matches!(result, Err(NoCurves)));
+        assert_eq!(spline.get_curves().len(), 1);
+        assert_eq!(spline.get_end(), middle);
+        assert!(matches!(spline.remove_node(2), Err(InvalidNode(2))));
+        Ok(())
+    }
+
+    #[test]
+    fn append_and_prepend() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let [first, second] = CubicBezierSpline::example().split_at_param(0.25)?;
+        let mut spline = second.clone();
+
+        // Act
+        spline.prepend(first.clone())?;
+        let appended = spline.append(first);
+
+        // Assert
+        assert_eq!(spline.get_curves().len(), 3);
+        assert!(matches!(appended, Err(NotConnected(..))));
+        assert!(CubicBezierSpline::new(spline.to_curves()).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn update_control_corner() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        spline.set_continuity(1, NodeContinuity::Corner)?;
+        let before = spline.get_control(EndHandle, 0).unwrap();
+
+        // Act
+        spline.update_control(StartHandle, 1, Vec3::new(60.0, 50.0, 0.0));
+
+        // Assert
+        assert_eq!(spline.get_control(EndHandle, 0).unwrap(), before);
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn update_control_smooth() {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        let anchor = spline.get_control(Start, 1).unwrap();
+        let distance = spline.get_control(EndHandle, 0).unwrap().distance(anchor);
+
+        // Act
+        spline.update_control(StartHandle, 1, Vec3::new(50.0, 50.0, 0.0));
+
+        // Assert
+        let expected = anchor + Vec3::new(0.0, -distance, 0.0);
+        let actual = spline.get_control(EndHandle, 0).unwrap();
+        assert!(actual.abs_diff_eq(expected, CONNECTION_TOLERANCE));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn update_control_symmetric() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        spline.set_continuity(1, NodeContinuity::Symmetric)?;
+
+        // Act
+        spline.update_control(EndHandle, 0, Vec3::new(40.0, 45.0, 0.0));
+
+        // Assert
+        let expected = Vec3::new(60.0, 35.0, 0.0);
+        let actual = spline.get_control(StartHandle, 1).unwrap();
+        assert!(actual.abs_diff_eq(expected, CONNECTION_TOLERANCE));
+        Ok(())
+    }
+
+    #[test]
+    fn continuities_follow_nodes() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        spline.set_continuity(2, NodeContinuity::Corner)?;
+
+        // Act
+        spline.insert_node(0.25)?;
+        spline.reverse();
+
+        // Assert
+        assert_eq!(
+            spline.get_continuities(),
+            vec![
+                NodeContinuity::Corner,
+                NodeContinuity::Smooth,
+                NodeContinuity::Smooth,
+                NodeContinuity::Smooth,
+            ]
+        );
+        spline.remove_node(0)?;
+        assert_eq!(spline.get_continuity(0), NodeContinuity::Smooth);
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn update_control_smooth_onto_anchor() {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        let anchor = spline.get_control(End, 0).unwrap();
+        let expected = spline.get_control(StartHandle, 1).unwrap();
+
+        // Act
+        spline.update_control(EndHandle, 0, anchor);
+
+        // Assert
+        let actual = spline.get_control(StartHandle, 1).unwrap();
+        assert!(actual.is_finite());
+        assert!(actual.abs_diff_eq(expected, CONNECTION_TOLERANCE));
+    }
+
+    #[test]
+    fn offset_keeps_continuities() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        spline.set_continuity(1, NodeContinuity::Corner)?;
+
+        // Act
+        let result = spline.offset(2.0, 0.01)?;
+
+        // Assert
+        let continuities = result.get_continuities();
+        assert_eq!(continuities.len(), result.get_node_count());
+        let corners: Vec<_> = continuities
+            .iter()
+            .enumerate()
+            .filter(|(_, continuity)| **continuity == NodeContinuity::Corner)
+            .map(|(index, _)| index)
+            .collect();
+        assert_eq!(corners.len(), 1);
+        let corner = *corners.first().expect("should be a corner");
+        let expected = *spline.get_nodes().get(1).expect("should be a node");
+        let actual = *result.get_nodes().get(corner).expect("should be a node");
+        assert!(actual.distance(expected) < 2.0 + CONNECTION_TOLERANCE);
+        Ok(())
+    }
+
+    #[test]
+    fn close_with_line() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        let start = spline.get_start();
+
+        // Act
+        spline.close()?;
+
+        // Assert
+        assert!(spline.is_closed());
+        assert_eq!(spline.get_curves().len(), 3);
+        assert_eq!(spline.get_end(), start);
+        assert_eq!(spline.get_nodes().len(), 3);
+        assert_eq!(spline.get_continuities().len(), 3);
+        assert!(spline
+            .get_point_at_param(1.0)
+            .abs_diff_eq(start, CONNECTION_TOLERANCE));
+        Ok(())
+    }
+
+    #[test]
+    fn close_connected() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let origins = vec![
+            Vec3::ZERO,
+            Vec3::new(50.0, 0.0, 0.0),
+            Vec3::new(25.0, 50.0, 0.0),
+            Vec3::new(0.0, 0.005, 0.0),
+        ];
+        let handles = vec![
+            Vec3::new(10.0, -10.0, 0.0),
+            Vec3::new(60.0, 10.0, 0.0),
+            Vec3::new(15.0, 50.0, 0.0),
+            Vec3::new(10.0, -10.0, 0.0),
+        ];
+        let mut spline = CubicBezierSpline::by_origins_and_handles(origins, handles)?;
+        let length = spline.get_length(LENGTH_ACCURACY);
+
+        // Act
+        spline.close()?;
+
+        // Assert
+        assert_eq!(spline.get_curves().len(), 3);
+        assert_eq!(spline.get_end(), Vec3::ZERO);
+        let wrapped = spline
+            .get_param_at_length(length + 1.0, LENGTH_ACCURACY)
+            .expect("length should wrap around");
+        let expected = spline
+            .get_param_at_length(1.0, LENGTH_ACCURACY)
+            .expect("length should be in range");
+        assert!((wrapped - expected).abs() < 0.001);
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn update_closed_start() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        spline.close()?;
+        let point = Vec3::new(-5.0, 75.0, 0.0);
+
+        // Act
+        spline.update_control(Start, 0, point);
+        spline.update_control(StartHandle, 0, Vec3::new(20.0, 75.0, 0.0));
+
+        // Assert
+        assert_eq!(spline.get_end(), point);
+        let end_handle = spline.get_control(EndHandle, 2).unwrap();
+        assert!((end_handle - point)
+            .normalize()
+            .abs_diff_eq(Vec3::NEG_X, 0.001));
+        Ok(())
+    }
+
+    #[test]
+    fn remove_closed_start() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        spline.close()?;
+        spline.set_continuity(1, NodeContinuity::Corner)?;
+        let nodes = spline.get_nodes();
+
+        // Act
+        spline.remove_node(0)?;
+
+        // Assert
+        assert!(spline.is_closed());
+        assert_eq!(
+            spline.get_nodes(),
+            nodes.into_iter().skip(1).collect::<Vec<_>>()
+        );
+        assert_eq!(spline.get_continuity(0), NodeContinuity::Corner);
+        assert!(matches!(spline.remove_node(0), Err(NoCurves)));
+        Ok(())
+    }
+
+    #[test]
+    fn reverse_closed() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        spline.close()?;
+        spline.set_continuity(1, NodeContinuity::Corner)?;
+
+        // Act
+        spline.reverse();
+
+        // Assert
+        assert_eq!(
+            spline.get_continuities(),
+            vec![
+                NodeContinuity::Smooth,
+                NodeContinuity::Smooth,
+                NodeContinuity::Corner,
+            ]
+        );
+        assert!(matches!(
+            spline.append(CubicBezierSpline::example()),
+            Err(Closed)
+        ));
+        Ok(())
+    }
 }
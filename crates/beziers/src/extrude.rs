@@ -0,0 +1,511 @@
+use crate::constants::*;
+use crate::*;
+use bevy::prelude::*;
+use konstruo_geometry::*;
+use ExtrudeError::*;
+
+/// An extrusion of a [`Profile`] along a [`CubicBezierSpline`].
+///
+/// Each vertex of the profile forms an edge along an offset of the spline so edges are
+/// parallel to the spline within [`FLATTEN_TOLERANCE`].
+///
+/// Each curve is offset separately so at a [`NodeContinuity::Corner`] the outer edges are
+/// joined by a bevel and the inner edges cross over themselves.
+///
+/// A loft interpolates from a start profile to an end profile along the length of the spline.
+///
+/// If the spline is closed the extrusion forms a loop without end caps.
+///
+/// All edges will have the same number of vertices.
+#[derive(Clone)]
+pub struct Extrude {
+    /// Profile at the start.
+    start: Profile,
+    /// Profile at the end.
+    end: Profile,
+    /// Vertices of each edge in the order of the [`Profile`] vertices.
+    edges: Vec<Polyline>,
+    /// Is the spline closed so the ends meet?
+    is_looped: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ExtrudeError {
+    /// The profile has too few vertices.
+    TooFewVertices(usize),
+    /// The start and end profile do not have the same vertex count or closure.
+    Mismatch(usize, usize),
+    /// The offset of the spline could not be converted.
+    Offset(F32ConversionError),
+}
+
+impl Extrude {
+    /// Create an [`Extrude`] of a [`Profile`] along a spline.
+    ///
+    /// The `x` of each profile vertex is the offset to the left of the spline and the `y`
+    /// is the height.
+    pub fn new(spline: &CubicBezierSpline, profile: Profile) -> Result<Self, ExtrudeError> {
+        Self::loft(spline, profile.clone(), profile)
+    }
+
+    /// Create an [`Extrude`] that lofts from the `start` [`Profile`] to the `end` [`Profile`]
+    /// along a spline.
+    ///
+    /// Both profiles must have the same number of vertices and both be open or closed.
+    pub fn loft(
+        spline: &CubicBezierSpline,
+        start: Profile,
+        end: Profile,
+    ) -> Result<Self, ExtrudeError> {
+        let count = start.get_vertices().len();
+        let minimum = if start.is_closed() { 3 } else { 2 };
+        if count < minimum {
+            return Err(TooFewVertices(count));
+        }
+        if end.get_vertices().len() != count || end.is_closed() != start.is_closed() {
+            return Err(Mismatch(count, end.get_vertices().len()));
+        }
+        let is_looped = spline.is_closed();
+        let mut distances: Vec<f32> = Vec::new();
+        for vertex in start.get_vertices().iter().chain(end.get_vertices()) {
+            if !distances
+                .iter()
+                .any(|distance| (distance - vertex.x).abs() < f32::EPSILON)
+            {
+                distances.push(vertex.x);
+            }
+        }
+        let mut polylines = vec![Polyline::new(spline.flatten(FLATTEN_TOLERANCE))];
+        for distance in &distances {
+            polylines.push(Polyline::new(get_offset(spline, *distance)?));
+        }
+        Polyline::equalize_all_vertices_count(&mut polylines);
+        let (center, offsets) = polylines.split_first().expect("center should exist");
+        let fractions = get_length_fractions(center.get_vertices());
+        let get_vertices = |x: f32| {
+            let index = distances
+                .iter()
+                .position(|distance| (distance - x).abs() < f32::EPSILON)
+                .expect("offset should exist");
+            offsets
+                .get(index)
+                .expect("offset should exist")
+                .get_vertices()
+        };
+        let edges = start
+            .get_vertices()
+            .iter()
+            .zip(end.get_vertices())
+            .map(|(start, end)| {
+                let vertices = get_vertices(start.x)
+                    .iter()
+                    .zip(get_vertices(end.x))
+                    .zip(&fractions)
+                    .map(|((a, b), fraction)| {
+                        let height = start.y + (end.y - start.y) * fraction;
+                        a.lerp(*b, *fraction) + Vec3::Z * height
+                    })
+                    .collect();
+                Polyline::new(vertices)
+            })
+            .collect();
+        Ok(Self {
+            start,
+            end,
+            edges,
+            is_looped,
+        })
+    }
+
+    /// Check that no edge crosses over itself.
+    ///
+    /// On a curve tighter than the offset of a profile vertex the edge will loop back
+    /// over itself.
+    pub fn validate(&self) -> Result<(), PolylineIntersectionError> {
+        for edge in &self.edges {
+            if let Some(point) = edge.get_self_intersection() {
+                return Err(PolylineIntersectionError::SelfIntersection(point));
+            }
+        }
+        Ok(())
+    }
+
+    /// Collapse any loops where an edge crosses over itself.
+    pub fn trim_loops(&mut self) {
+        for edge in &mut self.edges {
+            edge.collapse_loops();
+        }
+    }
+
+    /// Get the vertex at the start of each edge.
+    fn get_front_vertices(&self) -> Vec<Vec3> {
+        self.edges
+            .iter()
+            .map(|edge| {
+                *edge
+                    .get_vertices()
+                    .first()
+                    .expect("extrude edge should have vertices")
+            })
+            .collect()
+    }
+
+    /// Get the vertex at the end of each edge.
+    fn get_back_vertices(&self) -> Vec<Vec3> {
+        self.edges
+            .iter()
+            .map(|edge| {
+                *edge
+                    .get_vertices()
+                    .last()
+                    .expect("extrude edge should have vertices")
+            })
+            .collect()
+    }
+
+    /// Create a 3D [`TriangleList`] of the faces between each edge and the end caps.
+    ///
+    /// Faces are wound so their normals point out of a closed profile or to the right of
+    /// an open profile.
+    ///
+    /// The edges are not checked for intersections so on tight curves triangles may be
+    /// inverted. Use [`Extrude::try_to_triangle_list`] to check.
+    ///
+    /// The [`Extrude`] is consumed so minimal cloning takes places.
+    #[must_use]
+    pub fn to_triangle_list(self) -> TriangleList {
+        let mut triangles = self.get_caps();
+        for (left, right) in self.get_faces() {
+            triangles.merge(TriangleList::between_polylines(left, right));
+        }
+        triangles
+    }
+
+    /// Create a 3D [`TriangleList`] of the faces between each edge and the end caps after
+    /// checking that edges do not cross over themselves or each other.
+    ///
+    /// If `trim` is true then any loops are collapsed before checking.
+    pub fn try_to_triangle_list(
+        mut self,
+        trim: bool,
+    ) -> Result<TriangleList, PolylineIntersectionError> {
+        if trim {
+            self.trim_loops();
+        }
+        let mut triangles = self.get_caps();
+        for (left, right) in self.get_faces() {
+            triangles.merge(TriangleList::between_polylines_checked(left, right, false)?);
+        }
+        Ok(triangles)
+    }
+
+    /// Get the [`TriangleList`] of the end caps of a closed profile.
+    ///
+    /// A looped extrusion has no ends so no caps.
+    fn get_caps(&self) -> TriangleList {
+        let mut triangles = TriangleList::default();
+        if self.start.is_closed() && !self.is_looped {
+            triangles.merge(get_cap(&self.start, &self.get_front_vertices(), false));
+            triangles.merge(get_cap(&self.end, &self.get_back_vertices(), true));
+        }
+        triangles
+    }
+
+    /// Get the pairs of edges that form each face.
+    ///
+    /// Pairs are ordered so that [`TriangleList::between_polylines`] winds them outwards.
+    #[allow(clippy::indexing_slicing)]
+    fn get_faces(&self) -> Vec<(Polyline, Polyline)> {
+        let count = self.edges.len();
+        let faces = if self.start.is_closed() {
+            count
+        } else {
+            count - 1
+        };
+        (0..faces)
+            .map(|i| (self.edges[(i + 1) % count].clone(), self.edges[i].clone()))
+            .collect()
+    }
+
+    /// Get the edges along the spline and the outlines of the profile at each end.
+    #[must_use]
+    pub fn get_edges(&self) -> Vec<Polyline> {
+        let mut edges = self.edges.clone();
+        if self.is_looped {
+            return edges;
+        }
+        for mut vertices in [self.get_front_vertices(), self.get_back_vertices()] {
+            if self.start.is_closed() {
+                if let Some(first) = vertices.first() {
+                    vertices.push(*first);
+                }
+            }
+            edges.push(Polyline::new(vertices));
+        }
+        edges
+    }
+}
+
+/// Get the vertices of an offset of the spline to the left by distance.
+///
+/// Each curve is offset and flattened separately so the ends of adjacent curves are joined
+/// by a straight line at a [`NodeContinuity::Corner`].
+fn get_offset(spline: &CubicBezierSpline, distance: f32) -> Result<Vec<Vec3>, ExtrudeError> {
+    if distance.abs() < f32::EPSILON {
+        return Ok(spline.flatten(FLATTEN_TOLERANCE));
+    }
+    let mut vertices: Vec<Vec3> = Vec::new();
+    for curve in spline.get_curves() {
+        let curves = curve
+            .offset(distance, FLATTEN_TOLERANCE)
+            .map_err(ExtrudeError::Offset)?;
+        for curve in curves {
+            let points = CubicBezierSpline::new_unchecked(vec![curve]).flatten(FLATTEN_TOLERANCE);
+            let is_connected = vertices
+                .last()
+                .zip(points.first())
+                .is_some_and(|(last, first)| last.abs_diff_eq(*first, CONNECTION_TOLERANCE));
+            let skip = usize::from(is_connected);
+            vertices.extend(points.into_iter().skip(skip));
+        }
+    }
+    if spline.is_closed() {
+        if let Some(first) = vertices.first().copied() {
+            if vertices
+                .last()
+                .is_some_and(|last| !last.abs_diff_eq(first, CONNECTION_TOLERANCE))
+            {
+                vertices.push(first);
+            }
+        }
+    }
+    Ok(vertices)
+}
+
+/// Get the fraction of the total length at each vertex of a polyline.
+#[allow(clippy::indexing_slicing)]
+fn get_length_fractions(vertices: &[Vec3]) -> Vec<f32> {
+    let mut lengths = Vec::with_capacity(vertices.len());
+    let mut total = 0.0;
+    for (i, vertex) in vertices.iter().enumerate() {
+        if i > 0 {
+            total += vertex.distance(vertices[i - 1]);
+        }
+        lengths.push(total);
+    }
+    if total > 0.0 {
+        for length in &mut lengths {
+            *length /= total;
+        }
+    }
+    lengths
+}
+
+/// Create a [`TriangleList`] to cap the end of an [`Extrude`] by triangulating the profile.
+#[allow(clippy::indexing_slicing)]
+fn get_cap(profile: &Profile, vertices: &[Vec3], is_back: bool) -> TriangleList {
+    let triangles = profile
+        .get_triangles()
+        .into_iter()
+        .map(|[a, b, c]| {
+            if is_back {
+                Triangle::new([vertices[a], vertices[b], vertices[c]])
+            } else {
+                Triangle::new([vertices[c], vertices[b], vertices[a]])
+            }
+        })
+        .collect();
+    TriangleList::new(triangles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CubicBezier;
+
+    #[test]
+    fn extrude_closed() -> Result<(), ExtrudeError> {
+        // Arrange
+        let spline = CubicBezierSpline::example_2();
+        let profile = Profile::from_vec6(Vec6 {
+            left: -1.0,
+            right: 1.0,
+            top: 0.5,
+            ..default()
+        });
+
+        // Act
+        let extrude = Extrude::new(&spline, profile)?;
+        let edges = extrude.get_edges();
+        let triangles = extrude.to_triangle_list();
+
+        // Assert
+        assert_eq!(edges.len(), 6);
+        let segments = edges
+            .first()
+            .expect("should have edges")
+            .get_vertices()
+            .len()
+            - 1;
+        assert_eq!(triangles.get_triangles().len(), segments * 2 * 4 + 2 * 2);
+        let mut top = triangles
+            .get_triangles()
+            .iter()
+            .filter(|triangle| triangle.get_vertices().iter().all(|vertex| vertex.z > 0.0))
+            .filter(|triangle| triangle.get_normal().z.abs() > 0.99);
+        assert!(top.clone().count() > 0);
+        assert!(top.all(|triangle| triangle.get_normal().z > 0.0));
+        Ok(())
+    }
+
+    #[test]
+    fn extrude_looped() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let mut spline = CubicBezierSpline::example();
+        spline.close()?;
+        let profile = Profile::from_vec6(Vec6 {
+            left: -1.0,
+            right: 1.0,
+            top: 0.5,
+            ..default()
+        });
+
+        // Act
+        let extrude = Extrude::new(&spline, profile).expect("profile should be valid");
+        let edges = extrude.get_edges();
+        let triangles = extrude.to_triangle_list();
+
+        // Assert
+        assert_eq!(edges.len(), 4);
+        let vertices = edges.first().expect("should have edges").get_vertices();
+        let first = vertices.first().expect("should have vertices");
+        let last = vertices.last().expect("should have vertices");
+        assert!(first.abs_diff_eq(*last, CONNECTION_TOLERANCE));
+        let segments = vertices.len() - 1;
+        assert_eq!(triangles.get_triangles().len(), segments * 2 * 4);
+        Ok(())
+    }
+
+    #[test]
+    fn extrude_tight_curve() -> Result<(), PolylineIntersectionError> {
+        // Arrange
+        let spline = CubicBezierSpline::new(vec![CubicBezier::new(
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(10.0, 0.0, 0.0),
+            Vec3::new(10.5, 0.5, 0.0),
+            Vec3::new(10.0, 10.0, 0.0),
+        )
+        .expect("should be valid")])
+        .expect("should be valid");
+        let profile = Profile::open(vec![Vec2::new(3.0, 0.0), Vec2::new(3.0, 1.0)]);
+        let extrude = Extrude::new(&spline, profile).expect("should be valid");
+
+        // Act
+        let validation = extrude.validate();
+        let untrimmed = extrude.clone().try_to_triangle_list(false);
+        let trimmed = extrude.try_to_triangle_list(true);
+
+        // Assert
+        assert!(matches!(
+            validation,
+            Err(PolylineIntersectionError::SelfIntersection(_))
+        ));
+        assert!(untrimmed.is_err());
+        assert!(!trimmed?.get_triangles().is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn extrude_mismatch() {
+        // Arrange
+        let spline = CubicBezierSpline::example_2();
+        let start = Profile::open(vec![Vec2::ZERO, Vec2::X]);
+        let end = Profile::open(vec![Vec2::ZERO, Vec2::X, Vec2::Y]);
+
+        // Act
+        let result = Extrude::loft(&spline, start, end);
+
+        // Assert
+        assert_eq!(result.err(), Some(Mismatch(2, 3)));
+    }
+
+    /// Get the distance of each vertex of an edge from the spline on the ground plane.
+    fn get_distances(spline: &CubicBezierSpline, edge: &Polyline) -> Vec<f32> {
+        edge.get_vertices()
+            .iter()
+            .map(|vertex| {
+                let vertex = vertex.with_z(0.0);
+                let param = spline.get_param_nearest_to(vertex, LENGTH_ACCURACY);
+                spline.get_point_at_param(param).distance(vertex)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn extrude_precision() -> Result<(), ExtrudeError> {
+        // Arrange
+        let spline = CubicBezierSpline::new(vec![CubicBezier::new(
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(20.0, 0.0, 0.0),
+            Vec3::new(30.0, 10.0, 0.0),
+            Vec3::new(30.0, 30.0, 0.0),
+        )
+        .expect("should be valid")])
+        .expect("should be valid");
+        let profile = Profile::open(vec![Vec2::new(-2.0, 0.0), Vec2::new(5.0, 0.0)]);
+
+        // Act
+        let extrude = Extrude::new(&spline, profile)?;
+
+        // Assert
+        let [right, left] = extrude.edges.as_slice() else {
+            unreachable!()
+        };
+        for distance in get_distances(&spline, right) {
+            assert!((distance - 2.0).abs() < 0.05, "{distance} != 2.0");
+        }
+        for distance in get_distances(&spline, left) {
+            assert!((distance - 5.0).abs() < 0.05, "{distance} != 5.0");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn extrude_corner() -> Result<(), ExtrudeError> {
+        // Arrange
+        let corner = Vec3::new(10.0, 0.0, 0.0);
+        let spline = CubicBezierSpline::new(vec![
+            CubicBezier::from_line(Line::new(Vec3::ZERO, corner)).expect("should be valid"),
+            CubicBezier::from_line(Line::new(corner, Vec3::new(10.0, 10.0, 0.0)))
+                .expect("should be valid"),
+        ])
+        .expect("should be valid");
+        let profile = Profile::open(vec![Vec2::new(-1.0, 0.0), Vec2::new(1.0, 0.0)]);
+
+        // Act
+        let extrude = Extrude::new(&spline, profile)?;
+
+        // Assert
+        let [outer, _] = extrude.edges.as_slice() else {
+            unreachable!()
+        };
+        let distances = get_distances(&spline, outer);
+        let max = distances.iter().copied().fold(0.0, f32::max);
+        assert!(
+            max < 1.01,
+            "outer corner should be bevelled not mitred: {max}"
+        );
+        let vertices = outer.get_vertices();
+        for expected in [Vec3::new(10.0, -1.0, 0.0), Vec3::new(11.0, 0.0, 0.0)] {
+            assert!(vertices
+                .iter()
+                .any(|vertex| vertex.abs_diff_eq(expected, 0.001)));
+        }
+        assert!(matches!(
+            extrude.validate(),
+            Err(PolylineIntersectionError::SelfIntersection(_))
+        ));
+        assert!(extrude.try_to_triangle_list(true).is_ok());
+        Ok(())
+    }
+}
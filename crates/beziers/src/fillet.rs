@@ -0,0 +1,243 @@
+use crate::constants::*;
+use crate::CubicBezier;
+use crate::*;
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+/// Angle in radians below which two tangents are considered parallel.
+const PARALLEL_TOLERANCE: f32 = 0.001;
+
+/// Maximum deflection angle of a fillet.
+///
+/// The setback of a fillet tends to infinity as the deflection approaches a U-turn.
+const MAX_FILLET_ANGLE: f32 = PI * 0.95;
+
+/// Params closer than this to the start or end of a spline are not split.
+const SPLIT_TOLERANCE: f32 = 0.000_1;
+
+impl CubicBezier {
+    /// Create a [`CubicBezier`] from `start` to `end` that is tangent continuous with the
+    /// `start_tangent` and `end_tangent` directions.
+    ///
+    /// Where the directions allow it the handles are sized so the curve approximates
+    /// a circular arc.
+    /// - <https://pomax.github.io/bezierinfo/#circles_cubic>
+    pub fn from_tangents(
+        start: Vec3,
+        start_tangent: Vec3,
+        end: Vec3,
+        end_tangent: Vec3,
+    ) -> Result<Self, CubicBezierError> {
+        let start_tangent = start_tangent.normalize_or_zero();
+        let end_tangent = end_tangent.normalize_or_zero();
+        let chord = start.distance(end);
+        let angle = start_tangent.angle_between(end_tangent);
+        let handle = if angle < PARALLEL_TOLERANCE {
+            chord / 3.0
+        } else {
+            let radius = chord / (2.0 * (angle * 0.5).sin());
+            4.0 / 3.0 * (angle * 0.25).tan() * radius
+        };
+        Self::new(
+            start,
+            start + start_tangent * handle,
+            end - end_tangent * handle,
+            end,
+        )
+    }
+
+    /// Get the tangent at the start.
+    fn get_start_tangent(&self) -> Vec3 {
+        self.get_tangent_at_param(0.0)
+    }
+
+    /// Get the tangent at the end.
+    fn get_end_tangent(&self) -> Vec3 {
+        self.get_tangent_at_param(1.0)
+    }
+}
+
+impl CubicBezierSpline {
+    /// Get the intersection with `other` that is nearest to `point`.
+    #[must_use]
+    pub fn get_intersection_nearest_to(
+        &self,
+        other: &CubicBezierSpline,
+        point: Vec3,
+        tolerance: f32,
+    ) -> Option<Vec3> {
+        self.get_intersections_with_spline(other, tolerance)?
+            .into_iter()
+            .min_by(|a, b| {
+                a.distance(point)
+                    .partial_cmp(&b.distance(point))
+                    .expect("should be able to compare")
+            })
+    }
+
+    /// Fillet the corner between the end of the spline and the start of `other`.
+    ///
+    /// If the splines intersect then they are trimmed at the intersection nearest the corner
+    /// and joined by a tangent continuous curve of approximately `radius`. The radius is reduced
+    /// if there isn't enough length to fit it.
+    ///
+    /// If the splines don't intersect then the end is joined to the start by a tangent
+    /// continuous curve.
+    pub fn fillet(
+        &self,
+        other: &CubicBezierSpline,
+        radius: f32,
+    ) -> Result<CubicBezierSpline, CubicBezierSplineError> {
+        let corner = self.get_end().midpoint(other.get_start());
+        let Some(intersection) =
+            self.get_intersection_nearest_to(other, corner, INTERSECTION_TOLERANCE)
+        else {
+            return self.clone().join_tangent(other.clone());
+        };
+        let param = self.get_param_nearest_to(intersection, INTERSECTION_ACCURACY);
+        let before = self.trim_end_at_param(param)?;
+        let param = other.get_param_nearest_to(intersection, INTERSECTION_ACCURACY);
+        let after = other.trim_start_at_param(param)?;
+        let angle = before
+            .get_end_tangent()
+            .angle_between(after.get_start_tangent())
+            .min(MAX_FILLET_ANGLE);
+        let before_length = before.get_length(LENGTH_ACCURACY);
+        let after_length = after.get_length(LENGTH_ACCURACY);
+        let setback = (radius * (angle * 0.5).tan()).min(before_length.min(after_length) * 0.5);
+        if setback < CONNECTION_TOLERANCE {
+            return before.join_tangent(after);
+        }
+        let before = match before.get_param_at_length(before_length - setback, LENGTH_ACCURACY) {
+            Some(param) => before.trim_end_at_param(param)?,
+            None => before,
+        };
+        let after = match after.get_param_at_length(setback, LENGTH_ACCURACY) {
+            Some(param) => after.trim_start_at_param(param)?,
+            None => after,
+        };
+        before.join_tangent(after)
+    }
+
+    /// Join the end of the spline to the start of `other` with a tangent continuous curve.
+    ///
+    /// If they're already connected then the curves are simply joined.
+    fn join_tangent(
+        self,
+        other: CubicBezierSpline,
+    ) -> Result<CubicBezierSpline, CubicBezierSplineError> {
+        let end = self.get_end();
+        let start = other.get_start();
+        let mut spline = self;
+        if !end.abs_diff_eq(start, CONNECTION_TOLERANCE) {
+            let before = spline
+                .get_curves()
+                .last()
+                .expect("should be at least one curve");
+            let after = other
+                .get_curves()
+                .first()
+                .expect("should be at least one curve");
+            let curve = CubicBezier::from_tangents(
+                end,
+                before.get_end_tangent(),
+                start,
+                after.get_start_tangent(),
+            )
+            .map_err(CubicBezierSplineError::Curve)?;
+            spline.append(CubicBezierSpline::new(vec![curve])?)?;
+        }
+        spline.append(other)?;
+        Ok(spline)
+    }
+
+    /// Remove the part of the spline after param.
+    fn trim_end_at_param(&self, param: f32) -> Result<CubicBezierSpline, CubicBezierSplineError> {
+        if param <= SPLIT_TOLERANCE || param >= 1.0 - SPLIT_TOLERANCE {
+            return Ok(self.clone());
+        }
+        let [before, _] = self.clone().split_at_param(param)?;
+        Ok(before)
+    }
+
+    /// Remove the part of the spline before param.
+    fn trim_start_at_param(&self, param: f32) -> Result<CubicBezierSpline, CubicBezierSplineError> {
+        if param <= SPLIT_TOLERANCE || param >= 1.0 - SPLIT_TOLERANCE {
+            return Ok(self.clone());
+        }
+        let [_, after] = self.clone().split_at_param(param)?;
+        Ok(after)
+    }
+
+    /// Get the tangent at the start.
+    fn get_start_tangent(&self) -> Vec3 {
+        self.get_curves()
+            .first()
+            .expect("should be at least one curve")
+            .get_start_tangent()
+    }
+
+    /// Get the tangent at the end.
+    fn get_end_tangent(&self) -> Vec3 {
+        self.get_curves()
+            .last()
+            .expect("should be at least one curve")
+            .get_end_tangent()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konstruo_geometry::Line;
+
+    fn line(start: Vec3, end: Vec3) -> CubicBezierSpline {
+        let curve = CubicBezier::from_line(Line::new(start, end)).expect("should be valid");
+        CubicBezierSpline::new(vec![curve]).expect("should be valid")
+    }
+
+    #[test]
+    fn fillet_intersecting() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let a = line(Vec3::new(-10.0, 0.0, 0.0), Vec3::new(2.0, 0.0, 0.0));
+        let b = line(Vec3::new(0.0, -2.0, 0.0), Vec3::new(0.0, 10.0, 0.0));
+
+        // Act
+        let result = a.fillet(&b, 2.0)?;
+
+        // Assert
+        let curves = result.get_curves();
+        assert_eq!(curves.len(), 3);
+        let fillet = curves.get(1).expect("should be three curves");
+        assert!(fillet
+            .get_control(ControlType::Start)
+            .abs_diff_eq(Vec3::new(-2.0, 0.0, 0.0), 0.05));
+        assert!(fillet
+            .get_control(ControlType::End)
+            .abs_diff_eq(Vec3::new(0.0, 2.0, 0.0), 0.05));
+        assert!(fillet.get_start_tangent().abs_diff_eq(Vec3::X, 0.001));
+        assert!(fillet.get_end_tangent().abs_diff_eq(Vec3::Y, 0.001));
+        Ok(())
+    }
+
+    #[test]
+    fn fillet_not_intersecting() -> Result<(), CubicBezierSplineError> {
+        // Arrange
+        let a = line(Vec3::new(-10.0, 0.0, 0.0), Vec3::ZERO);
+        let b = line(Vec3::new(0.0, 1.0, 0.0), Vec3::new(10.0, 1.0, 0.0));
+
+        // Act
+        let result = a.fillet(&b, 2.0)?;
+
+        // Assert
+        let curves = result.get_curves();
+        assert_eq!(curves.len(), 3);
+        let fillet = curves.get(1).expect("should be three curves");
+        assert!(fillet.get_start_tangent().abs_diff_eq(Vec3::X, 0.001));
+        assert!(fillet.get_end_tangent().abs_diff_eq(Vec3::X, 0.001));
+        assert!(result
+            .get_end()
+            .abs_diff_eq(Vec3::new(10.0, 1.0, 0.0), 0.001));
+        Ok(())
+    }
+}
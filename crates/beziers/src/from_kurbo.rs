@@ -12,7 +12,7 @@ pub trait KurboPointExtensions {
     fn to_vec3(&self) -> Result<Vec3, F32ConversionError>;
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum F32ConversionError {
     TooLarge(f64),
     TooSmall(f64),
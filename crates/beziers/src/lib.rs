@@ -1,8 +1,10 @@
 pub use control_type::*;
 pub use cubic_bezier::*;
 pub use cubic_bezier_spline::*;
+pub use extrude::*;
 pub use from_kurbo::*;
-pub use sweep::*;
+pub use node_continuity::*;
+pub use svg::*;
 #[allow(unused_imports)]
 pub use to_bevy::*;
 pub use to_kurbo::*;
@@ -11,7 +13,10 @@ pub mod constants;
 mod control_type;
 mod cubic_bezier;
 mod cubic_bezier_spline;
+mod extrude;
+mod fillet;
 mod from_kurbo;
-mod sweep;
+mod node_continuity;
+mod svg;
 mod to_bevy;
 mod to_kurbo;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// How the handles either side of a node of a [`crate::CubicBezierSpline`] are constrained.
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum NodeContinuity {
+    /// Handles move independently so the spline may have a kink at the node.
+    Corner,
+    /// Handles are collinear but may differ in length (G1).
+    #[default]
+    Smooth,
+    /// Handles are collinear and equal in length (C1).
+    Symmetric,
+}
@@ -0,0 +1,351 @@
+use crate::*;
+use kurbo::{Affine, BezPath, PathEl, Point, Shape, SvgParseError};
+use roxmltree::{Document, Error as XmlError, Node, ParsingOptions};
+use std::fmt::Write;
+use std::fs;
+use std::io::Error as IoError;
+use std::path::Path;
+use SvgError::*;
+
+/// Options to convert between SVG path data and [`CubicBezierSpline`].
+#[derive(Clone, Copy, Debug)]
+pub struct SvgOptions {
+    /// Metres per SVG user unit.
+    pub scale: f32,
+    /// Flip the Y axis.
+    ///
+    /// SVG is Y down whereas the ground plane is Y up when viewed from above.
+    pub flip_y: bool,
+}
+
+#[derive(Debug)]
+pub enum SvgError {
+    Io(IoError),
+    Xml(XmlError),
+    Parse(SvgParseError),
+    Transform(String),
+    Spline(CubicBezierSplineError),
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            flip_y: true,
+        }
+    }
+}
+
+impl SvgOptions {
+    /// Get the [`Affine`] from SVG user units to metres.
+    fn to_affine(self) -> Affine {
+        let scale = f64::from(self.scale);
+        let scale_y = if self.flip_y { -scale } else { scale };
+        Affine::scale_non_uniform(scale, scale_y)
+    }
+}
+
+impl CubicBezierSpline {
+    /// Read every `<path>` of an SVG file.
+    pub fn read_svg(path: &Path, options: SvgOptions) -> Result<Vec<CubicBezierSpline>, SvgError> {
+        let svg = fs::read_to_string(path).map_err(Io)?;
+        Self::from_svg(&svg, options)
+    }
+
+    /// Write the splines to an SVG file.
+    pub fn write_svg(
+        splines: &[CubicBezierSpline],
+        path: &Path,
+        options: SvgOptions,
+    ) -> Result<(), SvgError> {
+        fs::write(path, Self::to_svg(splines, options)).map_err(Io)
+    }
+
+    /// Parse the `d` attribute of every `<path>` in an SVG document.
+    ///
+    /// The `transform` of a path and its parent groups is applied.
+    pub fn from_svg(svg: &str, options: SvgOptions) -> Result<Vec<CubicBezierSpline>, SvgError> {
+        let parsing = ParsingOptions {
+            allow_dtd: true,
+            ..ParsingOptions::default()
+        };
+        let document = Document::parse_with_options(svg, parsing).map_err(Xml)?;
+        let mut splines = Vec::new();
+        for node in document
+            .descendants()
+            .filter(|node| node.has_tag_name("path"))
+        {
+            let Some(data) = node.attribute("d") else {
+                continue;
+            };
+            let path = BezPath::from_svg(data).map_err(Parse)?;
+            let affine = options.to_affine() * get_transform(node)?;
+            splines.append(&mut Self::from_bez_path(&(affine * path))?);
+        }
+        Ok(splines)
+    }
+
+    /// Parse SVG path data into a [`CubicBezierSpline`] for each subpath.
+    ///
+    /// Lines, quadratic curves and arcs are converted to cubic curves.
+    /// A subpath that ends with `Z` is closed.
+    pub fn from_svg_path_data(
+        data: &str,
+        options: SvgOptions,
+    ) -> Result<Vec<CubicBezierSpline>, SvgError> {
+        let path = options.to_affine() * BezPath::from_svg(data).map_err(Parse)?;
+        Self::from_bez_path(&path)
+    }
+
+    /// Convert a [`BezPath`] into a [`CubicBezierSpline`] for each subpath.
+    fn from_bez_path(path: &BezPath) -> Result<Vec<CubicBezierSpline>, SvgError> {
+        let mut splines = Vec::new();
+        for subpath in split_subpaths(path) {
+            if subpath.segments().next().is_none() {
+                continue;
+            }
+            let is_closed = subpath.elements().contains(&PathEl::ClosePath);
+            let mut spline = Self::from_kurbo(subpath).map_err(Spline)?;
+            if is_closed {
+                spline.close().map_err(Spline)?;
+            }
+            splines.push(spline);
+        }
+        Ok(splines)
+    }
+
+    /// Get the SVG path data of the spline.
+    #[must_use]
+    pub fn to_svg_path_data(&self, options: SvgOptions) -> String {
+        (options.to_affine().inverse() * self.to_closed_kurbo()).to_svg()
+    }
+
+    /// Create an SVG document with a `<path>` for each spline.
+    ///
+    /// The `viewBox` fits the bounds of every spline.
+    #[must_use]
+    pub fn to_svg(splines: &[CubicBezierSpline], options: SvgOptions) -> String {
+        let affine = options.to_affine().inverse();
+        let paths: Vec<BezPath> = splines
+            .iter()
+            .map(|spline| affine * spline.to_closed_kurbo())
+            .collect();
+        let bounds = paths
+            .iter()
+            .map(Shape::bounding_box)
+            .reduce(|a, b| a.union(b))
+            .unwrap_or_default();
+        let mut elements = String::new();
+        for path in &paths {
+            writeln!(
+                elements,
+                "  <path d=\"{}\" fill=\"none\" stroke=\"black\"/>",
+                path.to_svg()
+            )
+            .expect("should write to string");
+        }
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{} {} {} {}\">\n{elements}</svg>\n",
+            bounds.x0,
+            bounds.y0,
+            bounds.width(),
+            bounds.height()
+        )
+    }
+
+    /// Convert to a kurbo [`BezPath`] that ends with a `ClosePath` if the spline is closed.
+    fn to_closed_kurbo(&self) -> BezPath {
+        let mut path = self.to_kurbo();
+        if self.is_closed() {
+            path.close_path();
+        }
+        path
+    }
+}
+
+/// Get the [`Affine`] of the `transform` of a node and each of its ancestors.
+fn get_transform(node: Node) -> Result<Affine, SvgError> {
+    let mut affine = Affine::IDENTITY;
+    for ancestor in node.ancestors() {
+        if let Some(transform) = ancestor.attribute("transform") {
+            affine = parse_transform(transform)? * affine;
+        }
+    }
+    Ok(affine)
+}
+
+/// Parse an SVG `transform` attribute such as `translate(10 20) rotate(45)`.
+/// - <https://www.w3.org/TR/SVG11/coords.html#TransformAttribute>
+fn parse_transform(value: &str) -> Result<Affine, SvgError> {
+    let mut affine = Affine::IDENTITY;
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(affine);
+    }
+    let value = value
+        .strip_suffix(')')
+        .ok_or_else(|| Transform(value.to_owned()))?;
+    for function in value.split(')') {
+        let function = function.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        let invalid = || Transform(function.to_owned());
+        let (name, args) = function.split_once('(').ok_or_else(invalid)?;
+        let args = args
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|arg| !arg.is_empty())
+            .map(str::parse::<f64>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| invalid())?;
+        let transform = match (name.trim(), args.as_slice()) {
+            ("matrix", &[xx, yx, xy, yy, tx, ty]) => Affine::new([xx, yx, xy, yy, tx, ty]),
+            ("translate", &[x]) => Affine::translate((x, 0.0)),
+            ("translate", &[x, y]) => Affine::translate((x, y)),
+            ("scale", &[s]) => Affine::scale(s),
+            ("scale", &[x, y]) => Affine::scale_non_uniform(x, y),
+            ("rotate", &[angle]) => Affine::rotate(angle.to_radians()),
+            ("rotate", &[angle, x, y]) => {
+                Affine::rotate_about(angle.to_radians(), Point::new(x, y))
+            }
+            ("skewX", &[angle]) => Affine::skew(angle.to_radians().tan(), 0.0),
+            ("skewY", &[angle]) => Affine::skew(0.0, angle.to_radians().tan()),
+            _ => return Err(invalid()),
+        };
+        affine *= transform;
+    }
+    Ok(affine)
+}
+
+/// Split a [`BezPath`] into a [`BezPath`] for each subpath starting with `MoveTo`.
+fn split_subpaths(path: &BezPath) -> Vec<BezPath> {
+    let mut subpaths: Vec<BezPath> = Vec::new();
+    for element in path.elements() {
+        if subpaths.is_empty() || matches!(element, PathEl::MoveTo(_)) {
+            subpaths.push(BezPath::new());
+        }
+        subpaths
+            .last_mut()
+            .expect("should be a subpath")
+            .push(*element);
+    }
+    subpaths
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::prelude::Vec3;
+
+    #[test]
+    fn from_svg_path_data() -> Result<(), SvgError> {
+        // Arrange
+        let data = "M 0 0 C 10 0 20 10 20 20 L 20 40";
+        let options = SvgOptions {
+            scale: 0.5,
+            flip_y: true,
+        };
+
+        // Act
+        let splines = CubicBezierSpline::from_svg_path_data(data, options)?;
+
+        // Assert
+        assert_eq!(splines.len(), 1);
+        let spline = splines.first().expect("should be a spline");
+        assert_eq!(spline.get_curves().len(), 2);
+        assert!(!spline.is_closed());
+        assert!(spline
+            .get_end()
+            .abs_diff_eq(Vec3::new(10.0, -20.0, 0.0), CONNECTION_TOLERANCE));
+        Ok(())
+    }
+
+    #[test]
+    fn from_svg_document() -> Result<(), SvgError> {
+        // Arrange
+        let svg = r#"<svg xmlns="http://www.w3.org/2000/svg">
+            <g>
+                <path id="a" d="M0 0 L10 0 L10 10 Z M20 0 L30 0"/>
+                <path fill='none' d='M 0 50 Q 25 75 50 50'/>
+            </g>
+        </svg>"#;
+
+        // Act
+        let splines = CubicBezierSpline::from_svg(svg, SvgOptions::default())?;
+
+        // Assert
+        assert_eq!(splines.len(), 3);
+        let closed: Vec<bool> = splines.iter().map(CubicBezierSpline::is_closed).collect();
+        assert_eq!(closed, vec![true, false, false]);
+        let first = splines.first().expect("should be a spline");
+        assert_eq!(first.get_curves().len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn from_svg_document_with_transforms() -> Result<(), SvgError> {
+        // Arrange
+        let svg = r#"<?xml version="1.0"?>
+        <!DOCTYPE svg [<!ENTITY line "M 0 0 L 5 0">]>
+        <svg xmlns="http://www.w3.org/2000/svg">
+            <!-- <path d="M 0 0 L 1 1"/> -->
+            <g transform="translate(10, 20)">
+                <path transform="scale(2)" d="&line;"/>
+            </g>
+            <path transform="rotate(90) translate(5)" d="M 0 0 L 5 0"/>
+        </svg>"#;
+
+        // Act
+        let splines = CubicBezierSpline::from_svg(svg, SvgOptions::default())?;
+
+        // Assert
+        assert_eq!(splines.len(), 2);
+        let ends: Vec<Vec3> = splines.iter().map(CubicBezierSpline::get_end).collect();
+        let expected = [Vec3::new(20.0, -20.0, 0.0), Vec3::new(0.0, -10.0, 0.0)];
+        for (actual, expected) in ends.iter().zip(expected) {
+            assert!(actual.abs_diff_eq(expected, CONNECTION_TOLERANCE));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn parse_transform_invalid() {
+        // Arrange
+        let values = ["translate(1, 2", "scale(a)", "rotate(1 2)", "spin(1)"];
+
+        // Act
+        let results: Vec<_> = values.into_iter().map(parse_transform).collect();
+
+        // Assert
+        for (value, result) in values.into_iter().zip(results) {
+            assert!(
+                matches!(result, Err(Transform(_))),
+                "{value} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn to_svg_round_trip() -> Result<(), SvgError> {
+        // Arrange
+        let mut closed = CubicBezierSpline::example();
+        closed.close().map_err(Spline)?;
+        let splines = vec![CubicBezierSpline::example_2(), closed];
+        let options = SvgOptions {
+            scale: 2.0,
+            flip_y: true,
+        };
+
+        // Act
+        let svg = CubicBezierSpline::to_svg(&splines, options);
+        let result = CubicBezierSpline::from_svg(&svg, options)?;
+
+        // Assert
+        assert_eq!(result.len(), splines.len());
+        for (actual, expected) in result.iter().zip(&splines) {
+            assert_eq!(actual.is_closed(), expected.is_closed());
+            assert_eq!(actual.get_curves().len(), expected.get_curves().len());
+            for (a, b) in actual.get_controls().iter().zip(expected.get_controls()) {
+                assert!(a.abs_diff_eq(b, CONNECTION_TOLERANCE));
+            }
+        }
+        Ok(())
+    }
+}
@@ -37,10 +37,7 @@ impl AncestryExtensions for Entity {
         let mut ancestors = Vec::new();
         let mut current = self;
         let mut generation = 0;
-        loop {
-            let Some(child_of) = entities.get(current)? else {
-                break;
-            };
+        while let Some(child_of) = entities.get(current)? {
             ancestors.push(child_of.parent());
             current = child_of.parent();
             generation += 1;
@@ -80,7 +77,7 @@ impl Display for AncestorError {
         let reason = match self {
             AncestorError::Query(e) => e.to_string(),
             AncestorError::Count { expected, actual } => {
-                format!("Expected {expected} ancestors but only found {actual}",)
+                format!("Expected {expected} ancestors but only found {actual}")
             }
         };
         formatter.write_str(&reason)
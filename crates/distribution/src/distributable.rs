@@ -1,9 +1,10 @@
 use crate::Distributed;
 use bevy::prelude::*;
 use konstruo_geometry::Vec6;
+use serde::{Deserialize, Serialize};
 
 /// Properties that define how the entity will be distributed.
-#[derive(Clone, Component, Debug, PartialEq)]
+#[derive(Clone, Component, Debug, Deserialize, PartialEq, Serialize)]
 #[require(InheritedVisibility, Transform)]
 pub struct Distributable {
     /// Order in which the item is distributed.
@@ -22,6 +23,20 @@ pub struct Distributable {
     ///
     /// Default is None
     pub margin: Option<Vec6>,
+    /// Translation from the distributed position.
+    ///
+    /// Along a spline the offset is relative to the direction of the spline at that point.
+    ///
+    /// Default is None
+    #[serde(default)]
+    pub offset: Option<Vec3>,
+    /// Rotation relative to the direction of the spline at the distributed position.
+    ///
+    /// Without a spline the rotation of the [`Transform`] is kept instead.
+    ///
+    /// Default is None
+    #[serde(default)]
+    pub rotation: Option<Quat>,
 }
 
 impl Default for Distributable {
@@ -30,6 +45,8 @@ impl Default for Distributable {
             order: usize::MAX,
             size: None,
             margin: None,
+            offset: None,
+            rotation: None,
         }
     }
 }
@@ -1,7 +1,7 @@
 use super::*;
 use bevy::prelude::*;
-use konstruo_beziers::constants::LENGTH_ACCURACY;
-use konstruo_beziers::CubicBezierSpline;
+use konstruo_beziers::constants::{LENGTH_ACCURACY, OFFSET_ACCURACY};
+use konstruo_beziers::{CubicBezierSpline, CubicBezierSplineError};
 
 /// How children with the [`Distributable`] component are to be distributed.
 #[derive(Clone, Component, Debug, Default)]
@@ -12,6 +12,11 @@ pub struct Distribution {
     pub spline: Option<CubicBezierSpline>,
     /// How far should the spline be offset?
     pub spline_offset: Option<f32>,
+    /// Should the offset spline be reversed?
+    ///
+    /// Items are rotated to follow the direction of the spline so reversing it
+    /// turns items on the opposite side of a path to face the same way.
+    pub spline_reversed: bool,
     /// Should the translation of the [`Transform`] be set so the container is at ground level.
     ///
     /// This is not applied to nested distributions.
@@ -24,6 +29,26 @@ pub enum DistributionError {
 }
 
 impl Distribution {
+    /// Get the spline to distribute along from the spline of a parent path.
+    ///
+    /// The spline is offset by [`Distribution::spline_offset`] and reversed if [`Distribution::spline_reversed`].
+    ///
+    /// The offset may fail if the path curves too tightly for the offset distance.
+    pub fn get_spline_from_path(
+        &self,
+        spline: &CubicBezierSpline,
+    ) -> Result<CubicBezierSpline, CubicBezierSplineError> {
+        let mut spline = if let Some(offset) = self.spline_offset {
+            spline.offset(offset, OFFSET_ACCURACY)?
+        } else {
+            spline.clone()
+        };
+        if self.spline_reversed {
+            spline.reverse();
+        }
+        Ok(spline)
+    }
+
     /// Distribute the [`Distributable`] children.
     pub fn distribute(
         &self,
@@ -59,19 +84,23 @@ impl Distribution {
         let (entities, items) = sort_and_split_children(unsorted);
         let container = self.flex.execute(items);
         for (entity, distributed) in entities.iter().zip(&container.items) {
-            let components = distributables.get_mut(*entity).expect("entity exists");
-            let mut transform = components.2;
+            let (_, distributable, mut transform, _, _) =
+                distributables.get_mut(*entity).expect("entity exists");
+            let offset = distributable.offset.unwrap_or_default();
+            let rotation = distributable.rotation.unwrap_or_default();
             if let Some(spline) = &self.spline {
                 match get_transform_along_spline(spline, distributed, transform.scale) {
                     Ok(t) => {
-                        *transform = t;
+                        *transform = t
+                            .with_translation(t.translation + t.rotation * offset)
+                            .with_rotation(t.rotation * rotation);
                     }
                     Err(DistributionError::ExceededSplineLength { actual, expected }) => {
                         error!("Failed to distribute item along a spline. The spline length is {actual:.3} but {expected:.3} was required.");
                     }
                 }
             } else {
-                *transform = Transform::from_translation(distributed.translation)
+                *transform = Transform::from_translation(distributed.translation + offset)
                     .with_rotation(transform.rotation)
                     .with_scale(transform.scale);
             }
@@ -204,8 +233,10 @@ fn get_transform_along_spline(
     scale: Vec3,
 ) -> Result<Transform, DistributionError> {
     let spline_length = spline.get_length(LENGTH_ACCURACY);
-    let distance = distributed.translation.x + spline_length * 0.5;
-    if distance > spline_length {
+    let mut distance = distributed.translation.x + spline_length * 0.5;
+    if spline.is_closed() {
+        distance = distance.rem_euclid(spline_length);
+    } else if distance > spline_length {
         return Err(DistributionError::ExceededSplineLength {
             actual: spline_length,
             expected: distance,
@@ -1,7 +1,8 @@
 use super::*;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct FlexFactory {
     pub main_axis: Vec3,
     pub cross_axis: Vec3,
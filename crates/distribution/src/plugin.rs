@@ -4,9 +4,25 @@ use bevy::prelude::*;
 
 pub struct DistributionPlugin;
 
+/// Systems that must run before the items of a [`Distribution`] are distributed.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, SystemSet)]
+pub enum DistributionSystems {
+    /// Update the spline and bounds of a [`Distribution`].
+    Spline,
+    /// Add or remove the [`Distributable`] children to fill a [`Distribution`].
+    Fill,
+}
+
 impl Plugin for DistributionPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Update, Distribution::added_system)
-            .add_systems(Update, Distribution::changed_system);
+        app.configure_sets(
+            Update,
+            (DistributionSystems::Spline, DistributionSystems::Fill)
+                .chain()
+                .before(Distribution::added_system)
+                .before(Distribution::changed_system),
+        )
+        .add_systems(Update, Distribution::added_system)
+        .add_systems(Update, Distribution::changed_system);
     }
 }
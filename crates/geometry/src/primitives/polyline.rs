@@ -4,6 +4,18 @@ use bevy::mesh::PrimitiveTopology;
 use bevy::prelude::*;
 use std::cmp::Ordering;
 
+/// Distance within which a point of intersection is considered to be the end of a line.
+const TOUCHING_TOLERANCE: f32 = 0.001;
+
+/// An intersection that would invert the triangles formed from a [`Polyline`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PolylineIntersectionError {
+    /// The polyline crosses over itself at the point.
+    SelfIntersection(Vec3),
+    /// The polylines cross each other at the point.
+    MutualIntersection(Vec3),
+}
+
 /// A polyline or [`PrimitiveTopology::LineStrip`]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Polyline {
@@ -90,6 +102,76 @@ impl Polyline {
         }
     }
 
+    /// Equalize the number of vertices of every [`Polyline`] to that of the longest by
+    /// splitting the longest edges of the others.
+    pub fn equalize_all_vertices_count(polylines: &mut [Polyline]) {
+        let Some(count) = polylines
+            .iter()
+            .map(|polyline| polyline.vertices.len())
+            .max()
+        else {
+            return;
+        };
+        for polyline in polylines.iter_mut() {
+            add_vertices(polyline, count - polyline.vertices.len());
+        }
+    }
+
+    /// Collapse any loops where the [`Polyline`] crosses over itself.
+    ///
+    /// The vertices of a loop are moved to the point where it crosses so the number of
+    /// vertices is unchanged and they still correspond with those of a parallel [`Polyline`].
+    #[allow(clippy::indexing_slicing)]
+    pub fn collapse_loops(&mut self) {
+        let count = self.vertices.len();
+        let mut i = 0;
+        while i + 1 < count {
+            let line = Line::new(self.vertices[i], self.vertices[i + 1]);
+            let crossing = (i + 2..count - 1).rev().find_map(|j| {
+                let other = Line::new(self.vertices[j], self.vertices[j + 1]);
+                Some((j, line.get_intersection(&other)?))
+            });
+            let Some((j, point)) = crossing else {
+                i += 1;
+                continue;
+            };
+            for vertex in &mut self.vertices[i + 1..=j] {
+                *vertex = point;
+            }
+            i = j;
+        }
+    }
+
+    /// Get the first point where the [`Polyline`] crosses over itself.
+    ///
+    /// Lines that only touch at their ends, such as those of a collapsed loop, are not
+    /// considered to cross.
+    ///
+    /// Pairs of lines are only intersected if their bounding boxes overlap.
+    #[must_use]
+    pub fn get_self_intersection(&self) -> Option<Vec3> {
+        let lines = self.to_lines();
+        let bounds: Vec<(Vec3, Vec3)> = lines.iter().map(get_bounds).collect();
+        for (i, (line, (min, max))) in lines.iter().zip(&bounds).enumerate() {
+            for (other, (other_min, other_max)) in lines.iter().zip(&bounds).skip(i + 2) {
+                if min.cmpgt(*other_max).any() || other_min.cmpgt(*max).any() {
+                    continue;
+                }
+                let Some(point) = line.get_intersection(other) else {
+                    continue;
+                };
+                let is_touching = (line.start.abs_diff_eq(point, TOUCHING_TOLERANCE)
+                    || line.end.abs_diff_eq(point, TOUCHING_TOLERANCE))
+                    && (other.start.abs_diff_eq(point, TOUCHING_TOLERANCE)
+                        || other.end.abs_diff_eq(point, TOUCHING_TOLERANCE));
+                if !is_touching {
+                    return Some(point);
+                }
+            }
+        }
+        None
+    }
+
     /// Get the points of intersection with [`Line`].
     #[must_use]
     pub fn get_intersections(&self, other: &Line) -> Option<Vec<Vec3>> {
@@ -124,6 +206,16 @@ impl Polyline {
     }
 }
 
+/// Get the minimum and maximum corners of the bounding box of a [`Line`] expanded by
+/// [`TOUCHING_TOLERANCE`].
+fn get_bounds(line: &Line) -> (Vec3, Vec3) {
+    let margin = Vec3::splat(TOUCHING_TOLERANCE);
+    (
+        line.start.min(line.end) - margin,
+        line.start.max(line.end) + margin,
+    )
+}
+
 #[allow(clippy::indexing_slicing)]
 /// Add vertices to a [`Polyline`] by splitting the longest edge.
 fn add_vertices(polyline: &mut Polyline, count: usize) {
@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{Polyline, PolylineIntersectionError, Triangle};
 use bevy::asset::RenderAssetUsages;
 use bevy::mesh::PrimitiveTopology;
 use bevy::prelude::*;
@@ -41,8 +41,8 @@ impl TriangleList {
     ///
     /// The [`Polyline`] MUST have equal numbers of vertices.
     ///
-    /// TODO: Check the polylines do not intersect with each another
-    /// TODO: Check the polylines do not self-intersect
+    /// The polylines are not checked for intersections which would invert triangles.
+    /// Use [`TriangleList::between_polylines_checked`] if they may intersect.
     #[must_use]
     #[allow(clippy::indexing_slicing)]
     pub fn between_polylines(left: Polyline, right: Polyline) -> Self {
@@ -60,6 +60,35 @@ impl TriangleList {
         Self { triangles }
     }
 
+    /// Create a [`TriangleList`] between two parallel polylines after checking they do not
+    /// cross over themselves or each other.
+    ///
+    /// If `trim` is true then any loops where a polyline crosses over itself are collapsed
+    /// before checking.
+    pub fn between_polylines_checked(
+        mut left: Polyline,
+        mut right: Polyline,
+        trim: bool,
+    ) -> Result<Self, PolylineIntersectionError> {
+        if trim {
+            left.collapse_loops();
+            right.collapse_loops();
+        }
+        if let Some(point) = left
+            .get_self_intersection()
+            .or_else(|| right.get_self_intersection())
+        {
+            return Err(PolylineIntersectionError::SelfIntersection(point));
+        }
+        if let Some(point) = left
+            .get_intersections_with_polyline(&right)
+            .and_then(|points| points.first().copied())
+        {
+            return Err(PolylineIntersectionError::MutualIntersection(point));
+        }
+        Ok(Self::between_polylines(left, right))
+    }
+
     /// Create a [`PrimitiveTopology::TriangleList`].
     pub fn merge(&mut self, mut other: TriangleList) {
         let triangles = &mut other.triangles;
@@ -0,0 +1,648 @@
+use crate::{get_signed_area, Polygon, PolygonWithHoles};
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::f32::consts::PI;
+use std::iter::once;
+use std::slice::from_ref;
+use BooleanOperation::*;
+use EdgePosition::*;
+
+/// Distance in metres within which vertices are considered coincident.
+///
+/// Rings with a smaller area in square metres are discarded.
+const BOOLEAN_TOLERANCE: f32 = 0.000_1;
+
+/// A boolean operation between two planar regions.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BooleanOperation {
+    /// The region covered by either.
+    Union,
+    /// The region covered by the first but not the second.
+    Difference,
+    /// The region covered by both.
+    Intersection,
+}
+
+/// Position of an edge relative to the other region.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EdgePosition {
+    Inside,
+    Outside,
+    /// Coincident with an edge of the other region in the same direction.
+    Shared,
+    /// Coincident with an edge of the other region in the opposite direction.
+    Opposite,
+}
+
+/// Vertices shared by the edges of both regions once they are split at every intersection.
+#[derive(Default)]
+struct SplitGraph {
+    points: Vec<Vec2>,
+}
+
+impl PolygonWithHoles {
+    /// Apply a [`BooleanOperation`] with another [`PolygonWithHoles`].
+    ///
+    /// Both are projected onto the XY plane and the result takes the Z of the first vertex.
+    ///
+    /// Each edge is split wherever it intersects the other region and then kept or discarded
+    /// depending on whether it's inside, outside or coincident with the other region.
+    /// The kept edges are linked into rings which are wound counter-clockwise for an exterior
+    /// or clockwise for a hole.
+    #[must_use]
+    pub fn boolean(
+        &self,
+        other: &PolygonWithHoles,
+        operation: BooleanOperation,
+    ) -> Vec<PolygonWithHoles> {
+        let z = self
+            .get_exterior()
+            .get_vertices()
+            .first()
+            .map_or(0.0, |vertex| vertex.z);
+        let rings = [get_rings(self), get_rings(other)];
+        let [rings_a, rings_b] = &rings;
+        let mut graph = SplitGraph::default();
+        let [edges_a, edges_b]: [Vec<[usize; 2]>; 2] = graph
+            .split(&rings)
+            .try_into()
+            .expect("should be edges for both regions");
+        let positions_a = graph.classify(&edges_a, &edges_b, rings_b);
+        let positions_b = graph.classify(&edges_b, &edges_a, rings_a);
+        let mut kept = Vec::new();
+        for (edge, position) in edges_a.into_iter().zip(positions_a) {
+            let is_kept = match operation {
+                Union => matches!(position, Outside | Shared),
+                Difference => matches!(position, Outside | Opposite),
+                Intersection => matches!(position, Inside | Shared),
+            };
+            if is_kept {
+                kept.push(edge);
+            }
+        }
+        for ([start, end], position) in edges_b.into_iter().zip(positions_b) {
+            match (operation, position) {
+                (Union, Outside) | (Intersection, Inside) => kept.push([start, end]),
+                (Difference, Inside) => kept.push([end, start]),
+                _ => {}
+            }
+        }
+        assemble(graph.link(&kept), z)
+    }
+
+    /// Get the region covered by either.
+    #[must_use]
+    pub fn union(&self, other: &PolygonWithHoles) -> Vec<PolygonWithHoles> {
+        self.boolean(other, Union)
+    }
+
+    /// Get the region covered by any of the regions.
+    ///
+    /// The edges of every region are split against each other in a single pass and only
+    /// those outside every other region are kept, so the result is the same as a
+    /// [`BooleanOperation::Union`] of each in turn without the intermediate regions.
+    #[must_use]
+    #[allow(clippy::indexing_slicing)]
+    pub fn union_all(regions: &[PolygonWithHoles]) -> Vec<PolygonWithHoles> {
+        let Some(first) = regions.first() else {
+            return Vec::new();
+        };
+        let z = first
+            .get_exterior()
+            .get_vertices()
+            .first()
+            .map_or(0.0, |vertex| vertex.z);
+        let rings: Vec<Vec<Vec<Vec2>>> = regions.iter().map(get_rings).collect();
+        let mut graph = SplitGraph::default();
+        let edges = graph.split(&rings);
+        let mut is_kept: Vec<Vec<bool>> =
+            edges.iter().map(|edges| vec![true; edges.len()]).collect();
+        for (i, edges_i) in edges.iter().enumerate() {
+            for (j, edges_j) in edges.iter().enumerate() {
+                if i == j {
+                    continue;
+                }
+                let positions = graph.classify(edges_i, edges_j, &rings[j]);
+                for (index, position) in positions.into_iter().enumerate() {
+                    // A shared edge is only kept from the first region it belongs to.
+                    let is_outside = match position {
+                        Outside => true,
+                        Shared => i < j,
+                        Inside | Opposite => false,
+                    };
+                    is_kept[i][index] &= is_outside;
+                }
+            }
+        }
+        let kept: Vec<[usize; 2]> = edges
+            .into_iter()
+            .zip(is_kept)
+            .flat_map(|(edges, is_kept)| edges.into_iter().zip(is_kept))
+            .filter_map(|(edge, is_kept)| is_kept.then_some(edge))
+            .collect();
+        assemble(graph.link(&kept), z)
+    }
+
+    /// Get the region covered by this but not the other.
+    #[must_use]
+    pub fn difference(&self, other: &PolygonWithHoles) -> Vec<PolygonWithHoles> {
+        self.boolean(other, Difference)
+    }
+
+    /// Get the region covered by both.
+    #[must_use]
+    pub fn intersection(&self, other: &PolygonWithHoles) -> Vec<PolygonWithHoles> {
+        self.boolean(other, Intersection)
+    }
+}
+
+impl Polygon {
+    /// Get the region covered by either.
+    #[must_use]
+    pub fn union(&self, other: &Polygon) -> Vec<PolygonWithHoles> {
+        PolygonWithHoles::from(self.clone()).union(&PolygonWithHoles::from(other.clone()))
+    }
+
+    /// Get the region covered by this but not the other.
+    #[must_use]
+    pub fn difference(&self, other: &Polygon) -> Vec<PolygonWithHoles> {
+        PolygonWithHoles::from(self.clone()).difference(&PolygonWithHoles::from(other.clone()))
+    }
+
+    /// Get the region covered by both.
+    #[must_use]
+    pub fn intersection(&self, other: &Polygon) -> Vec<PolygonWithHoles> {
+        PolygonWithHoles::from(self.clone()).intersection(&PolygonWithHoles::from(other.clone()))
+    }
+}
+
+impl SplitGraph {
+    /// Get the index of a point.
+    ///
+    /// If no existing point is within [`BOOLEAN_TOLERANCE`] then it's added.
+    fn get_index(&mut self, point: Vec2) -> usize {
+        if let Some(index) = self
+            .points
+            .iter()
+            .position(|existing| existing.distance(point) < BOOLEAN_TOLERANCE)
+        {
+            return index;
+        }
+        self.points.push(point);
+        self.points.len() - 1
+    }
+
+    /// Split the edges of the rings of each region wherever they intersect another.
+    ///
+    /// Returns the start and end indices of each split edge of each region.
+    #[allow(clippy::indexing_slicing)]
+    fn split(&mut self, regions: &[Vec<Vec<Vec2>>]) -> Vec<Vec<[usize; 2]>> {
+        let segments: Vec<Vec<[Vec2; 2]>> =
+            regions.iter().map(|rings| get_segments(rings)).collect();
+        let mut params: Vec<Vec<Vec<f32>>> = segments
+            .iter()
+            .map(|segments| vec![vec![0.0, 1.0]; segments.len()])
+            .collect();
+        for i in 0..segments.len() {
+            for j in i + 1..segments.len() {
+                for (a, segment_a) in segments[i].iter().enumerate() {
+                    for (b, segment_b) in segments[j].iter().enumerate() {
+                        for (t, u) in get_intersections(*segment_a, *segment_b) {
+                            params[i][a].push(t);
+                            params[j][b].push(u);
+                        }
+                    }
+                }
+            }
+        }
+        segments
+            .iter()
+            .zip(params)
+            .map(|(segments, params)| self.to_edges(segments, params))
+            .collect()
+    }
+
+    /// Split each segment at its params.
+    fn to_edges(&mut self, segments: &[[Vec2; 2]], params: Vec<Vec<f32>>) -> Vec<[usize; 2]> {
+        let mut edges = Vec::new();
+        for (&[start, end], mut params) in segments.iter().zip(params) {
+            params.sort_by(f32::total_cmp);
+            let indices: Vec<usize> = params
+                .into_iter()
+                .map(|param| self.get_index(start.lerp(end, param)))
+                .collect();
+            for pair in indices.windows(2) {
+                if let &[start, end] = pair {
+                    if start != end {
+                        edges.push([start, end]);
+                    }
+                }
+            }
+        }
+        edges
+    }
+
+    /// Get the [`EdgePosition`] of each edge relative to the other region.
+    #[allow(clippy::indexing_slicing)]
+    fn classify(
+        &self,
+        edges: &[[usize; 2]],
+        other_edges: &[[usize; 2]],
+        other_rings: &[Vec<Vec2>],
+    ) -> Vec<EdgePosition> {
+        let other_edges: HashSet<[usize; 2]> = other_edges.iter().copied().collect();
+        edges
+            .iter()
+            .map(|&[start, end]| {
+                if other_edges.contains(&[start, end]) {
+                    Shared
+                } else if other_edges.contains(&[end, start]) {
+                    Opposite
+                } else if is_inside(self.points[start].midpoint(self.points[end]), other_rings) {
+                    Inside
+                } else {
+                    Outside
+                }
+            })
+            .collect()
+    }
+
+    /// Link edges end to start into closed rings.
+    ///
+    /// Where more than one edge leaves a vertex the one turning furthest left is followed
+    /// so regions that only touch at a vertex remain separate.
+    /// Edges that don't form a closed ring are discarded.
+    #[allow(clippy::indexing_slicing)]
+    fn link(&self, edges: &[[usize; 2]]) -> Vec<Vec<Vec2>> {
+        let mut outgoing: HashMap<usize, Vec<usize>> = HashMap::new();
+        for (index, [start, _]) in edges.iter().enumerate() {
+            outgoing.entry(*start).or_default().push(index);
+        }
+        let mut is_used = vec![false; edges.len()];
+        let mut rings = Vec::new();
+        for first in 0..edges.len() {
+            if is_used[first] {
+                continue;
+            }
+            let [ring_start, _] = edges[first];
+            let mut ring = Vec::new();
+            let mut current = first;
+            loop {
+                is_used[current] = true;
+                let [start, end] = edges[current];
+                ring.push(self.points[start]);
+                if end == ring_start {
+                    rings.push(ring);
+                    break;
+                }
+                let incoming = self.points[end] - self.points[start];
+                let next = outgoing
+                    .get(&end)
+                    .into_iter()
+                    .flatten()
+                    .filter(|&&index| !is_used[index])
+                    .max_by(|&&a, &&b| {
+                        let turn_a = self.get_turn(incoming, edges[a]);
+                        let turn_b = self.get_turn(incoming, edges[b]);
+                        turn_a.total_cmp(&turn_b)
+                    });
+                let Some(&next) = next else {
+                    break;
+                };
+                current = next;
+            }
+        }
+        rings
+    }
+
+    /// Get the counter-clockwise angle from the incoming direction to an outgoing edge.
+    ///
+    /// Turning back along the incoming direction is the least preferred.
+    #[allow(clippy::indexing_slicing)]
+    fn get_turn(&self, incoming: Vec2, [start, end]: [usize; 2]) -> f32 {
+        let outgoing = self.points[end] - self.points[start];
+        let angle = incoming.perp_dot(outgoing).atan2(incoming.dot(outgoing));
+        if angle.abs() > PI - BOOLEAN_TOLERANCE {
+            -PI
+        } else {
+            angle
+        }
+    }
+}
+
+/// Get the exterior and holes as rings of 2D vertices without the closing vertex.
+fn get_rings(polygon: &PolygonWithHoles) -> Vec<Vec<Vec2>> {
+    once(polygon.get_exterior())
+        .chain(polygon.get_holes())
+        .filter_map(|polygon| {
+            let mut ring: Vec<Vec2> = polygon
+                .get_vertices()
+                .iter()
+                .map(|vertex| vertex.truncate())
+                .collect();
+            if ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+            (ring.len() >= 3).then_some(ring)
+        })
+        .collect()
+}
+
+/// Get the start and end of every edge of the rings.
+fn get_segments(rings: &[Vec<Vec2>]) -> Vec<[Vec2; 2]> {
+    rings
+        .iter()
+        .flat_map(|ring| {
+            ring.iter()
+                .zip(ring.iter().cycle().skip(1))
+                .map(|(start, end)| [*start, *end])
+        })
+        .collect()
+}
+
+/// Get the params along each segment at which they intersect.
+///
+/// Collinear segments that overlap intersect at the ends of each overlapping segment.
+#[allow(clippy::similar_names)]
+fn get_intersections([a0, a1]: [Vec2; 2], [b0, b1]: [Vec2; 2]) -> Vec<(f32, f32)> {
+    let r = a1 - a0;
+    let s = b1 - b0;
+    let length_a = r.length();
+    let length_b = s.length();
+    if length_a < BOOLEAN_TOLERANCE || length_b < BOOLEAN_TOLERANCE {
+        return Vec::new();
+    }
+    let tolerance_a = BOOLEAN_TOLERANCE / length_a;
+    let tolerance_b = BOOLEAN_TOLERANCE / length_b;
+    let between = b0 - a0;
+    let denominator = r.perp_dot(s);
+    if denominator.abs() > BOOLEAN_TOLERANCE * length_a * length_b {
+        let t = between.perp_dot(s) / denominator;
+        let u = between.perp_dot(r) / denominator;
+        let is_on_a = t >= -tolerance_a && t <= 1.0 + tolerance_a;
+        let is_on_b = u >= -tolerance_b && u <= 1.0 + tolerance_b;
+        return if is_on_a && is_on_b {
+            vec![(t.clamp(0.0, 1.0), u.clamp(0.0, 1.0))]
+        } else {
+            Vec::new()
+        };
+    }
+    if between.perp_dot(r).abs() > BOOLEAN_TOLERANCE * length_a {
+        return Vec::new();
+    }
+    let mut intersections = Vec::new();
+    for (point, u) in [(b0, 0.0), (b1, 1.0)] {
+        let t = (point - a0).dot(r) / r.length_squared();
+        if t > 0.0 && t < 1.0 {
+            intersections.push((t, u));
+        }
+    }
+    for (point, t) in [(a0, 0.0), (a1, 1.0)] {
+        let u = (point - b0).dot(s) / s.length_squared();
+        if u > 0.0 && u < 1.0 {
+            intersections.push((t, u));
+        }
+    }
+    intersections
+}
+
+/// Is the point inside the region bounded by the rings?
+///
+/// Determined by the even-odd rule so a point inside a hole is outside.
+fn is_inside(point: Vec2, rings: &[Vec<Vec2>]) -> bool {
+    let crossings = get_segments(rings)
+        .into_iter()
+        .filter(|[start, end]| {
+            (start.y > point.y) != (end.y > point.y)
+                && point.x < start.x + (point.y - start.y) / (end.y - start.y) * (end.x - start.x)
+        })
+        .count();
+    crossings % 2 == 1
+}
+
+/// Group rings into [`PolygonWithHoles`].
+///
+/// Counter-clockwise rings are exteriors and clockwise rings are holes of the smallest
+/// exterior that contains them.
+fn assemble(rings: Vec<Vec<Vec2>>, z: f32) -> Vec<PolygonWithHoles> {
+    let mut exteriors = Vec::new();
+    let mut holes = Vec::new();
+    for ring in rings {
+        let area = get_signed_area(&ring);
+        if area.abs() < BOOLEAN_TOLERANCE {
+            continue;
+        }
+        if area > 0.0 {
+            exteriors.push((area, ring, Vec::new()));
+        } else {
+            holes.push(ring);
+        }
+    }
+    for hole in holes {
+        let [Some(start), Some(end)] = [hole.first(), hole.get(1)] else {
+            continue;
+        };
+        let point = start.midpoint(*end);
+        let exterior = exteriors
+            .iter_mut()
+            .filter(|(_, exterior, _)| is_inside(point, from_ref(exterior)))
+            .min_by(|(a, _, _), (b, _, _)| a.total_cmp(b));
+        if let Some((_, _, exterior_holes)) = exterior {
+            exterior_holes.push(hole);
+        }
+    }
+    exteriors
+        .into_iter()
+        .filter_map(|(_, exterior, holes)| {
+            let exterior = to_polygon(exterior, z)?;
+            let holes = holes
+                .into_iter()
+                .filter_map(|hole| to_polygon(hole, z))
+                .collect();
+            Some(PolygonWithHoles::new(exterior, holes))
+        })
+        .collect()
+}
+
+/// Convert a ring of 2D vertices to a closed [`Polygon`] at the elevation.
+fn to_polygon(ring: Vec<Vec2>, z: f32) -> Option<Polygon> {
+    Polygon::from_open(ring.into_iter().map(|vertex| vertex.extend(z)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_counter_clockwise(polygon: &Polygon) -> bool {
+        let vertices: Vec<Vec2> = polygon
+            .get_vertices()
+            .iter()
+            .map(|vertex| vertex.truncate())
+            .collect();
+        get_signed_area(&vertices) > 0.0
+    }
+
+    fn get_total_area(polygons: &[PolygonWithHoles]) -> f32 {
+        polygons.iter().map(PolygonWithHoles::get_area).sum()
+    }
+
+    #[test]
+    fn union_overlapping() {
+        // Arrange
+        let a = Polygon::create_square(Vec3::ZERO, 2.0);
+        let b = Polygon::create_square(Vec3::new(1.0, 1.0, 0.0), 2.0);
+
+        // Act
+        let result = a.union(&b);
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        let polygon = result.first().expect("should be a polygon");
+        assert!(polygon.get_holes().is_empty());
+        assert_eq!(polygon.get_exterior().get_vertices().len(), 9);
+        assert!(is_counter_clockwise(polygon.get_exterior()));
+        assert!((get_total_area(&result) - 7.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn union_all_overlapping() {
+        // Arrange
+        let regions: Vec<PolygonWithHoles> = [
+            Vec3::ZERO,
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(10.0, 0.0, 0.0),
+        ]
+        .into_iter()
+        .map(|origin| PolygonWithHoles::from(Polygon::create_square(origin, 2.0)))
+        .collect();
+
+        // Act
+        let result = PolygonWithHoles::union_all(&regions);
+
+        // Assert
+        assert_eq!(result.len(), 2);
+        assert!(result
+            .iter()
+            .all(|polygon| is_counter_clockwise(polygon.get_exterior())));
+        assert!((get_total_area(&result) - (10.0 + 4.0)).abs() < 0.001);
+    }
+
+    #[test]
+    fn intersection_overlapping() {
+        // Arrange
+        let a = Polygon::create_square(Vec3::ZERO, 2.0);
+        let b = Polygon::create_square(Vec3::new(1.0, 1.0, 0.0), 2.0);
+
+        // Act
+        let result = a.intersection(&b);
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        assert!((get_total_area(&result) - 1.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn difference_overlapping() {
+        // Arrange
+        let a = Polygon::create_square(Vec3::ZERO, 2.0);
+        let b = Polygon::create_square(Vec3::new(1.0, 1.0, 0.0), 2.0);
+
+        // Act
+        let result = a.difference(&b);
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        assert!((get_total_area(&result) - 3.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn difference_contained() {
+        // Arrange
+        let a = Polygon::create_square(Vec3::ZERO, 4.0);
+        let b = Polygon::create_square(Vec3::ZERO, 2.0);
+
+        // Act
+        let result = a.difference(&b);
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        let polygon = result.first().expect("should be a polygon");
+        assert_eq!(polygon.get_holes().len(), 1);
+        assert!(is_counter_clockwise(polygon.get_exterior()));
+        let hole = polygon.get_holes().first().expect("should be a hole");
+        assert!(!is_counter_clockwise(hole));
+        assert!((get_total_area(&result) - 12.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn union_fills_hole() {
+        // Arrange
+        let a = Polygon::create_square(Vec3::ZERO, 4.0);
+        let b = Polygon::create_square(Vec3::ZERO, 2.0);
+        let holed = a.difference(&b);
+        let holed = holed.first().expect("should be a polygon");
+
+        // Act
+        let result = holed.union(&PolygonWithHoles::from(b));
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        let polygon = result.first().expect("should be a polygon");
+        assert!(polygon.get_holes().is_empty());
+        assert!((get_total_area(&result) - 16.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn union_adjacent() {
+        // Arrange
+        let a = Polygon::create_square(Vec3::ZERO, 2.0);
+        let b = Polygon::create_square(Vec3::new(2.0, 0.5, 0.0), 2.0);
+
+        // Act
+        let result = a.union(&b);
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        assert!((get_total_area(&result) - 8.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn union_touching_at_vertex() {
+        // Arrange
+        let a = Polygon::create_square(Vec3::ZERO, 2.0);
+        let b = Polygon::create_square(Vec3::new(2.0, 2.0, 0.0), 2.0);
+
+        // Act
+        let result = a.union(&b);
+
+        // Assert
+        assert_eq!(result.len(), 2);
+        assert!((get_total_area(&result) - 8.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn intersection_disjoint() {
+        // Arrange
+        let a = Polygon::create_square(Vec3::ZERO, 2.0);
+        let b = Polygon::create_square(Vec3::new(5.0, 0.0, 0.0), 2.0);
+
+        // Act
+        let result = a.intersection(&b);
+
+        // Assert
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn difference_identical() {
+        // Arrange
+        let a = Polygon::create_diamond(Vec3::ZERO, 2.0);
+
+        // Act
+        let result = a.difference(&a);
+
+        // Assert
+        assert!(result.is_empty());
+    }
+}
@@ -1,7 +1,16 @@
+pub use boolean::*;
 pub use line::*;
+pub use offset::*;
 pub use polygon::*;
+pub use polygon_with_holes::*;
+pub use profile::*;
 pub use triangle::*;
 
+mod boolean;
 mod line;
+mod offset;
 mod polygon;
+mod polygon_with_holes;
+mod profile;
 mod triangle;
+mod triangulation;
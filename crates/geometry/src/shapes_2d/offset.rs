@@ -0,0 +1,399 @@
+use crate::{Polygon, PolygonWithHoles, Polyline};
+use bevy::prelude::*;
+use std::f32::consts::PI;
+use std::iter::once;
+use JoinStyle::*;
+
+/// Maximum angle in radians of each segment of a [`JoinStyle::Round`] join.
+const ROUND_SEGMENT_ANGLE: f32 = PI / 16.0;
+
+/// Offsets and cross products smaller than this are ignored.
+const OFFSET_TOLERANCE: f32 = 0.000_1;
+
+/// How the offset edges either side of a corner are joined.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum JoinStyle {
+    /// Extend the edges until they meet at a point.
+    ///
+    /// If the point is further from the corner than the limit multiplied by the offset
+    /// distance then the corner is bevelled instead.
+    Mitre(f32),
+    /// Join with an arc centered on the corner.
+    Round,
+    /// Join with a straight line.
+    Bevel,
+}
+
+impl PolygonWithHoles {
+    /// Offset outwards by a positive distance or inwards by a negative distance.
+    ///
+    /// Both are projected onto the XY plane and the result takes the Z of the first vertex.
+    ///
+    /// Holes shrink as the polygon grows and grow as it shrinks.
+    /// A deep inset may split the polygon into several or remove it entirely, and an outset
+    /// may close holes or merge parts of the polygon.
+    ///
+    /// The region within the distance of each edge, and the [`JoinStyle`] at each corner
+    /// that opens towards the offset, is added to or subtracted from the polygon.
+    #[must_use]
+    pub fn offset(&self, distance: f32, join: JoinStyle) -> Vec<PolygonWithHoles> {
+        if distance.abs() < OFFSET_TOLERANCE {
+            return vec![self.clone()];
+        }
+        let z = self
+            .get_exterior()
+            .get_vertices()
+            .first()
+            .map_or(0.0, |vertex| vertex.z);
+        let pieces: Vec<PolygonWithHoles> = once(self.get_exterior())
+            .chain(self.get_holes())
+            .flat_map(|polygon| get_offset_pieces(polygon, distance, join))
+            .filter_map(|piece| {
+                let piece = Polygon::from_open(piece.into_iter().map(|v| v.extend(z)).collect())?;
+                Some(PolygonWithHoles::from(piece))
+            })
+            .collect();
+        if distance > 0.0 {
+            let regions: Vec<PolygonWithHoles> = once(self.clone()).chain(pieces).collect();
+            return PolygonWithHoles::union_all(&regions);
+        }
+        let mut regions = vec![self.clone()];
+        for piece in PolygonWithHoles::union_all(&pieces) {
+            regions = regions
+                .iter()
+                .flat_map(|region| region.difference(&piece))
+                .collect();
+        }
+        regions
+    }
+}
+
+impl Polygon {
+    /// Offset outwards by a positive distance or inwards by a negative distance.
+    ///
+    /// A deep inset may split the polygon into several or remove it entirely.
+    #[must_use]
+    pub fn offset(&self, distance: f32, join: JoinStyle) -> Vec<PolygonWithHoles> {
+        PolygonWithHoles::from(self.clone()).offset(distance, join)
+    }
+}
+
+impl Polyline {
+    /// Offset to the left by a positive distance or to the right by a negative distance
+    /// when viewed from above.
+    ///
+    /// Corners on the outside of a turn are joined with the [`JoinStyle`] and any loops on
+    /// the inside of a turn are collapsed.
+    #[must_use]
+    #[allow(clippy::indexing_slicing)]
+    pub fn offset(&self, distance: f32, join: JoinStyle) -> Polyline {
+        let mut vertices = self.get_vertices().clone();
+        vertices.dedup();
+        let count = vertices.len();
+        if count < 2 {
+            return Polyline::new(vertices);
+        }
+        let normals: Vec<Vec2> = vertices
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).truncate().normalize_or_zero().perp())
+            .collect();
+        let mut offset = Vec::new();
+        for (i, vertex) in vertices.iter().enumerate() {
+            let incoming = i.checked_sub(1).and_then(|i| normals.get(i));
+            let outgoing = normals.get(i);
+            let (normal_a, normal_b) = match (incoming, outgoing) {
+                (Some(a), Some(b)) => (*a, *b),
+                (Some(normal), None) | (None, Some(normal)) => (*normal, *normal),
+                (None, None) => continue,
+            };
+            let turn = normal_a.perp_dot(normal_b);
+            let points = if turn * distance < -OFFSET_TOLERANCE {
+                let sign = distance.signum();
+                get_join(
+                    vertex.truncate(),
+                    normal_a * sign,
+                    normal_b * sign,
+                    distance.abs(),
+                    join,
+                )
+            } else {
+                vec![
+                    vertex.truncate() + normal_a * distance,
+                    vertex.truncate() + normal_b * distance,
+                ]
+            };
+            offset.extend(points.into_iter().map(|point| point.extend(vertex.z)));
+        }
+        offset.dedup();
+        let mut polyline = Polyline::new(offset);
+        polyline.collapse_loops();
+        let mut vertices = polyline.to_vertices();
+        vertices.dedup_by(|a, b| a.abs_diff_eq(*b, OFFSET_TOLERANCE));
+        Polyline::new(remove_collinear(vertices))
+    }
+}
+
+/// Remove each vertex that is on the straight line between its neighbours.
+fn remove_collinear(vertices: Vec<Vec3>) -> Vec<Vec3> {
+    let mut result: Vec<Vec3> = Vec::with_capacity(vertices.len());
+    for vertex in vertices {
+        if let [.., a, b] = result[..] {
+            let incoming = (b - a).truncate();
+            let outgoing = (vertex - b).truncate();
+            if incoming.perp_dot(outgoing).abs() < OFFSET_TOLERANCE && incoming.dot(outgoing) > 0.0
+            {
+                result.pop();
+            }
+        }
+        result.push(vertex);
+    }
+    result
+}
+
+/// Get the regions to add or subtract to offset the edges of a closed [`Polygon`].
+///
+/// Each edge has a rectangle of the offset distance on the outside for a positive distance
+/// or the inside for a negative distance.
+/// Each corner that opens towards the offset has a [`JoinStyle`] between the rectangles.
+#[allow(clippy::indexing_slicing)]
+fn get_offset_pieces(polygon: &Polygon, distance: f32, join: JoinStyle) -> Vec<Vec<Vec2>> {
+    let mut ring: Vec<Vec2> = polygon
+        .get_vertices()
+        .iter()
+        .map(|vertex| vertex.truncate())
+        .collect();
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    ring.dedup();
+    let count = ring.len();
+    if count < 3 {
+        return Vec::new();
+    }
+    let sign = distance.signum();
+    let depth = distance.abs();
+    // The material is to the left of each edge so the outward normal is to the right.
+    let normals: Vec<Vec2> = (0..count)
+        .map(|i| -(ring[(i + 1) % count] - ring[i]).normalize_or_zero().perp() * sign)
+        .collect();
+    let mut pieces = Vec::new();
+    for i in 0..count {
+        let start = ring[i];
+        let end = ring[(i + 1) % count];
+        let normal = normals[i];
+        pieces.push(vec![
+            start,
+            end,
+            end + normal * depth,
+            start + normal * depth,
+        ]);
+        let previous = normals[(i + count - 1) % count];
+        if previous.perp_dot(normal) * sign > OFFSET_TOLERANCE {
+            let mut piece = vec![start];
+            piece.extend(get_join(start, previous, normal, depth, join));
+            pieces.push(piece);
+        }
+    }
+    pieces
+}
+
+/// Get the points that join the offset ends of two edges meeting at a corner.
+///
+/// The points start at `corner + normal_a * distance` and end at `corner + normal_b * distance`.
+fn get_join(
+    corner: Vec2,
+    normal_a: Vec2,
+    normal_b: Vec2,
+    distance: f32,
+    join: JoinStyle,
+) -> Vec<Vec2> {
+    let start = corner + normal_a * distance;
+    let end = corner + normal_b * distance;
+    match join {
+        Mitre(limit) => {
+            let bisector = (normal_a + normal_b).normalize_or_zero();
+            let cosine = bisector.dot(normal_a);
+            if cosine <= 0.0 || 1.0 / cosine > limit {
+                return vec![start, end];
+            }
+            vec![start, corner + bisector * distance / cosine, end]
+        }
+        Round => {
+            let angle = normal_a.angle_to(normal_b);
+            let segments = (angle.abs() / ROUND_SEGMENT_ANGLE).ceil().max(1.0);
+            #[allow(
+                clippy::as_conversions,
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss
+            )]
+            let count = segments as usize;
+            let step = angle / segments;
+            once(start)
+                .chain((1..count).scan(0.0, |rotation, _| {
+                    *rotation += step;
+                    Some(corner + Vec2::from_angle(*rotation).rotate(normal_a) * distance)
+                }))
+                .chain(once(end))
+                .collect()
+        }
+        Bevel => vec![start, end],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_total_area(polygons: &[PolygonWithHoles]) -> f32 {
+        polygons.iter().map(PolygonWithHoles::get_area).sum()
+    }
+
+    #[test]
+    fn offset_outwards() {
+        // Arrange
+        let polygon = Polygon::create_square(Vec3::ZERO, 2.0);
+
+        // Act
+        let mitre = polygon.offset(1.0, Mitre(2.0));
+        let bevel = polygon.offset(1.0, Bevel);
+        let round = polygon.offset(1.0, Round);
+
+        // Assert
+        assert_eq!(mitre.len(), 1);
+        assert!((get_total_area(&mitre) - 16.0).abs() < 0.001);
+        assert!((get_total_area(&bevel) - 14.0).abs() < 0.001);
+        assert!((get_total_area(&round) - (12.0 + PI)).abs() < 0.05);
+    }
+
+    #[test]
+    fn offset_inwards() {
+        // Arrange
+        let polygon = Polygon::create_square(Vec3::ZERO, 4.0);
+
+        // Act
+        let result = polygon.offset(-1.0, Round);
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        assert!((get_total_area(&result) - 4.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn offset_inwards_reflex_corner() {
+        // Arrange
+        let polygon = Polygon::from_open(vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(3.0, 0.0, 0.0),
+            Vec3::new(3.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(1.0, 3.0, 0.0),
+            Vec3::new(0.0, 3.0, 0.0),
+        ])
+        .expect("should be valid");
+
+        // Act
+        let mitre = polygon.offset(-0.25, Mitre(2.0));
+        let bevel = polygon.offset(-0.25, Bevel);
+
+        // Assert
+        assert_eq!(mitre.len(), 1);
+        assert!((get_total_area(&mitre) - 2.25).abs() < 0.001);
+        assert!((get_total_area(&bevel) - (2.25 + 0.25 * 0.25 / 2.0)).abs() < 0.001);
+    }
+
+    #[test]
+    fn offset_inwards_collapse() {
+        // Arrange
+        let polygon = Polygon::create_square(Vec3::ZERO, 2.0);
+
+        // Act
+        let result = polygon.offset(-1.5, Mitre(2.0));
+
+        // Assert
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn offset_inwards_split() {
+        // Arrange
+        let polygon = Polygon::from_open(vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(4.0, 0.0, 0.0),
+            Vec3::new(4.0, 1.5, 0.0),
+            Vec3::new(8.0, 1.5, 0.0),
+            Vec3::new(8.0, 0.0, 0.0),
+            Vec3::new(12.0, 0.0, 0.0),
+            Vec3::new(12.0, 4.0, 0.0),
+            Vec3::new(8.0, 4.0, 0.0),
+            Vec3::new(8.0, 2.5, 0.0),
+            Vec3::new(4.0, 2.5, 0.0),
+            Vec3::new(4.0, 4.0, 0.0),
+            Vec3::new(0.0, 4.0, 0.0),
+        ])
+        .expect("should be valid");
+
+        // Act
+        let result = polygon.offset(-0.75, Mitre(2.0));
+
+        // Assert
+        assert_eq!(result.len(), 2);
+        assert!((get_total_area(&result) - 12.5).abs() < 0.001);
+    }
+
+    #[test]
+    fn offset_with_hole() {
+        // Arrange
+        let polygon = PolygonWithHoles::new(
+            Polygon::create_square(Vec3::ZERO, 10.0),
+            vec![Polygon::create_square(Vec3::ZERO, 4.0)],
+        );
+
+        // Act
+        let inset = polygon.offset(-1.0, Mitre(2.0));
+        let outset = polygon.offset(2.5, Mitre(2.0));
+
+        // Assert
+        assert_eq!(inset.len(), 1);
+        let inset = inset.first().expect("should be a polygon");
+        assert_eq!(inset.get_holes().len(), 1);
+        assert!((inset.get_area() - (64.0 - 36.0)).abs() < 0.001);
+        assert_eq!(outset.len(), 1);
+        let outset = outset.first().expect("should be a polygon");
+        assert!(outset.get_holes().is_empty());
+        assert!((outset.get_area() - 225.0).abs() < 0.001);
+    }
+
+    #[test]
+    fn offset_polyline() {
+        // Arrange
+        let polyline = Polyline::new(vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(2.0, 2.0, 0.0),
+        ]);
+
+        // Act
+        let left = polyline.offset(1.0, Mitre(2.0));
+        let right = polyline.offset(-1.0, Mitre(2.0));
+
+        // Assert
+        let expected = [
+            Vec3::new(0.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(1.0, 2.0, 0.0),
+        ];
+        assert_eq!(left.get_vertices().len(), expected.len());
+        for (actual, expected) in left.get_vertices().iter().zip(expected) {
+            assert!(actual.abs_diff_eq(expected, 0.001));
+        }
+        let expected = [
+            Vec3::new(0.0, -1.0, 0.0),
+            Vec3::new(3.0, -1.0, 0.0),
+            Vec3::new(3.0, 2.0, 0.0),
+        ];
+        assert_eq!(right.get_vertices().len(), expected.len());
+        for (actual, expected) in right.get_vertices().iter().zip(expected) {
+            assert!(actual.abs_diff_eq(expected, 0.001));
+        }
+    }
+}
@@ -92,6 +92,21 @@ impl Polygon {
             / 2.0
     }
 
+    /// Get the unit normal of the plane of the [`Polygon`] by Newell's method.
+    ///
+    /// The vertices are wound counter-clockwise when viewed from the direction of the normal.
+    ///
+    /// Returns [`Vec3::ZERO`] if the polygon has no area.
+    #[must_use]
+    #[allow(clippy::indexing_slicing)]
+    pub fn get_normal(&self) -> Vec3 {
+        self.vertices
+            .windows(2)
+            .map(|pair| pair[0].cross(pair[1]))
+            .sum::<Vec3>()
+            .normalize_or_zero()
+    }
+
     /// Get the individual lines that form the [`Polygon`].
     #[must_use]
     #[allow(clippy::indexing_slicing)]
@@ -0,0 +1,82 @@
+use crate::Polygon;
+use bevy::prelude::*;
+
+/// The `z` of the normal below which a [`PolygonWithHoles`] is considered to face down.
+///
+/// Vertical polygons are left as they're wound.
+const VERTICAL_TOLERANCE: f32 = 0.000_1;
+
+/// A [`Polygon`] with zero or more [`Polygon`] holes on the same plane.
+///
+/// The exterior is wound counter-clockwise when viewed from above, unless it's vertical,
+/// and the holes are wound in the opposite direction to the exterior.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolygonWithHoles {
+    exterior: Polygon,
+    holes: Vec<Polygon>,
+}
+
+impl PolygonWithHoles {
+    /// Create a [`PolygonWithHoles`].
+    ///
+    /// The exterior and holes are reversed if they are not wound consistently.
+    #[must_use]
+    pub fn new(mut exterior: Polygon, holes: Vec<Polygon>) -> Self {
+        if exterior.get_normal().z < -VERTICAL_TOLERANCE {
+            exterior = reverse(exterior);
+        }
+        let normal = exterior.get_normal();
+        let holes = holes
+            .into_iter()
+            .map(|hole| {
+                if hole.get_normal().dot(normal) > 0.0 {
+                    reverse(hole)
+                } else {
+                    hole
+                }
+            })
+            .collect();
+        Self { exterior, holes }
+    }
+
+    /// Get the exterior.
+    #[must_use]
+    pub fn get_exterior(&self) -> &Polygon {
+        &self.exterior
+    }
+
+    /// Get the holes.
+    #[must_use]
+    pub fn get_holes(&self) -> &Vec<Polygon> {
+        &self.holes
+    }
+
+    /// Get the exterior followed by the holes.
+    ///
+    /// The [`PolygonWithHoles`] is consumed so no cloning takes place.
+    #[must_use]
+    pub fn to_polygons(self) -> Vec<Polygon> {
+        let mut polygons = vec![self.exterior];
+        polygons.extend(self.holes);
+        polygons
+    }
+
+    /// Calculate the area of the exterior less the area of the holes.
+    #[must_use]
+    pub fn get_area(&self) -> f32 {
+        self.exterior.get_area() - self.holes.iter().map(Polygon::get_area).sum::<f32>()
+    }
+}
+
+impl From<Polygon> for PolygonWithHoles {
+    fn from(polygon: Polygon) -> Self {
+        Self::new(polygon, Vec::new())
+    }
+}
+
+/// Reverse the order of the vertices.
+fn reverse(polygon: Polygon) -> Polygon {
+    let mut vertices = polygon.to_vertices();
+    vertices.reverse();
+    Polygon::new(vertices)
+}
@@ -0,0 +1,220 @@
+use crate::Polygon;
+use crate::*;
+use bevy::prelude::*;
+
+/// A 2D cross-section in the plane normal to a spline.
+///
+/// The `x` of each vertex is the offset to the side of the spline and the `y` is the
+/// height above the spline.
+///
+/// A closed profile is implicitly closed so the first vertex is not repeated and its
+/// vertices are wound counter-clockwise.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile {
+    /// Vertices
+    vertices: Vec<Vec2>,
+    /// Is the last vertex connected to the first?
+    is_closed: bool,
+}
+
+impl Profile {
+    /// Create a closed [`Profile`].
+    ///
+    /// If the vertices are wound clockwise they are reversed.
+    #[must_use]
+    pub fn new(mut vertices: Vec<Vec2>) -> Self {
+        if vertices
+            .last()
+            .is_some_and(|last| Some(last) == vertices.first())
+        {
+            vertices.pop();
+        }
+        if get_signed_area(&vertices) < 0.0 {
+            vertices.reverse();
+        }
+        Self {
+            vertices,
+            is_closed: true,
+        }
+    }
+
+    /// Create an open [`Profile`].
+    ///
+    /// When extruded the faces are to the right of the direction of the vertices.
+    #[must_use]
+    pub fn open(vertices: Vec<Vec2>) -> Self {
+        Self {
+            vertices,
+            is_closed: false,
+        }
+    }
+
+    /// Create a rectangular [`Profile`] from the `left`, `right`, `bottom` and `top` of a [`Vec6`].
+    #[must_use]
+    pub fn from_vec6(offsets: Vec6) -> Self {
+        Self::new(vec![
+            Vec2::new(offsets.left, offsets.bottom),
+            Vec2::new(offsets.right, offsets.bottom),
+            Vec2::new(offsets.right, offsets.top),
+            Vec2::new(offsets.left, offsets.top),
+        ])
+    }
+
+    /// Get the vertices of the [`Profile`].
+    ///
+    /// The vertices are borrowed.
+    #[must_use]
+    pub fn get_vertices(&self) -> &Vec<Vec2> {
+        &self.vertices
+    }
+
+    /// Get the vertices of the [`Profile`].
+    ///
+    /// The [`Profile`] is consumed so no cloning takes place.
+    #[must_use]
+    pub fn to_vertices(self) -> Vec<Vec2> {
+        self.vertices
+    }
+
+    /// Is the last vertex connected to the first?
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.is_closed
+    }
+
+    /// Linearly interpolate each vertex towards the matching vertex of `other`.
+    ///
+    /// Returns `None` if the vertex counts differ.
+    #[must_use]
+    pub fn lerp(&self, other: &Profile, factor: f32) -> Option<Profile> {
+        if self.vertices.len() != other.vertices.len() {
+            return None;
+        }
+        let vertices = self
+            .vertices
+            .iter()
+            .zip(&other.vertices)
+            .map(|(a, b)| a.lerp(*b, factor))
+            .collect();
+        Some(Self {
+            vertices,
+            is_closed: self.is_closed,
+        })
+    }
+
+    /// Triangulate a closed [`Profile`] with [`Polygon::triangulate`].
+    ///
+    /// Returns the indices of the vertices of each triangle wound counter-clockwise.
+    #[must_use]
+    pub fn get_triangles(&self) -> Vec<[usize; 3]> {
+        if !self.is_closed {
+            return Vec::new();
+        }
+        let vertices: Vec<Vec3> = self
+            .vertices
+            .iter()
+            .map(|vertex| vertex.extend(0.0))
+            .collect();
+        let Some(polygon) = Polygon::from_open(vertices.clone()) else {
+            return Vec::new();
+        };
+        let index_of = |vertex: &Vec3| vertices.iter().position(|other| other == vertex);
+        polygon
+            .triangulate()
+            .get_triangles()
+            .iter()
+            .filter_map(|triangle| {
+                let [a, b, c] = triangle.get_vertices();
+                Some([index_of(a)?, index_of(b)?, index_of(c)?])
+            })
+            .collect()
+    }
+
+    /// Get the average of the vertices.
+    #[must_use]
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    pub fn get_centroid(&self) -> Vec2 {
+        if self.vertices.is_empty() {
+            return Vec2::ZERO;
+        }
+        self.vertices.iter().sum::<Vec2>() / self.vertices.len() as f32
+    }
+
+    /// Calculate the area of the [`Profile`].
+    #[must_use]
+    pub fn get_area(&self) -> f32 {
+        get_signed_area(&self.vertices).abs()
+    }
+}
+
+/// Is the point inside or on the edge of the counter-clockwise triangle?
+pub(crate) fn is_inside_triangle(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    (b - a).perp_dot(point - a) >= 0.0
+        && (c - b).perp_dot(point - b) >= 0.0
+        && (a - c).perp_dot(point - c) >= 0.0
+}
+
+/// Calculate the signed area of a closed polygon with the shoelace formula.
+///
+/// The area is negative if the vertices are wound clockwise.
+#[allow(clippy::indexing_slicing)]
+pub(crate) fn get_signed_area(vertices: &[Vec2]) -> f32 {
+    let count = vertices.len();
+    (0..count)
+        .map(|i| vertices[i].perp_dot(vertices[(i + 1) % count]))
+        .sum::<f32>()
+        / 2.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_clockwise() {
+        // Arrange
+        let vertices = vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(0.0, 1.0),
+            Vec2::new(2.0, 1.0),
+            Vec2::new(2.0, 0.0),
+            Vec2::new(0.0, 0.0),
+        ];
+
+        // Act
+        let profile = Profile::new(vertices);
+
+        // Assert
+        assert_eq!(profile.get_vertices().len(), 4);
+        assert_eq!(profile.get_vertices().first(), Some(&Vec2::new(2.0, 0.0)));
+        assert!((profile.get_area() - 2.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn get_triangles_concave() {
+        // Arrange
+        let profile = Profile::new(vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(3.0, 0.0),
+            Vec2::new(3.0, 1.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(1.0, 3.0),
+            Vec2::new(0.0, 3.0),
+        ]);
+
+        // Act
+        let triangles = profile.get_triangles();
+
+        // Assert
+        assert_eq!(triangles.len(), 4);
+        let vertices = profile.get_vertices();
+        let area: f32 = triangles
+            .iter()
+            .filter_map(|[a, b, c]| {
+                let [a, b, c] = [vertices.get(*a)?, vertices.get(*b)?, vertices.get(*c)?];
+                Some((*b - *a).perp_dot(*c - *a) / 2.0)
+            })
+            .sum();
+        assert!((area - profile.get_area()).abs() < 0.001);
+    }
+}
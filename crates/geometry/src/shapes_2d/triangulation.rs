@@ -0,0 +1,402 @@
+use crate::{
+    get_signed_area, is_inside_triangle, Polygon, PolygonWithHoles, Triangle, TriangleList,
+};
+use bevy::prelude::*;
+use std::cmp::Ordering;
+use std::iter::once;
+
+/// Cross products smaller than this are treated as collinear.
+const COLLINEAR_TOLERANCE: f32 = 0.000_001;
+
+impl PolygonWithHoles {
+    /// Triangulate by ear clipping after bridging each hole to the exterior.
+    ///
+    /// The polygon may be concave and on any plane.
+    /// The triangles are wound counter-clockwise around the normal of the exterior.
+    /// - <https://en.wikipedia.org/wiki/Polygon_triangulation#Ear_clipping_method>
+    /// - <https://www.geometrictools.com/Documentation/TriangulationByEarClipping.pdf>
+    #[must_use]
+    pub fn triangulate(&self) -> TriangleList {
+        let normal = self.get_exterior().get_normal();
+        if normal == Vec3::ZERO {
+            return TriangleList::default();
+        }
+        let u = normal.any_orthonormal_vector();
+        let v = normal.cross(u);
+        let mut vertices: Vec<Vec3> = Vec::new();
+        let mut rings: Vec<Vec<usize>> = Vec::new();
+        for polygon in once(self.get_exterior()).chain(self.get_holes()) {
+            let mut ring: Vec<Vec3> = polygon.get_vertices().clone();
+            if ring.len() > 1 && ring.first() == ring.last() {
+                ring.pop();
+            }
+            if ring.len() < 3 {
+                continue;
+            }
+            let start = vertices.len();
+            vertices.append(&mut ring);
+            rings.push((start..vertices.len()).collect());
+        }
+        let points: Vec<Vec2> = vertices
+            .iter()
+            .map(|vertex| Vec2::new(vertex.dot(u), vertex.dot(v)))
+            .collect();
+        let mut rings = rings.into_iter();
+        let Some(mut outer) = rings.next() else {
+            return TriangleList::default();
+        };
+        wind_ring(&points, &mut outer, true);
+        let mut holes: Vec<Vec<usize>> = rings
+            .map(|mut hole| {
+                wind_ring(&points, &mut hole, false);
+                hole
+            })
+            .collect();
+        holes.sort_by(|a, b| get_max_x(&points, b).total_cmp(&get_max_x(&points, a)));
+        for hole in holes {
+            outer = bridge_hole(&points, outer, &hole);
+        }
+        let triangles = clip_ears(&points, outer)
+            .into_iter()
+            .filter_map(|[a, b, c]| {
+                Some(Triangle::new([
+                    *vertices.get(a)?,
+                    *vertices.get(b)?,
+                    *vertices.get(c)?,
+                ]))
+            })
+            .collect();
+        TriangleList::new(triangles)
+    }
+}
+
+impl Polygon {
+    /// Triangulate by ear clipping.
+    ///
+    /// The triangles are wound as the exterior of a [`PolygonWithHoles`] so a horizontal
+    /// polygon faces up.
+    #[must_use]
+    pub fn triangulate(&self) -> TriangleList {
+        PolygonWithHoles::new(self.clone(), Vec::new()).triangulate()
+    }
+}
+
+/// Reverse the ring if it's not wound counter-clockwise, or clockwise if
+/// `is_counter_clockwise` is false.
+fn wind_ring(points: &[Vec2], ring: &mut [usize], is_counter_clockwise: bool) {
+    let vertices: Vec<Vec2> = ring
+        .iter()
+        .filter_map(|&index| points.get(index).copied())
+        .collect();
+    if (get_signed_area(&vertices) > 0.0) != is_counter_clockwise {
+        ring.reverse();
+    }
+}
+
+/// Get the greatest `x` of the ring.
+fn get_max_x(points: &[Vec2], ring: &[usize]) -> f32 {
+    ring.iter()
+        .filter_map(|&index| points.get(index))
+        .map(|point| point.x)
+        .fold(f32::MIN, f32::max)
+}
+
+/// Join a clockwise hole to a counter-clockwise outer ring with a pair of coincident edges
+/// to a mutually visible vertex.
+///
+/// A ray from the vertex of the hole with the greatest `x` is cast towards `+x` and the
+/// nearest vertex of the outer ring that is visible from it is found.
+#[allow(clippy::indexing_slicing, clippy::float_cmp)]
+fn bridge_hole(points: &[Vec2], outer: Vec<usize>, hole: &[usize]) -> Vec<usize> {
+    let Some((hole_start, &m)) = hole
+        .iter()
+        .enumerate()
+        .max_by(|(_, &a), (_, &b)| points[a].x.total_cmp(&points[b].x))
+    else {
+        return outer;
+    };
+    let pm = points[m];
+    let count = outer.len();
+    let mut nearest: Option<(Vec2, usize)> = None;
+    for i in 0..count {
+        let a = points[outer[i]];
+        let b = points[outer[(i + 1) % count]];
+        if (a.y > pm.y) == (b.y > pm.y) && a.y != pm.y {
+            continue;
+        }
+        let hit = if a.y == b.y {
+            if a.x < b.x {
+                a
+            } else {
+                b
+            }
+        } else {
+            Vec2::new(a.x + (pm.y - a.y) / (b.y - a.y) * (b.x - a.x), pm.y)
+        };
+        if hit.x < pm.x || nearest.is_some_and(|(nearest, _)| nearest.x <= hit.x) {
+            continue;
+        }
+        let candidate = if a.x > b.x { i } else { (i + 1) % count };
+        nearest = Some((hit, candidate));
+    }
+    let Some((hit, mut candidate)) = nearest.or_else(|| {
+        let nearest = (0..count).min_by(|&a, &b| {
+            let a = points[outer[a]].distance_squared(pm);
+            let b = points[outer[b]].distance_squared(pm);
+            a.total_cmp(&b)
+        })?;
+        Some((points[outer[nearest]], nearest))
+    }) else {
+        return outer;
+    };
+    let p = points[outer[candidate]];
+    if p != hit {
+        let mut best_angle = f32::MAX;
+        let mut best_distance = f32::MAX;
+        for (i, &index) in outer.iter().enumerate() {
+            let point = points[index];
+            if point == p || !is_inside_either(point, pm, hit, p) {
+                continue;
+            }
+            let offset = point - pm;
+            let angle = offset.y.abs().atan2(offset.x);
+            let distance = offset.length_squared();
+            let is_better = match angle.total_cmp(&best_angle) {
+                Ordering::Less => true,
+                Ordering::Equal => distance < best_distance,
+                Ordering::Greater => false,
+            };
+            if is_better {
+                best_angle = angle;
+                best_distance = distance;
+                candidate = i;
+            }
+        }
+    }
+    // Once a hole is bridged its outer vertex is duplicated so pick the duplicate that
+    // faces the hole.
+    let p = points[outer[candidate]];
+    if let Some(duplicate) =
+        (0..count).find(|&i| points[outer[i]] == p && is_in_corner(points, &outer, i, pm))
+    {
+        candidate = duplicate;
+    }
+    let mut bridged = Vec::with_capacity(outer.len() + hole.len() + 2);
+    bridged.extend_from_slice(&outer[..=candidate]);
+    bridged.extend(hole.iter().cycle().skip(hole_start).take(hole.len() + 1));
+    bridged.extend_from_slice(&outer[candidate..]);
+    bridged
+}
+
+/// Is the point within the interior angle at a vertex of a counter-clockwise ring?
+#[allow(clippy::indexing_slicing)]
+fn is_in_corner(points: &[Vec2], ring: &[usize], index: usize, point: Vec2) -> bool {
+    let count = ring.len();
+    let previous = points[ring[(index + count - 1) % count]];
+    let vertex = points[ring[index]];
+    let next = points[ring[(index + 1) % count]];
+    let is_left_of_previous = (vertex - previous).perp_dot(point - vertex) >= 0.0;
+    let is_left_of_next = (next - vertex).perp_dot(point - vertex) >= 0.0;
+    if (vertex - previous).perp_dot(next - vertex) >= 0.0 {
+        is_left_of_previous && is_left_of_next
+    } else {
+        is_left_of_previous || is_left_of_next
+    }
+}
+
+/// Triangulate a counter-clockwise ring by ear clipping.
+///
+/// Returns the indices of the vertices of each triangle.
+#[allow(clippy::indexing_slicing)]
+fn clip_ears(points: &[Vec2], mut ring: Vec<usize>) -> Vec<[usize; 3]> {
+    let mut triangles = Vec::new();
+    while ring.len() > 3 {
+        let count = ring.len();
+        let corner = |i: usize| {
+            [
+                ring[(i + count - 1) % count],
+                ring[i],
+                ring[(i + 1) % count],
+            ]
+        };
+        let ear = (0..count).find(|&i| is_ear(points, &ring, corner(i)));
+        let Some(i) = ear else {
+            // Collinear vertices have no area so are removed without a triangle.
+            // Self-intersecting rings may not have an ear so clip any vertex.
+            let i = (0..count)
+                .find(|&i| get_cross(points, corner(i)).abs() <= COLLINEAR_TOLERANCE)
+                .unwrap_or(0);
+            let [a, b, c] = corner(i);
+            if get_cross(points, [a, b, c]) > COLLINEAR_TOLERANCE {
+                triangles.push([a, b, c]);
+            }
+            ring.remove(i);
+            continue;
+        };
+        triangles.push(corner(i));
+        ring.remove(i);
+    }
+    if let [a, b, c] = ring[..] {
+        if get_cross(points, [a, b, c]) > COLLINEAR_TOLERANCE {
+            triangles.push([a, b, c]);
+        }
+    }
+    triangles
+}
+
+/// Is the corner convex and free of any other vertex of the ring?
+///
+/// Vertices coincident with the corner are ignored as they are the ends of a bridge.
+#[allow(clippy::indexing_slicing)]
+fn is_ear(points: &[Vec2], ring: &[usize], [a, b, c]: [usize; 3]) -> bool {
+    if get_cross(points, [a, b, c]) <= COLLINEAR_TOLERANCE {
+        return false;
+    }
+    let [pa, pb, pc] = [points[a], points[b], points[c]];
+    !ring
+        .iter()
+        .map(|&index| points[index])
+        .filter(|&point| point != pa && point != pb && point != pc)
+        .any(|point| is_inside_triangle(point, pa, pb, pc))
+}
+
+/// Get the cross product of the edges either side of the middle vertex.
+///
+/// Positive if the corner is convex in a counter-clockwise ring.
+#[allow(clippy::indexing_slicing)]
+fn get_cross(points: &[Vec2], [a, b, c]: [usize; 3]) -> f32 {
+    (points[b] - points[a]).perp_dot(points[c] - points[b])
+}
+
+/// Is the point inside or on the edge of the triangle wound in either direction?
+fn is_inside_either(point: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    is_inside_triangle(point, a, b, c) || is_inside_triangle(point, a, c, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_total_area(triangles: &TriangleList) -> f32 {
+        triangles
+            .get_triangles()
+            .iter()
+            .map(|triangle| {
+                let [a, b, c] = *triangle.get_vertices();
+                (b - a).cross(c - a).length() / 2.0
+            })
+            .sum()
+    }
+
+    fn is_facing(triangles: &TriangleList, normal: Vec3) -> bool {
+        triangles
+            .get_triangles()
+            .iter()
+            .all(|triangle| triangle.get_normal().abs_diff_eq(normal, 0.001))
+    }
+
+    #[test]
+    fn triangulate_concave() {
+        // Arrange
+        let polygon = Polygon::from_open(vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(3.0, 0.0, 0.0),
+            Vec3::new(3.0, 1.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(1.0, 3.0, 0.0),
+            Vec3::new(0.0, 3.0, 0.0),
+        ])
+        .expect("should be valid");
+
+        // Act
+        let triangles = polygon.triangulate();
+
+        // Assert
+        assert_eq!(triangles.get_triangles().len(), 4);
+        assert!((get_total_area(&triangles) - 5.0).abs() < 0.001);
+        assert!(is_facing(&triangles, Vec3::Z));
+    }
+
+    #[test]
+    fn triangulate_clockwise() {
+        // Arrange
+        let mut vertices = Polygon::create_square(Vec3::ZERO, 2.0).to_vertices();
+        vertices.reverse();
+        let polygon = Polygon::new(vertices);
+
+        // Act
+        let triangles = polygon.triangulate();
+
+        // Assert
+        assert_eq!(triangles.get_triangles().len(), 2);
+        assert!((get_total_area(&triangles) - 4.0).abs() < 0.001);
+        assert!(is_facing(&triangles, Vec3::Z));
+    }
+
+    #[test]
+    fn triangulate_collinear() {
+        // Arrange
+        let polygon = Polygon::from_open(vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(2.0, 0.0, 0.0),
+            Vec3::new(2.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ])
+        .expect("should be valid");
+
+        // Act
+        let triangles = polygon.triangulate();
+
+        // Assert
+        assert_eq!(triangles.get_triangles().len(), 3);
+        assert!((get_total_area(&triangles) - 2.0).abs() < 0.001);
+        assert!(is_facing(&triangles, Vec3::Z));
+    }
+
+    #[test]
+    fn triangulate_holes() {
+        // Arrange
+        let exterior = Polygon::create_square(Vec3::ZERO, 10.0);
+        let holes = vec![
+            Polygon::create_square(Vec3::new(-2.0, 0.0, 0.0), 2.0),
+            Polygon::create_diamond(Vec3::new(2.0, 2.0, 0.0), 2.0),
+        ];
+        let polygon = PolygonWithHoles::new(exterior, holes);
+
+        // Act
+        let triangles = polygon.triangulate();
+
+        // Assert
+        assert!((get_total_area(&triangles) - polygon.get_area()).abs() < 0.001);
+        assert!((get_total_area(&triangles) - 92.0).abs() < 0.001);
+        assert!(is_facing(&triangles, Vec3::Z));
+    }
+
+    #[test]
+    fn triangulate_vertical() {
+        // Arrange
+        let exterior = Polygon::from_open(vec![
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(4.0, 0.0, 0.0),
+            Vec3::new(4.0, 0.0, 3.0),
+            Vec3::new(0.0, 0.0, 3.0),
+        ])
+        .expect("should be valid");
+        let hole = Polygon::from_open(vec![
+            Vec3::new(1.0, 0.0, 1.0),
+            Vec3::new(2.0, 0.0, 1.0),
+            Vec3::new(2.0, 0.0, 2.0),
+            Vec3::new(1.0, 0.0, 2.0),
+        ])
+        .expect("should be valid");
+        let polygon = PolygonWithHoles::new(exterior, vec![hole]);
+
+        // Act
+        let triangles = polygon.triangulate();
+
+        // Assert
+        assert!((get_total_area(&triangles) - 11.0).abs() < 0.001);
+        assert!(is_facing(&triangles, Vec3::NEG_Y));
+    }
+}
@@ -1,5 +1,6 @@
 use bevy::math::Vec3;
 use konstruo_core::{Vec3Extensions, HALF_PI, PI};
+use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 use std::ops::Neg;
 use Orientation::*;
@@ -11,7 +12,7 @@ use Orientation::*;
 /// An edge is defined by two orientations.
 ///
 /// A corner is defined by three orientations.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub enum Orientation {
     Front,
     Back,
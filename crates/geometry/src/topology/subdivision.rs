@@ -1,129 +1,111 @@
-use crate::{Line, Polygon};
+use crate::{Polygon, PolygonWithHoles, Triangle, TriangleList};
 use bevy::prelude::*;
-use konstruo_core::{Vec3Extensions, VecVec3Extensions};
-use std::ops::Neg;
-// TODO: Rename to RectangularOpeningSubtraction
 
-/// A strategy to subdivide a rectangle into multiple rectangle by subtracting openings.
+/// Area in square metres below which a face is considered empty.
+const AREA_TOLERANCE: f32 = 0.000_1;
+
+/// A strategy to subtract openings from a planar face and triangulate what remains.
+///
+/// Openings may be any shape, overlap each other, be in any order and extend beyond the face.
 #[derive(Clone)]
 pub struct Subdivision {
-    pub bounds: [Vec3; 4],
-    pub openings: Vec<[Vec3; 4]>,
+    /// The face.
+    pub bounds: Polygon,
+    /// The openings to subtract from the face.
+    ///
+    /// Each opening is projected onto the plane of the face.
+    pub openings: Vec<Polygon>,
+    /// The horizontal axis of the plane of the face.
     pub main_axis: Vec3,
+    /// The vertical axis of the plane of the face.
     pub cross_axis: Vec3,
 }
 
 #[derive(Debug)]
 pub enum SubdivisionError {
-    BoundWinding,
-    GetBoundTop,
-    GetBoundBottom,
-    OpeningWinding(usize),
-    GetOpeningLeft(usize),
-    GetOpeningRight(usize),
+    ParallelAxes,
+    BoundArea,
 }
 
 impl Subdivision {
-    /// Divide into multiple rectangles.
-    pub fn execute(self) -> Result<Vec<[Vec3; 4]>, SubdivisionError> {
-        let normal = self.main_axis.cross(self.cross_axis).normalize();
-        if !&self.bounds.is_ccw(normal).expect("bounds should be valid") {
-            return Err(SubdivisionError::BoundWinding);
+    /// Subtract the openings and triangulate the remaining face.
+    ///
+    /// The triangles are wound counter-clockwise around the cross product of the main and
+    /// cross axes.
+    pub fn execute(self) -> Result<TriangleList, SubdivisionError> {
+        let right = self.main_axis.normalize_or_zero();
+        let up = self.cross_axis.reject_from(right).normalize_or_zero();
+        if right == Vec3::ZERO || up == Vec3::ZERO {
+            return Err(SubdivisionError::ParallelAxes);
+        }
+        let origin = self
+            .bounds
+            .get_vertices()
+            .first()
+            .copied()
+            .unwrap_or_default();
+        let to_local = |polygon: Polygon| {
+            let vertices = polygon
+                .to_vertices()
+                .into_iter()
+                .map(|vertex| {
+                    let offset = vertex - origin;
+                    Vec3::new(offset.dot(right), offset.dot(up), 0.0)
+                })
+                .collect();
+            PolygonWithHoles::from(Polygon::new(vertices))
+        };
+        let face = to_local(self.bounds);
+        if face.get_area() < AREA_TOLERANCE {
+            return Err(SubdivisionError::BoundArea);
         }
-        let mut top_bound = get_edge_by_direction(&get_edges(self.bounds), self.main_axis.neg())
-            .ok_or(SubdivisionError::GetBoundTop)?;
-        let mut bottom_bound = get_edge_by_direction(&get_edges(self.bounds), self.main_axis)
-            .ok_or(SubdivisionError::GetBoundTop)?;
-        let mut rectangles = Vec::new();
-        for (index, opening) in self.openings.into_iter().enumerate() {
-            if opening.is_ccw(normal).expect("opening should be valid") {
-                return Err(SubdivisionError::OpeningWinding(index));
-            }
-            let edges = get_edges(opening);
-            let left = get_edge_by_direction(&edges, self.cross_axis)
-                .ok_or(SubdivisionError::GetOpeningLeft(index))?;
-            let right = get_edge_by_direction(&edges, self.cross_axis.neg())
-                .ok_or(SubdivisionError::GetOpeningRight(index))?;
-            // Create rectangle to the left of the opening
-            let full = [
-                bottom_bound.start,
-                bottom_bound.project(left.start),
-                top_bound.project(left.start),
-                top_bound.end,
-            ];
-            top_bound.end = full[2];
-            bottom_bound.start = full[1];
-            push_if_not_zero(&mut rectangles, full);
-            // Create rectangle above the opening
-            let above = [
-                left.end,
-                right.start,
-                top_bound.project(right.start),
-                top_bound.project(left.end),
-            ];
-            // Create rectangle below the opening
-            let below = [
-                bottom_bound.project(left.start),
-                bottom_bound.project(right.end),
-                right.end,
-                left.start,
-            ];
-            top_bound.end = above[2];
-            bottom_bound.start = below[1];
-            push_if_not_zero(&mut rectangles, above);
-            push_if_not_zero(&mut rectangles, below);
+        let mut regions = vec![face];
+        for opening in self.openings {
+            let opening = to_local(opening);
+            regions = regions
+                .iter()
+                .flat_map(|region| region.difference(&opening))
+                .collect();
         }
-        // Create last rectangle
-        let last = [
-            bottom_bound.start,
-            bottom_bound.end,
-            top_bound.start,
-            top_bound.end,
-        ];
-        push_if_not_zero(&mut rectangles, last);
-        Ok(rectangles)
+        let triangles = regions
+            .iter()
+            .flat_map(|region| region.triangulate().get_triangles().clone())
+            .map(|triangle| {
+                Triangle::new(
+                    triangle
+                        .to_vertices()
+                        .map(|vertex| origin + right * vertex.x + up * vertex.y),
+                )
+            })
+            .collect();
+        Ok(TriangleList::new(triangles))
     }
 
     #[must_use]
     pub fn example() -> Self {
+        let rectangle = |x0: f32, y0: f32, x1: f32, y1: f32| {
+            Polygon::from_open(vec![
+                Vec3::new(x0, y0, 0.0),
+                Vec3::new(x0, y1, 0.0),
+                Vec3::new(x1, y1, 0.0),
+                Vec3::new(x1, y0, 0.0),
+            ])
+            .expect("polygon should be valid")
+        };
         Self {
-            bounds: [
+            bounds: Polygon::from_open(vec![
                 Vec3::new(0.0, 0.0, 0.0),
                 Vec3::new(12.0, 0.0, 0.0),
                 Vec3::new(12.0, 12.0, 0.0),
                 Vec3::new(0.0, 12.0, 0.0),
-            ],
+            ])
+            .expect("polygon should be valid"),
             openings: vec![
-                [
-                    Vec3::new(1.0, 3.0, 0.0),
-                    Vec3::new(1.0, 9.0, 0.0),
-                    Vec3::new(2.0, 9.0, 0.0),
-                    Vec3::new(2.0, 3.0, 0.0),
-                ],
-                [
-                    Vec3::new(3.0, 2.0, 0.0),
-                    Vec3::new(3.0, 5.0, 0.0),
-                    Vec3::new(4.0, 5.0, 0.0),
-                    Vec3::new(4.0, 2.0, 0.0),
-                ],
-                [
-                    Vec3::new(4.0, 7.0, 0.0),
-                    Vec3::new(4.0, 10.0, 0.0),
-                    Vec3::new(5.0, 10.0, 0.0),
-                    Vec3::new(5.0, 7.0, 0.0),
-                ],
-                [
-                    Vec3::new(6.0, 0.0, 0.0),
-                    Vec3::new(6.0, 12.0, 0.0),
-                    Vec3::new(8.0, 12.0, 0.0),
-                    Vec3::new(8.0, 0.0, 0.0),
-                ],
-                [
-                    Vec3::new(9.0, 6.0, 0.0),
-                    Vec3::new(9.0, 12.0, 0.0),
-                    Vec3::new(11.0, 12.0, 0.0),
-                    Vec3::new(11.0, 6.0, 0.0),
-                ],
+                rectangle(1.0, 3.0, 2.0, 9.0),
+                rectangle(3.0, 2.0, 4.0, 5.0),
+                rectangle(4.0, 7.0, 5.0, 10.0),
+                rectangle(6.0, 0.0, 8.0, 12.0),
+                rectangle(9.0, 6.0, 11.0, 12.0),
             ],
             main_axis: Vec3::X,
             cross_axis: Vec3::Y,
@@ -131,41 +113,133 @@ impl Subdivision {
     }
 }
 
-fn push_if_not_zero(rectangles: &mut Vec<[Vec3; 4]>, rectangle: [Vec3; 4]) {
-    let polygon = Polygon::from_open(rectangle.to_vec()).expect("polygon should be valid");
-    if polygon.get_area() > 0.0 {
-        rectangles.push(rectangle);
-    }
-}
-
-#[allow(clippy::indexing_slicing)]
-fn get_edges(rectangle: [Vec3; 4]) -> Vec<Line> {
-    let polygon = Polygon::from_open(rectangle.to_vec()).expect("polygon should be valid");
-    polygon.to_lines()
-}
-
-#[allow(clippy::indexing_slicing)]
-fn get_edge_by_direction(edges: &[Line], direction: Vec3) -> Option<Line> {
-    edges
-        .iter()
-        .find(|&edge| edge.get_direction().is_almost_equal_to(direction))
-        .copied()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::f32::consts::TAU;
+
+    fn get_area(triangles: &TriangleList) -> f32 {
+        triangles
+            .get_triangles()
+            .iter()
+            .map(|triangle| {
+                let [a, b, c] = *triangle.get_vertices();
+                0.5 * (b - a).cross(c - a).length()
+            })
+            .sum()
+    }
+
+    fn is_facing(triangles: &TriangleList, normal: Vec3) -> bool {
+        triangles.get_triangles().iter().all(|triangle| {
+            let [a, b, c] = *triangle.get_vertices();
+            (b - a).cross(c - a).dot(normal) > 0.0
+        })
+    }
+
+    fn square(x: f32, y: f32, size: f32) -> Polygon {
+        Polygon::create_square(Vec3::new(x, y, 0.0), size)
+    }
 
     #[test]
-    fn execute() {
+    fn execute() -> Result<(), SubdivisionError> {
         // Arrange
         let subdivision = Subdivision::example();
 
         // Act
-        let result = subdivision.execute();
+        let result = subdivision.execute()?;
+
+        // Assert
+        assert!((get_area(&result) - 96.0).abs() < 0.001);
+        assert!(is_facing(&result, Vec3::Z));
+        Ok(())
+    }
+
+    #[test]
+    fn execute_overlapping_out_of_order() -> Result<(), SubdivisionError> {
+        // Arrange
+        let mut subdivision = Subdivision::example();
+        subdivision.openings = vec![square(6.0, 6.0, 4.0), square(4.0, 4.0, 4.0)];
+
+        // Act
+        let result = subdivision.execute()?;
+
+        // Assert
+        assert!((get_area(&result) - (144.0 - 28.0)).abs() < 0.001);
+        Ok(())
+    }
+
+    #[test]
+    fn execute_crossing_bounds() -> Result<(), SubdivisionError> {
+        // Arrange
+        let mut subdivision = Subdivision::example();
+        subdivision.openings = vec![square(12.0, 6.0, 4.0)];
+
+        // Act
+        let result = subdivision.execute()?;
+
+        // Assert
+        assert!((get_area(&result) - (144.0 - 8.0)).abs() < 0.001);
+        Ok(())
+    }
+
+    #[test]
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    fn execute_circle() -> Result<(), SubdivisionError> {
+        // Arrange
+        let segments = 32;
+        let circle: Vec<Vec3> = (0..segments)
+            .map(|i| {
+                let angle = TAU * i as f32 / segments as f32;
+                Vec3::new(6.0 + 2.0 * angle.cos(), 6.0 + 2.0 * angle.sin(), 0.0)
+            })
+            .collect();
+        let circle = Polygon::from_open(circle).expect("polygon should be valid");
+        let expected = 144.0 - circle.get_area();
+        let mut subdivision = Subdivision::example();
+        subdivision.openings = vec![circle];
+
+        // Act
+        let result = subdivision.execute()?;
+
+        // Assert
+        assert!((get_area(&result) - expected).abs() < 0.001);
+        assert!(is_facing(&result, Vec3::Z));
+        Ok(())
+    }
+
+    #[test]
+    fn execute_vertical() -> Result<(), SubdivisionError> {
+        // Arrange
+        let subdivision = Subdivision {
+            bounds: Polygon::from_open(vec![
+                Vec3::new(0.0, 0.0, 0.0),
+                Vec3::new(4.0, 0.0, 0.0),
+                Vec3::new(4.0, 0.0, 3.0),
+                Vec3::new(0.0, 0.0, 3.0),
+            ])
+            .expect("polygon should be valid"),
+            openings: vec![Polygon::from_open(vec![
+                Vec3::new(1.0, 0.5, 1.0),
+                Vec3::new(2.0, 0.5, 1.0),
+                Vec3::new(2.0, 0.5, 2.0),
+                Vec3::new(1.0, 0.5, 2.0),
+            ])
+            .expect("polygon should be valid")],
+            main_axis: Vec3::X,
+            cross_axis: Vec3::Z,
+        };
+
+        // Act
+        let result = subdivision.execute()?;
 
         // Assert
-        assert!(result.is_ok());
-        assert_eq!(result.expect("should be some").len(), 12);
+        assert!((get_area(&result) - 11.0).abs() < 0.001);
+        assert!(is_facing(&result, Vec3::NEG_Y));
+        assert!(result
+            .get_triangles()
+            .iter()
+            .flat_map(Triangle::get_vertices)
+            .all(|vertex| vertex.y.abs() < 0.001));
+        Ok(())
     }
 }
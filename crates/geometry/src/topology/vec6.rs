@@ -1,6 +1,8 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(default)]
 pub struct Vec6 {
     /// Left (-x)
     pub left: f32,
@@ -86,6 +88,16 @@ impl Vec6 {
         }
     }
 
+    /// Swap the left and right values.
+    #[must_use]
+    pub fn mirror_x(self) -> Self {
+        Self {
+            left: self.right,
+            right: self.left,
+            ..self
+        }
+    }
+
     /// Ensure that positive values are greater than negative values.
     #[must_use]
     pub fn fix_order(self) -> Self {
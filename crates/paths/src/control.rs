@@ -26,6 +26,16 @@ impl PathControl {
         }
     }
 
+    /// Get the type of the control from which the angle of this control is snapped.
+    ///
+    /// Handles are snapped about their origin and origins about the other end of the curve.
+    fn get_angle_origin(&self) -> ControlType {
+        match self.control_type {
+            Start | EndHandle => End,
+            StartHandle | End => Start,
+        }
+    }
+
     /// Update the [`Transform`] when a control is moved.
     pub(super) fn on_control_moved(
         mut events: MessageReader<ControlMoved>,
@@ -74,6 +84,8 @@ impl PathControl {
 
 impl PathFactory<'_> {
     /// Spawn [`PathControl`] for each control point in a [`Path`]
+    ///
+    /// The end of a closed spline is the start so it only has one control.
     pub(super) fn spawn_controls(
         &mut self,
         spline: &CubicBezierSpline,
@@ -102,7 +114,10 @@ impl PathFactory<'_> {
                 bezier.get_control(EndHandle),
                 visibility,
             ));
-            bundles.push(self.control_bundle(End, curve, bezier.get_control(End), visibility));
+            let is_last = curve == spline.get_curves().len() - 1;
+            if !(is_last && spline.is_closed()) {
+                bundles.push(self.control_bundle(End, curve, bezier.get_control(End), visibility));
+            }
             for bundle in bundles {
                 self.commands
                     .spawn(bundle)
@@ -192,6 +207,8 @@ fn on_pointer_drag(
     mut paths: Query<(&mut Path, Entity)>,
     window: Query<&Window, With<PrimaryWindow>>,
     camera: Query<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+    snapping: Res<Snapping>,
+    mut indicator: ResMut<SnapIndicator>,
 ) {
     if trigger.button != PointerButton::Primary {
         return;
@@ -200,16 +217,26 @@ fn on_pointer_drag(
         error!("Failed to get PathControl");
         return;
     };
-    let Ok(translation) = Cursor::from_window(&window, &camera) else {
+    let Ok(cursor) = Cursor::from_window(&window, &camera) else {
         warn!("Failed to get cursor on ground");
         return;
     };
+    let others = paths
+        .iter()
+        .filter(|(_, entity)| *entity != child_of.parent())
+        .map(|(path, _)| path);
+    let targets = Path::get_snap_targets(others);
     let Ok((mut path, entity)) = paths.get_mut(child_of.parent()) else {
         warn!("Failed to get Path");
         return;
     };
+    let origin = path
+        .spline
+        .get_control(control.get_angle_origin(), control.curve);
+    let snap = snapping.snap(cursor, &targets.with_origin(origin));
+    indicator.set(snap);
     path.spline
-        .update_control(control.control_type, control.curve, translation);
+        .update_control(control.control_type, control.curve, snap.position);
     event_writer.write(ControlMoved {
         path: entity,
         spline: path.spline.clone(),
@@ -219,15 +246,26 @@ fn on_pointer_drag(
 fn on_pointer_drag_end(
     trigger: On<Pointer<DragEnd>>,
     materials: Res<PathMaterials>,
-    mut query: Query<(&mut EntityState, &mut MeshMaterial3d<StandardMaterial>)>,
+    mut query: Query<(
+        &mut EntityState,
+        &mut MeshMaterial3d<StandardMaterial>,
+        &ChildOf,
+    )>,
+    mut edited: MessageWriter<PathEdited>,
+    mut indicator: ResMut<SnapIndicator>,
 ) {
     if trigger.button != PointerButton::Primary {
         return;
     }
-    let Ok((mut state, mut material)) = query.get_mut(trigger.original_event_target()) else {
+    let Ok((mut state, mut material, child_of)) = query.get_mut(trigger.original_event_target())
+    else {
         error!("Failed to get PathControl");
         return;
     };
     *state = EntityState::Default;
     *material = MeshMaterial3d(materials.control_node.clone());
+    indicator.clear();
+    edited.write(PathEdited {
+        path: child_of.parent(),
+    });
 }
@@ -22,6 +22,14 @@ pub struct CurveAdded {
     pub spline: CubicBezierSpline,
 }
 
+/// Drawing or editing of a path has been completed.
+///
+/// Unlike [`CurveAdded`] and [`ControlMoved`] this is not written on every frame of a drag.
+#[derive(Debug, Message)]
+pub struct PathEdited {
+    pub path: Entity,
+}
+
 impl SplineChanged {
     /// Trigger a [`SplineChanged`] on [`CurveAdded`].
     pub(super) fn on_curve_added(
@@ -4,7 +4,6 @@ use konstruo_beziers::constants::*;
 use konstruo_beziers::CubicBezier;
 use konstruo_beziers::*;
 use konstruo_geometry::Line;
-use ControlType::End;
 use PathSurfacePosition::*;
 
 /// An intersection between [`Path`].
@@ -12,6 +11,8 @@ use PathSurfacePosition::*;
 #[require(InheritedVisibility, Transform)]
 pub struct PathIntersection {
     pub paths: Vec<PathIntersectionInfo>,
+    /// Radius of the kerb where the edges of adjacent paths meet.
+    pub kerb_radius: f32,
 }
 
 pub struct PathIntersectionInfo {
@@ -34,27 +35,28 @@ impl PathIntersectionInfo {
 }
 
 impl PathIntersection {
-    pub(super) fn new(entities: Vec<PathIntersectionInfo>) -> Self {
-        Self { paths: entities }
+    pub(super) fn new(entities: Vec<PathIntersectionInfo>, kerb_radius: f32) -> Self {
+        Self {
+            paths: entities,
+            kerb_radius,
+        }
     }
 
     pub fn get_centered_polygon(&self) -> Result<CubicBezierSpline, CubicBezierSplineError> {
-        let mut curves: Vec<CubicBezier> = Vec::new();
+        let mut polygon: Option<CubicBezierSpline> = None;
         for i in 0..self.paths.len() {
             let corner = self.get_offset_corner(i, false)?;
-            if i != 0 {
-                let start = curves
-                    .last()
-                    .expect("should be at least one curve")
-                    .get_control(End);
-                let end = corner.get_start();
-                curves.push(
-                    CubicBezier::from_line(Line::new(start, end)).expect("bezier should be valid"),
-                );
-            }
-            curves.append(&mut corner.to_curves());
+            let Some(spline) = polygon.as_mut() else {
+                polygon = Some(corner);
+                continue;
+            };
+            let line = CubicBezier::from_line(Line::new(spline.get_end(), corner.get_start()))
+                .expect("bezier should be valid");
+            spline.append(CubicBezierSpline::new(vec![line])?)?;
+            spline.set_continuity(spline.get_node_count() - 1, NodeContinuity::Corner)?;
+            spline.append(corner)?;
         }
-        CubicBezierSpline::new(curves)
+        polygon.ok_or(CubicBezierSplineError::NoCurves)
     }
 
     fn get_offset_corner(
@@ -74,28 +76,6 @@ impl PathIntersection {
         let path_b = self.paths.get(index_b).expect("next index should be valid");
         let dist_b = path_b.get_offset_value(inside);
         let spline_b = path_b.spline.offset(dist_b, OFFSET_ACCURACY)?;
-        let end_a = spline_a.get_end();
-        let start_b = spline_b.get_start();
-        if end_a.abs_diff_eq(start_b, CONNECTION_TOLERANCE) {
-            let mut curves = spline_a.to_curves();
-            curves.append(&mut spline_b.to_curves());
-            return CubicBezierSpline::new(curves);
-        }
-        let Some(intersections) =
-            spline_a.get_intersections_with_spline(&spline_b, INTERSECTION_TOLERANCE)
-        else {
-            todo!("Fillet between the ends of non-intersecting splines");
-        };
-        if intersections.len() > 1 {
-            todo!("Sort intersections to find the closest to the end");
-        }
-        let intersection = *intersections.first().expect("should be at least one");
-        let param_a = spline_a.get_param_nearest_to(intersection, INTERSECTION_ACCURACY);
-        let param_b = spline_b.get_param_nearest_to(intersection, INTERSECTION_ACCURACY);
-        let [split_a, _] = spline_a.split_at_param(param_a)?;
-        let [_, split_b] = spline_b.split_at_param(param_b)?;
-        let mut curves = split_a.to_curves();
-        curves.append(&mut split_b.to_curves());
-        CubicBezierSpline::new(curves)
+        spline_a.fillet(&spline_b, self.kerb_radius)
     }
 }
@@ -5,11 +5,15 @@ use konstruo_beziers::*;
 use konstruo_core::Vec3Extensions;
 use PathIntersectionError::Mismatch;
 
+/// Default radius of the kerb where the edges of adjacent paths meet.
+pub const DEFAULT_KERB_RADIUS: f32 = 6.0;
+
 /// An intersection between [`Path`].
 #[derive(Component, Default)]
 #[require(InheritedVisibility, Transform)]
 pub struct PathIntersectionBuilder {
     paths: Vec<PathIntersectionInfo>,
+    kerb_radius: Option<f32>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -27,13 +31,21 @@ impl PathIntersectionBuilder {
         });
     }
 
+    /// Set the radius of the kerb where the edges of adjacent paths meet.
+    #[must_use]
+    pub fn with_kerb_radius(mut self, kerb_radius: f32) -> Self {
+        self.kerb_radius = Some(kerb_radius);
+        self
+    }
+
     pub fn build(self) -> Result<PathIntersection, PathIntersectionError> {
         if self.paths.len() < 2 {
             return Err(PathIntersectionError::Count(self.paths.len()));
         }
         let entities = start_from_intersection(self.paths)?;
         let entities = wind_ccw(entities);
-        Ok(PathIntersection::new(entities))
+        let kerb_radius = self.kerb_radius.unwrap_or(DEFAULT_KERB_RADIUS);
+        Ok(PathIntersection::new(entities, kerb_radius))
     }
 }
 
@@ -78,8 +90,9 @@ fn wind_ccw(mut paths: Vec<PathIntersectionInfo>) -> Vec<PathIntersectionInfo> {
 }
 
 fn angle_from_x(origin: Vec3, spline: &CubicBezierSpline) -> f32 {
+    let length = spline.get_length(LENGTH_ACCURACY).min(2.0) * 0.5;
     let param = spline
-        .get_param_at_length(1.0, LENGTH_ACCURACY)
+        .get_param_at_length(length, LENGTH_ACCURACY)
         .expect("should be valid");
     let point = spline.get_point_at_param(param);
     let vector = point - origin;
@@ -0,0 +1,596 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_beziers::constants::*;
+use konstruo_beziers::{ControlType, CubicBezierSpline};
+use konstruo_core::constants::PATH_ELEVATION;
+use konstruo_distribution::{Distributable, Distribution};
+use konstruo_geometry::{Polygon, TriangleList};
+use std::collections::HashMap;
+use ControlType::{End, Start};
+use PathSurfacePosition::Centered;
+use PathSurfaceType::Carriageway;
+
+/// Distance within which the end of a path is considered to meet another path.
+const JUNCTION_TOLERANCE: f32 = 0.500;
+
+/// Maximum number of times an edited path can be split where it crosses other paths.
+const MAX_SPLITS: usize = 32;
+
+/// A section of a [`Path`] while detecting junctions.
+struct Segment {
+    /// The existing [`Path`] or `None` if the segment has been split from another.
+    entity: Option<Entity>,
+    /// The [`Path`] the segment originates from.
+    source: Entity,
+    /// The spline of the segment.
+    spline: CubicBezierSpline,
+    /// Has the spline been changed?
+    is_changed: bool,
+}
+
+impl PathIntersection {
+    /// Get the point where the paths meet.
+    #[must_use]
+    pub fn get_origin(&self) -> Option<Vec3> {
+        Some(self.paths.first()?.spline.get_start())
+    }
+
+    /// System to detect junctions when a [`Path`] is edited.
+    ///
+    /// A junction is formed where the edited path:
+    /// - ends on the start or end of another path
+    /// - ends part way along another path, which is split
+    /// - crosses another path, in which case both are split
+    ///
+    /// Split paths keep their [`PathSurface`] and each [`Distribution`] is copied to the new
+    /// path with its spline and bounds matching the new length.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn on_path_edited(
+        mut events: MessageReader<PathEdited>,
+        commands: Commands,
+        meshes: ResMut<Assets<Mesh>>,
+        path_meshes: Res<PathMeshes>,
+        materials: Res<PathMaterials>,
+        mut paths: Query<(Entity, &mut Path)>,
+        surfaces: Query<(&PathSurface, &ChildOf)>,
+        distributions: Query<(Entity, &Distribution, &ChildOf), Without<Distributable>>,
+        junctions: Query<(Entity, &PathIntersection)>,
+        mut curve_added: MessageWriter<CurveAdded>,
+    ) {
+        let mut factory = PathFactory {
+            commands,
+            meshes,
+            path_meshes,
+            materials,
+        };
+        let surfaces = get_surfaces_by_path(&surfaces);
+        let distributions = get_distributions_by_path(&distributions);
+        for event in events.read() {
+            let mut segments: Vec<Segment> = paths
+                .iter()
+                .map(|(entity, path)| Segment {
+                    entity: Some(entity),
+                    source: entity,
+                    spline: path.spline.clone(),
+                    is_changed: false,
+                })
+                .collect();
+            let Some(edited) = segments
+                .iter()
+                .position(|segment| segment.entity == Some(event.path))
+            else {
+                warn!("Failed to get edited Path: {:?}", event.path);
+                continue;
+            };
+            let mut points = detect_ends(&mut segments, edited);
+            points.append(&mut detect_crossings(&mut segments, edited));
+            if points.is_empty() {
+                continue;
+            }
+            let entities: Vec<Entity> = segments
+                .iter()
+                .map(|segment| {
+                    apply_segment(
+                        &mut factory,
+                        &mut paths,
+                        &surfaces,
+                        &distributions,
+                        &mut curve_added,
+                        segment,
+                    )
+                })
+                .collect();
+            for point in points {
+                for (entity, junction) in junctions.iter() {
+                    let is_same = junction
+                        .get_origin()
+                        .is_some_and(|origin| origin.distance(point) < JUNCTION_TOLERANCE);
+                    if is_same {
+                        factory.commands.entity(entity).despawn();
+                    }
+                }
+                let mut builder = PathIntersectionBuilder::default();
+                for (segment, entity) in segments.iter().zip(&entities) {
+                    let start = segment.spline.get_start();
+                    let end = segment.spline.get_end();
+                    if start.distance(point) < JUNCTION_TOLERANCE
+                        || end.distance(point) < JUNCTION_TOLERANCE
+                    {
+                        let info = get_carriageway(&surfaces, segment.source);
+                        builder.add(*entity, segment.spline.clone(), info);
+                    }
+                }
+                match builder.build() {
+                    Ok(intersection) => {
+                        factory.spawn_junction(intersection);
+                    }
+                    Err(e) => warn!("Failed to build junction: {e:?}"),
+                }
+            }
+        }
+    }
+
+    /// System to update the junction geometry when the spline of one of its [`Path`] changes.
+    ///
+    /// If the paths no longer meet then the junction is removed.
+    pub(super) fn on_spline_changed(
+        mut events: MessageReader<SplineChanged>,
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut junctions: Query<(Entity, &mut PathIntersection, &mut Mesh3d)>,
+        paths: Query<&Path>,
+    ) {
+        for event in events.read() {
+            for (entity, mut junction, mut mesh) in &mut junctions {
+                if !junction.paths.iter().any(|info| info.entity == event.path) {
+                    continue;
+                }
+                let mut builder =
+                    PathIntersectionBuilder::default().with_kerb_radius(junction.kerb_radius);
+                for info in &junction.paths {
+                    let spline = if info.entity == event.path {
+                        event.spline.clone()
+                    } else if let Ok(path) = paths.get(info.entity) {
+                        path.spline.clone()
+                    } else {
+                        continue;
+                    };
+                    builder.add(info.entity, spline, info.info.clone());
+                }
+                let Some(triangles) = builder.build().ok().and_then(|intersection| {
+                    let triangles = intersection.to_triangle_list()?;
+                    Some((intersection, triangles))
+                }) else {
+                    trace!("Removing junction as the paths no longer meet");
+                    commands.entity(entity).despawn();
+                    continue;
+                };
+                let (intersection, triangles) = triangles;
+                *junction = intersection;
+                *mesh = Mesh3d(meshes.add(triangles.to_mesh()));
+            }
+        }
+    }
+
+    /// Create a [`TriangleList`] to fill the junction.
+    ///
+    /// The polygon is triangulated rather than fanned from the origin so concave corners
+    /// between acute paths are filled correctly.
+    fn to_triangle_list(&self) -> Option<TriangleList> {
+        let spline = self
+            .get_centered_polygon()
+            .map_err(|e| warn!("Failed to get junction polygon: {e:?}"))
+            .ok()?;
+        let polygon = Polygon::from_open(spline.flatten(FLATTEN_TOLERANCE))?;
+        Some(polygon.triangulate())
+    }
+}
+
+impl PathFactory<'_> {
+    /// Spawn a [`PathIntersection`] with a filled junction surface.
+    #[allow(clippy::must_use_candidate)]
+    pub fn spawn_junction(&mut self, intersection: PathIntersection) -> Option<Entity> {
+        let triangles = intersection.to_triangle_list()?;
+        let depth = intersection
+            .paths
+            .iter()
+            .map(|info| info.info.depth)
+            .fold(0.0, f32::max);
+        let bundle = (
+            Name::new("Path Junction"),
+            intersection,
+            Mesh3d(self.meshes.add(triangles.to_mesh())),
+            MeshMaterial3d(self.materials.carriageway.clone()),
+            Transform::from_translation(Vec3::new(0.0, 0.0, PATH_ELEVATION + depth)),
+        );
+        Some(self.commands.spawn(bundle).id())
+    }
+}
+
+/// Find where the start or end of the edited segment meets another segment.
+///
+/// If the end meets part way along another segment then it is split.
+fn detect_ends(segments: &mut Vec<Segment>, edited: usize) -> Vec<Vec3> {
+    let mut points = Vec::new();
+    for control in [Start, End] {
+        let Some(segment) = segments.get(edited) else {
+            continue;
+        };
+        let end = get_end_point(&segment.spline, control);
+        for other in 0..segments.len() {
+            if other == edited {
+                continue;
+            }
+            let Some(point) = get_meeting_point(segments, other, end) else {
+                continue;
+            };
+            if let Some(segment) = segments.get_mut(edited) {
+                set_end_point(&mut segment.spline, control, point);
+                segment.is_changed = true;
+            }
+            points.push(point);
+            break;
+        }
+    }
+    points
+}
+
+/// Get the point where `end` meets another segment.
+///
+/// If it meets part way along then the segment is split.
+fn get_meeting_point(segments: &mut Vec<Segment>, other: usize, end: Vec3) -> Option<Vec3> {
+    let spline = &segments.get(other)?.spline;
+    for point in [spline.get_start(), spline.get_end()] {
+        if end.distance(point) < JUNCTION_TOLERANCE {
+            return Some(point);
+        }
+    }
+    let param = spline.get_param_nearest_to(end, INTERSECTION_ACCURACY);
+    if param <= 0.0 || param >= 1.0 {
+        return None;
+    }
+    let point = spline.get_point_at_param(param);
+    if end.distance(point) >= JUNCTION_TOLERANCE {
+        return None;
+    }
+    split_segment(segments, other, param, point)?;
+    Some(point)
+}
+
+/// Find where the edited segment crosses other segments and split both at the crossing.
+fn detect_crossings(segments: &mut Vec<Segment>, edited: usize) -> Vec<Vec3> {
+    let Some(source) = segments.get(edited).map(|segment| segment.source) else {
+        return Vec::new();
+    };
+    let mut points = Vec::new();
+    let mut pending = vec![edited];
+    while let Some(piece) = pending.pop() {
+        if points.len() >= MAX_SPLITS {
+            warn!("Exceeded the maximum number of junctions for a single path");
+            break;
+        }
+        let Some((other, point)) = find_crossing(segments, piece, source) else {
+            continue;
+        };
+        let Some(piece_spline) = segments.get(piece).map(|segment| &segment.spline) else {
+            continue;
+        };
+        let piece_param = piece_spline.get_param_nearest_to(point, INTERSECTION_ACCURACY);
+        let Some(other_spline) = segments.get(other).map(|segment| &segment.spline) else {
+            continue;
+        };
+        let other_param = other_spline.get_param_nearest_to(point, INTERSECTION_ACCURACY);
+        if split_segment(segments, other, other_param, point).is_none() {
+            continue;
+        }
+        let Some(new_piece) = split_segment(segments, piece, piece_param, point) else {
+            continue;
+        };
+        pending.push(piece);
+        pending.push(new_piece);
+        points.push(point);
+    }
+    points
+}
+
+/// Find the first point where a piece of the edited path crosses another segment.
+///
+/// Crossings at the ends of either segment are ignored as they are handled by [`detect_ends`].
+fn find_crossing(segments: &[Segment], piece: usize, source: Entity) -> Option<(usize, Vec3)> {
+    let spline = &segments.get(piece)?.spline;
+    for (other, segment) in segments.iter().enumerate() {
+        if segment.source == source {
+            continue;
+        }
+        let Some(intersections) =
+            spline.get_intersections_with_spline(&segment.spline, INTERSECTION_TOLERANCE)
+        else {
+            continue;
+        };
+        let ends = [
+            spline.get_start(),
+            spline.get_end(),
+            segment.spline.get_start(),
+            segment.spline.get_end(),
+        ];
+        let crossing = intersections.into_iter().find(|point| {
+            ends.iter()
+                .all(|end| end.distance(*point) >= JUNCTION_TOLERANCE)
+        });
+        if let Some(point) = crossing {
+            return Some((other, point));
+        }
+    }
+    None
+}
+
+/// Split a segment at param.
+///
+/// The new segment is appended and its index returned.
+fn split_segment(
+    segments: &mut Vec<Segment>,
+    index: usize,
+    param: f32,
+    point: Vec3,
+) -> Option<usize> {
+    let segment = segments.get_mut(index)?;
+    let [mut left, mut right] = segment
+        .spline
+        .clone()
+        .split_at_param(param)
+        .map_err(|e| warn!("Failed to split path at junction: {e:?}"))
+        .ok()?;
+    set_end_point(&mut left, End, point);
+    set_end_point(&mut right, Start, point);
+    segment.spline = left;
+    segment.is_changed = true;
+    let source = segment.source;
+    segments.push(Segment {
+        entity: None,
+        source,
+        spline: right,
+        is_changed: true,
+    });
+    Some(segments.len() - 1)
+}
+
+/// Update an existing [`Path`] or spawn a new one for a [`Segment`].
+///
+/// An existing [`Path`] is updated through [`CurveAdded`] so its [`Distribution`] are
+/// shortened by [`Path::on_spline_changed`].
+fn apply_segment(
+    factory: &mut PathFactory<'_>,
+    paths: &mut Query<(Entity, &mut Path)>,
+    surfaces: &HashMap<Entity, Vec<PathSurfaceInfo>>,
+    distributions: &HashMap<Entity, Vec<(Entity, Distribution)>>,
+    curve_added: &mut MessageWriter<CurveAdded>,
+    segment: &Segment,
+) -> Entity {
+    if let Some(entity) = segment.entity {
+        if segment.is_changed {
+            if let Ok((_, mut path)) = paths.get_mut(entity) {
+                path.spline = segment.spline.clone();
+            }
+            curve_added.write(CurveAdded {
+                path: entity,
+                spline: segment.spline.clone(),
+            });
+        }
+        return entity;
+    }
+    let path = Path::new(segment.spline.clone());
+    let entity = factory.spawn_path(path.clone());
+    for info in surfaces.get(&segment.source).into_iter().flatten() {
+        factory.spawn_surface(PathSurface::from_info(info.clone()), &path, entity);
+    }
+    for (source, distribution) in distributions.get(&segment.source).into_iter().flatten() {
+        spawn_distribution(factory, *source, distribution, &path, entity);
+    }
+    entity
+}
+
+/// Spawn a copy of a [`Distribution`] without its children along a new [`Path`].
+///
+/// Other components such as the templates to fill it with are cloned from the source.
+fn spawn_distribution(
+    factory: &mut PathFactory<'_>,
+    source: Entity,
+    distribution: &Distribution,
+    path: &Path,
+    path_entity: Entity,
+) {
+    let Ok(spline) = distribution
+        .get_spline_from_path(&path.spline)
+        .map_err(|e| warn!("Failed to offset Distribution spline: {e}"))
+    else {
+        return;
+    };
+    let length = spline.get_length(LENGTH_ACCURACY);
+    let mut distribution = distribution.clone();
+    distribution.flex.bounds = distribution.flex.bounds.map(|bounds| bounds.with_x(length));
+    distribution.spline = Some(spline);
+    factory
+        .commands
+        .entity(source)
+        .clone_and_spawn_with_opt_out(|builder| {
+            builder.deny::<(Children, ChildOf)>();
+        })
+        .insert((distribution, ChildOf(path_entity)));
+}
+
+/// Get the [`PathSurfaceInfo`] of every [`PathSurface`] by [`Path`].
+fn get_surfaces_by_path(
+    surfaces: &Query<(&PathSurface, &ChildOf)>,
+) -> HashMap<Entity, Vec<PathSurfaceInfo>> {
+    let mut map: HashMap<Entity, Vec<PathSurfaceInfo>> = HashMap::new();
+    for (surface, child_of) in surfaces.iter() {
+        map.entry(child_of.parent())
+            .or_default()
+            .push(surface.get_info().clone());
+    }
+    map
+}
+
+/// Get the [`Distribution`] of every [`Path`].
+fn get_distributions_by_path(
+    distributions: &Query<(Entity, &Distribution, &ChildOf), Without<Distributable>>,
+) -> HashMap<Entity, Vec<(Entity, Distribution)>> {
+    let mut map: HashMap<Entity, Vec<(Entity, Distribution)>> = HashMap::new();
+    for (entity, distribution, child_of) in distributions.iter() {
+        map.entry(child_of.parent())
+            .or_default()
+            .push((entity, distribution.clone()));
+    }
+    map
+}
+
+/// Get the centered carriageway of a [`Path`] or a default if it doesn't have one.
+fn get_carriageway(
+    surfaces: &HashMap<Entity, Vec<PathSurfaceInfo>>,
+    path: Entity,
+) -> PathSurfaceInfo {
+    surfaces
+        .get(&path)
+        .and_then(|surfaces| {
+            surfaces
+                .iter()
+                .find(|info| info.position == Centered)
+                .cloned()
+        })
+        .unwrap_or_else(|| PathSurfaceInfo::from_purpose(Carriageway))
+}
+
+fn get_end_point(spline: &CubicBezierSpline, control: ControlType) -> Vec3 {
+    match control {
+        Start => spline.get_start(),
+        _ => spline.get_end(),
+    }
+}
+
+fn set_end_point(spline: &mut CubicBezierSpline, control: ControlType, point: Vec3) {
+    if control == Start {
+        spline.update_control(Start, 0, point);
+    } else {
+        let last = spline.get_curves().len() - 1;
+        spline.update_control(End, last, point);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konstruo_beziers::CubicBezier;
+    use konstruo_geometry::Line;
+
+    fn line(start: Vec3, end: Vec3) -> CubicBezierSpline {
+        let curve = CubicBezier::from_line(Line::new(start, end)).expect("should be valid");
+        CubicBezierSpline::new(vec![curve]).expect("should be valid")
+    }
+
+    fn segments(world: &mut World, splines: Vec<CubicBezierSpline>) -> Vec<Segment> {
+        splines
+            .into_iter()
+            .map(|spline| {
+                let entity = world.spawn_empty().id();
+                Segment {
+                    entity: Some(entity),
+                    source: entity,
+                    spline,
+                    is_changed: false,
+                }
+            })
+            .collect()
+    }
+
+    /// Build the junction of the segments that meet at point and fill it.
+    fn fill(segments: &[Segment], point: Vec3) -> TriangleList {
+        let mut builder = PathIntersectionBuilder::default();
+        for segment in segments {
+            let start = segment.spline.get_start();
+            let end = segment.spline.get_end();
+            if start.distance(point) < JUNCTION_TOLERANCE
+                || end.distance(point) < JUNCTION_TOLERANCE
+            {
+                let info = PathSurfaceInfo::from_purpose(Carriageway);
+                builder.add(segment.source, segment.spline.clone(), info);
+            }
+        }
+        let intersection = builder.build().expect("should build");
+        assert_eq!(intersection.paths.len(), segments.len());
+        intersection.to_triangle_list().expect("should fill")
+    }
+
+    #[test]
+    fn t_junction() {
+        // Arrange
+        let mut world = World::new();
+        let point = Vec3::new(50.0, 0.0, 0.0);
+        let mut segments = segments(
+            &mut world,
+            vec![
+                line(Vec3::ZERO, Vec3::new(100.0, 0.0, 0.0)),
+                line(Vec3::new(50.0, 50.0, 0.0), Vec3::new(50.0, 0.2, 0.0)),
+            ],
+        );
+
+        // Act
+        let points = detect_ends(&mut segments, 1);
+
+        // Assert
+        assert_eq!(points.len(), 1);
+        let actual = *points.first().expect("should be a point");
+        assert!(actual.distance(point) < 0.01);
+        assert_eq!(segments.len(), 3);
+        let [left, edited, right] = segments.as_slice() else {
+            unreachable!()
+        };
+        assert_eq!(left.spline.get_start(), Vec3::ZERO);
+        assert_eq!(left.spline.get_end(), actual);
+        assert_eq!(edited.spline.get_end(), actual);
+        assert_eq!(right.spline.get_start(), actual);
+        assert_eq!(right.spline.get_end(), Vec3::new(100.0, 0.0, 0.0));
+        assert_eq!(right.source, left.source);
+        assert!(right.entity.is_none());
+        assert!(left.is_changed && edited.is_changed && right.is_changed);
+        assert!(!fill(&segments, actual).get_triangles().is_empty());
+    }
+
+    #[test]
+    fn x_crossing() {
+        // Arrange
+        let mut world = World::new();
+        let point = Vec3::new(50.0, 0.0, 0.0);
+        let mut segments = segments(
+            &mut world,
+            vec![
+                line(Vec3::ZERO, Vec3::new(100.0, 0.0, 0.0)),
+                line(Vec3::new(50.0, -50.0, 0.0), Vec3::new(50.0, 50.0, 0.0)),
+            ],
+        );
+
+        // Act
+        let mut points = detect_ends(&mut segments, 1);
+        points.append(&mut detect_crossings(&mut segments, 1));
+
+        // Assert
+        assert_eq!(points.len(), 1);
+        let actual = *points.first().expect("should be a point");
+        assert!(actual.distance(point) < 0.01);
+        assert_eq!(segments.len(), 4);
+        for segment in &segments {
+            let start = segment.spline.get_start();
+            let end = segment.spline.get_end();
+            assert!(start == actual || end == actual);
+        }
+        let sources: Vec<Entity> = segments.iter().map(|segment| segment.source).collect();
+        let first = segments.first().map(|segment| segment.source);
+        assert_eq!(
+            sources
+                .iter()
+                .filter(|source| Some(**source) == first)
+                .count(),
+            2
+        );
+        assert!(!fill(&segments, actual).get_triangles().is_empty());
+    }
+}
@@ -6,8 +6,10 @@ pub use intersection::*;
 pub use intersection_builder::*;
 pub use materials::*;
 pub use meshes::*;
+pub use network::*;
 pub use path::*;
 pub use plugin::*;
+pub use profile::*;
 pub use surface::*;
 
 mod control;
@@ -16,8 +18,11 @@ mod events;
 mod factory;
 mod intersection;
 mod intersection_builder;
+mod junction;
 mod materials;
 mod meshes;
+mod network;
 mod path;
 mod plugin;
+mod profile;
 mod surface;
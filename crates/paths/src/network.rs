@@ -0,0 +1,467 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_beziers::constants::LENGTH_ACCURACY;
+use konstruo_beziers::{CubicBezierSpline, CONNECTION_TOLERANCE};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
+
+/// Identifier of a node in the [`RoadNetwork`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RoadNodeId(usize);
+
+/// An edge of the [`RoadNetwork`] along the spline of a [`Path`].
+#[derive(Clone, Copy, Debug)]
+pub struct RoadEdge {
+    /// Node at the start of the spline.
+    pub start: RoadNodeId,
+    /// Node at the end of the spline.
+    pub end: RoadNodeId,
+    /// Length of the spline.
+    pub length: f32,
+}
+
+/// The shortest route between two nodes of the [`RoadNetwork`].
+#[derive(Clone, Debug, Default)]
+pub struct RoadRoute {
+    /// Nodes in order from the start to the end of the route.
+    pub nodes: Vec<RoadNodeId>,
+    /// [`Path`] in order from the start to the end of the route.
+    pub paths: Vec<Entity>,
+    /// Total length of the route.
+    pub length: f32,
+}
+
+/// A graph of every [`Path`].
+///
+/// Nodes are located at the ends of paths. Where the ends of paths meet they share a node
+/// so a junction is a node with a degree greater than two and a dead end is a node with a
+/// degree of one.
+///
+/// Edges are the spline of each [`Path`].
+#[derive(Debug, Default, Resource)]
+pub struct RoadNetwork {
+    nodes: BTreeMap<RoadNodeId, Vec3>,
+    edges: HashMap<Entity, RoadEdge>,
+    next_node: usize,
+}
+
+/// Entry of the priority queue when finding the shortest route.
+#[derive(PartialEq)]
+struct RouteCandidate {
+    length: f32,
+    node: RoadNodeId,
+}
+
+impl RoadNetwork {
+    /// Add or update the edge of a [`Path`].
+    ///
+    /// An existing edge is updated in place so its nodes keep their [`RoadNodeId`] unless an
+    /// end now meets a different node.
+    pub fn set_edge(&mut self, path: Entity, spline: &CubicBezierSpline) {
+        let previous = self.edges.get(&path).copied();
+        let start = self.get_or_move_node(previous.map(|edge| edge.start), spline.get_start());
+        let end = self.get_or_move_node(previous.map(|edge| edge.end), spline.get_end());
+        let length = spline.get_length(LENGTH_ACCURACY);
+        self.edges.insert(path, RoadEdge { start, end, length });
+        for node in previous.into_iter().flat_map(|edge| [edge.start, edge.end]) {
+            if self.get_degree(node) == 0 {
+                self.nodes.remove(&node);
+            }
+        }
+    }
+
+    /// Remove the edge of a [`Path`] and any nodes that are no longer used.
+    pub fn remove_edge(&mut self, path: Entity) {
+        let Some(edge) = self.edges.remove(&path) else {
+            return;
+        };
+        for node in [edge.start, edge.end] {
+            if self.get_degree(node) == 0 {
+                self.nodes.remove(&node);
+            }
+        }
+    }
+
+    /// Get the edge of a [`Path`].
+    #[must_use]
+    pub fn get_edge(&self, path: Entity) -> Option<&RoadEdge> {
+        self.edges.get(&path)
+    }
+
+    /// Get the position of a node.
+    #[must_use]
+    pub fn get_position(&self, node: RoadNodeId) -> Option<Vec3> {
+        self.nodes.get(&node).copied()
+    }
+
+    /// Get the node at a point.
+    #[must_use]
+    pub fn get_node_at(&self, point: Vec3) -> Option<RoadNodeId> {
+        self.nodes
+            .iter()
+            .find(|(_, position)| position.abs_diff_eq(point, CONNECTION_TOLERANCE))
+            .map(|(node, _)| *node)
+    }
+
+    /// Get every node.
+    pub fn get_nodes(&self) -> impl Iterator<Item = RoadNodeId> + '_ {
+        self.nodes.keys().copied()
+    }
+
+    /// Get the [`Path`] connected to a node and the node at their opposite end.
+    #[must_use]
+    pub fn get_neighbours(&self, node: RoadNodeId) -> Vec<(Entity, RoadNodeId)> {
+        let mut neighbours = Vec::new();
+        for (path, edge) in &self.edges {
+            if edge.start == node {
+                neighbours.push((*path, edge.end));
+            }
+            if edge.end == node {
+                neighbours.push((*path, edge.start));
+            }
+        }
+        neighbours
+    }
+
+    /// Get the number of path ends at a node.
+    ///
+    /// A path that starts and ends at the same node counts twice.
+    #[must_use]
+    pub fn get_degree(&self, node: RoadNodeId) -> usize {
+        self.edges
+            .values()
+            .map(|edge| usize::from(edge.start == node) + usize::from(edge.end == node))
+            .sum()
+    }
+
+    /// Is the node only connected to a single path end?
+    #[must_use]
+    pub fn is_dead_end(&self, node: RoadNodeId) -> bool {
+        self.get_degree(node) == 1
+    }
+
+    /// Is the node a junction of more than two path ends?
+    #[must_use]
+    pub fn is_junction(&self, node: RoadNodeId) -> bool {
+        self.get_degree(node) > 2
+    }
+
+    /// Get every dead end.
+    #[must_use]
+    pub fn get_dead_ends(&self) -> Vec<RoadNodeId> {
+        self.get_nodes()
+            .filter(|node| self.is_dead_end(*node))
+            .collect()
+    }
+
+    /// Is there a route between the nodes?
+    #[must_use]
+    pub fn is_connected(&self, from: RoadNodeId, to: RoadNodeId) -> bool {
+        if !self.nodes.contains_key(&from) || !self.nodes.contains_key(&to) {
+            return false;
+        }
+        let mut visited = HashSet::from([from]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                return true;
+            }
+            for (_, neighbour) in self.get_neighbours(node) {
+                if visited.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        false
+    }
+
+    /// Is there a route between the paths?
+    #[must_use]
+    pub fn are_paths_connected(&self, a: Entity, b: Entity) -> bool {
+        let (Some(a), Some(b)) = (self.edges.get(&a), self.edges.get(&b)) else {
+            return false;
+        };
+        self.is_connected(a.start, b.start)
+    }
+
+    /// Get the shortest route between the nodes by length.
+    ///
+    /// Returns `None` if the nodes are not connected.
+    #[must_use]
+    pub fn get_shortest_route(&self, from: RoadNodeId, to: RoadNodeId) -> Option<RoadRoute> {
+        if !self.nodes.contains_key(&from) || !self.nodes.contains_key(&to) {
+            return None;
+        }
+        let mut lengths: HashMap<RoadNodeId, f32> = HashMap::from([(from, 0.0)]);
+        let mut previous: HashMap<RoadNodeId, (RoadNodeId, Entity)> = HashMap::new();
+        let mut queue = BinaryHeap::from([RouteCandidate {
+            length: 0.0,
+            node: from,
+        }]);
+        while let Some(RouteCandidate { length, node }) = queue.pop() {
+            if node == to {
+                break;
+            }
+            if lengths
+                .get(&node)
+                .is_some_and(|shortest| length > *shortest)
+            {
+                continue;
+            }
+            for (path, neighbour) in self.get_neighbours(node) {
+                let edge = self.edges.get(&path).expect("edge should exist");
+                let candidate = length + edge.length;
+                let is_shorter = lengths
+                    .get(&neighbour)
+                    .is_none_or(|shortest| candidate < *shortest);
+                if is_shorter {
+                    lengths.insert(neighbour, candidate);
+                    previous.insert(neighbour, (node, path));
+                    queue.push(RouteCandidate {
+                        length: candidate,
+                        node: neighbour,
+                    });
+                }
+            }
+        }
+        let length = *lengths.get(&to)?;
+        let mut route = RoadRoute {
+            nodes: vec![to],
+            paths: Vec::new(),
+            length,
+        };
+        let mut node = to;
+        while let Some((prior, path)) = previous.get(&node) {
+            route.nodes.push(*prior);
+            route.paths.push(*path);
+            node = *prior;
+        }
+        route.nodes.reverse();
+        route.paths.reverse();
+        Some(route)
+    }
+
+    /// Get the node for an end of an existing edge that may have moved to point.
+    ///
+    /// The previous node is reused if it is still at point, or moved if only this end used
+    /// it and it doesn't now meet another node.
+    fn get_or_move_node(&mut self, previous: Option<RoadNodeId>, point: Vec3) -> RoadNodeId {
+        if let Some(node) = previous {
+            let is_same = self
+                .get_position(node)
+                .is_some_and(|position| position.abs_diff_eq(point, CONNECTION_TOLERANCE));
+            if is_same {
+                return node;
+            }
+            if self.get_degree(node) == 1 && self.get_node_at(point).is_none() {
+                self.nodes.insert(node, point);
+                return node;
+            }
+        }
+        self.get_or_add_node(point)
+    }
+
+    fn get_or_add_node(&mut self, point: Vec3) -> RoadNodeId {
+        if let Some(node) = self.get_node_at(point) {
+            return node;
+        }
+        let node = RoadNodeId(self.next_node);
+        self.next_node += 1;
+        self.nodes.insert(node, point);
+        node
+    }
+
+    /// System to add the edge of each new [`Path`] and remove the edge of despawned [`Path`].
+    pub(super) fn on_path_added(
+        mut network: ResMut<RoadNetwork>,
+        paths: Query<(Entity, &Path), Added<Path>>,
+        mut removed: RemovedComponents<Path>,
+    ) {
+        for entity in removed.read() {
+            network.remove_edge(entity);
+        }
+        for (entity, path) in paths.iter() {
+            network.set_edge(entity, &path.spline);
+        }
+    }
+
+    /// System to update the edge of a [`Path`] on [`SplineChanged`].
+    ///
+    /// [`SplineChanged`] is written on [`CurveAdded`] and [`ControlMoved`].
+    pub(super) fn on_spline_changed(
+        mut events: MessageReader<SplineChanged>,
+        mut network: ResMut<RoadNetwork>,
+    ) {
+        for event in events.read() {
+            network.set_edge(event.path, &event.spline);
+        }
+    }
+}
+
+impl Eq for RouteCandidate {}
+
+impl Ord for RouteCandidate {
+    /// Reversed so [`BinaryHeap`] is a min-heap by length.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .length
+            .total_cmp(&self.length)
+            .then_with(|| self.node.cmp(&other.node))
+    }
+}
+
+impl PartialOrd for RouteCandidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konstruo_beziers::CubicBezier;
+    use konstruo_geometry::Line;
+
+    fn line(start: Vec3, end: Vec3) -> CubicBezierSpline {
+        let curve = CubicBezier::from_line(Line::new(start, end)).expect("should be valid");
+        CubicBezierSpline::new(vec![curve]).expect("should be valid")
+    }
+
+    /// A square of four paths with a diagonal and a disconnected path.
+    fn example() -> (RoadNetwork, Vec<Entity>) {
+        let mut world = World::new();
+        let entities: Vec<Entity> = (0..6).map(|_| world.spawn_empty().id()).collect();
+        let a = Vec3::ZERO;
+        let b = Vec3::new(100.0, 0.0, 0.0);
+        let c = Vec3::new(100.0, 100.0, 0.0);
+        let d = Vec3::new(0.0, 100.0, 0.0);
+        let splines = [
+            line(a, b),
+            line(b, c),
+            line(d, c),
+            line(a, d),
+            line(a, c),
+            line(Vec3::new(200.0, 0.0, 0.0), Vec3::new(300.0, 0.0, 0.0)),
+        ];
+        let mut network = RoadNetwork::default();
+        for (entity, spline) in entities.iter().zip(&splines) {
+            network.set_edge(*entity, spline);
+        }
+        (network, entities)
+    }
+
+    #[test]
+    fn degree_and_dead_ends() {
+        // Arrange
+        let (network, _) = example();
+        let a = network.get_node_at(Vec3::ZERO).expect("should exist");
+        let b = network
+            .get_node_at(Vec3::new(100.0, 0.0, 0.0))
+            .expect("should exist");
+
+        // Act
+        let dead_ends = network.get_dead_ends();
+
+        // Assert
+        assert_eq!(network.get_nodes().count(), 6);
+        assert_eq!(network.get_degree(a), 3);
+        assert_eq!(network.get_degree(b), 2);
+        assert!(network.is_junction(a));
+        assert_eq!(dead_ends.len(), 2);
+    }
+
+    #[test]
+    fn connectivity() {
+        // Arrange
+        let (network, entities) = example();
+        let [a, b, _, _, _, f] = entities.as_slice() else {
+            unreachable!()
+        };
+
+        // Act
+        let connected = network.are_paths_connected(*a, *b);
+        let disconnected = network.are_paths_connected(*a, *f);
+
+        // Assert
+        assert!(connected);
+        assert!(!disconnected);
+    }
+
+    #[test]
+    fn shortest_route() {
+        // Arrange
+        let (network, entities) = example();
+        let from = network.get_node_at(Vec3::ZERO).expect("should exist");
+        let to = network
+            .get_node_at(Vec3::new(100.0, 100.0, 0.0))
+            .expect("should exist");
+        let far = network
+            .get_node_at(Vec3::new(300.0, 0.0, 0.0))
+            .expect("should exist");
+
+        // Act
+        let route = network
+            .get_shortest_route(from, to)
+            .expect("should be connected");
+        let unreachable = network.get_shortest_route(from, far);
+
+        // Assert
+        assert_eq!(
+            route.paths.as_slice(),
+            entities.get(4..5).expect("should exist")
+        );
+        assert_eq!(route.nodes, vec![from, to]);
+        assert!((route.length - 100.0 * 2.0_f32.sqrt()).abs() < 0.01);
+        assert!(unreachable.is_none());
+    }
+
+    #[test]
+    fn remove_edge() {
+        // Arrange
+        let (mut network, entities) = example();
+
+        // Act
+        network.remove_edge(*entities.get(5).expect("should exist"));
+
+        // Assert
+        assert_eq!(network.get_nodes().count(), 4);
+        assert!(network.get_dead_ends().is_empty());
+    }
+
+    #[test]
+    fn set_edge_in_place() {
+        // Arrange
+        let (mut network, entities) = example();
+        let a = network.get_node_at(Vec3::ZERO).expect("should exist");
+        let e = network
+            .get_node_at(Vec3::new(300.0, 0.0, 0.0))
+            .expect("should exist");
+        let f = *entities.get(5).expect("should exist");
+
+        // Act
+        network.set_edge(
+            f,
+            &line(Vec3::new(200.0, 0.0, 0.0), Vec3::new(300.0, 50.0, 0.0)),
+        );
+        let moved = *network.get_edge(f).expect("should exist");
+        network.set_edge(
+            *entities.first().expect("should exist"),
+            &line(Vec3::ZERO, Vec3::new(100.0, 0.0, 0.0)),
+        );
+        network.set_edge(f, &line(Vec3::new(200.0, 0.0, 0.0), Vec3::ZERO));
+        let joined = *network.get_edge(f).expect("should exist");
+
+        // Assert
+        assert_eq!(moved.end, e);
+        assert_eq!(
+            network.get_node_at(Vec3::ZERO),
+            Some(a),
+            "unmoved node should keep its id"
+        );
+        assert_eq!(joined.start, moved.start);
+        assert_eq!(joined.end, a);
+        assert!(network.get_position(e).is_none());
+        assert_eq!(network.get_nodes().count(), 5);
+        assert_eq!(network.get_degree(a), 4);
+    }
+}
@@ -4,7 +4,7 @@ use konstruo_beziers::constants::*;
 use konstruo_beziers::CubicBezierSpline;
 use konstruo_distribution::{Distributable, Distribution, FlexFactory};
 use konstruo_geometry::Polyline;
-use konstruo_ui::EntityState;
+use konstruo_ui::{EntityState, SnapTargets};
 
 /// A road, route or path defined by one or more cubic bezier curves.
 ///
@@ -31,6 +31,18 @@ impl Path {
         Self { spline }
     }
 
+    /// Get the [`SnapTargets`] of the start and end of each [`Path`] and their splines.
+    #[must_use]
+    pub fn get_snap_targets<'a>(paths: impl IntoIterator<Item = &'a Path>) -> SnapTargets {
+        let mut targets = SnapTargets::default();
+        for path in paths {
+            targets.add_point(path.spline.get_start());
+            targets.add_point(path.spline.get_end());
+            targets.add_spline(path.spline.clone());
+        }
+        targets
+    }
+
     /// Update [`Mesh3d`] and [`Distribution`] when the spline changes.
     pub(super) fn on_spline_changed(
         mut events: MessageReader<SplineChanged>,
@@ -82,13 +94,11 @@ fn redistribute_on_spline_changed(
         if child_of.parent() != event.path {
             continue;
         }
-        let spline = if let Some(offset) = distribution.spline_offset {
-            event
-                .spline
-                .offset(offset, OFFSET_ACCURACY)
-                .expect("spline offset should be valid")
-        } else {
-            event.spline.clone()
+        let Ok(spline) = distribution
+            .get_spline_from_path(&event.spline)
+            .map_err(|e| warn!("Failed to offset Distribution spline: {e}"))
+        else {
+            continue;
         };
         let length = spline.get_length(LENGTH_ACCURACY);
         let flex = FlexFactory {
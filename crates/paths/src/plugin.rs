@@ -1,6 +1,7 @@
 use super::*;
 use bevy::app::{App, Startup, Update};
 use bevy::prelude::*;
+use konstruo_distribution::DistributionSystems;
 
 pub struct PathPlugin;
 
@@ -8,15 +9,26 @@ impl Plugin for PathPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<ControlMoved>()
             .add_message::<CurveAdded>()
+            .add_message::<PathEdited>()
             .add_message::<SplineChanged>()
+            .init_resource::<RoadNetwork>()
             .add_systems(Startup, PathMaterials::startup_system)
             .add_systems(Startup, PathMeshes::startup_system)
-            .add_systems(Update, Path::on_spline_changed)
+            .add_systems(
+                Update,
+                Path::on_spline_changed.in_set(DistributionSystems::Spline),
+            )
+            .add_systems(Update, PathIntersection::on_path_edited)
+            .add_systems(Update, PathIntersection::on_spline_changed)
             .add_systems(Update, PathControl::on_control_moved)
             .add_systems(Update, PathControl::on_curve_added)
             .add_systems(Update, PathControlLine::on_control_moved)
             .add_systems(Update, PathControlLine::on_curve_added)
+            .add_systems(Update, PathSurface::on_changed)
             .add_systems(Update, PathSurface::on_spline_changed)
+            .add_systems(Update, PathSurface::wireframe_system)
+            .add_systems(Update, RoadNetwork::on_path_added)
+            .add_systems(Update, RoadNetwork::on_spline_changed)
             .add_systems(Update, SplineChanged::on_control_moved)
             .add_systems(Update, SplineChanged::on_curve_added);
     }
@@ -0,0 +1,204 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_geometry::Profile;
+use serde::{Deserialize, Serialize};
+use PathSurfacePosition::*;
+use PathSurfaceProfile::*;
+
+/// Size of the chamfer at the top edge of a kerb.
+const KERB_CHAMFER: f32 = 0.020;
+
+/// Width of the ramp of a dropped kerb.
+const DROPPED_KERB_WIDTH: f32 = 0.300;
+
+/// Width of a channel.
+const CHANNEL_WIDTH: f32 = 0.300;
+
+/// Maximum proportion of the depth that a channel or dropped kerb can be lowered.
+const MAX_LOWERED: f32 = 0.8;
+
+/// Shape of the top of a [`PathSurface`] cross-section.
+#[derive(Copy, Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub enum PathSurfaceProfile {
+    /// A flat top.
+    #[default]
+    Flat,
+    /// A top that rises by value to a crown at the center so water drains to each side.
+    Camber(f32),
+    /// A top that falls by value to a channel at each side to collect water.
+    Channel(f32),
+    /// A kerb with a chamfered top edge on the side nearest the [`Path`].
+    ///
+    /// The upstand of the kerb is the difference in depth to the adjacent surface.
+    Kerb,
+    /// A kerb on the side nearest the [`Path`] that is lowered by value so it can be crossed.
+    DroppedKerb(f32),
+}
+
+impl PathSurfaceProfile {
+    /// Get the typical profile for the type of surface.
+    #[must_use]
+    pub fn from_purpose(purpose: PathSurfaceType) -> Self {
+        match purpose {
+            PathSurfaceType::Footway => Kerb,
+            _ => Flat,
+        }
+    }
+}
+
+impl PathSurfaceInfo {
+    /// Get the cross-section [`Profile`] to sweep along the [`Path`].
+    #[must_use]
+    pub fn get_profile(&self) -> Profile {
+        let offsets = self.get_vec6();
+        let (left, right, top) = (offsets.left, offsets.right, offsets.top);
+        let (is_left_near, is_right_near) = match self.position {
+            Centered => (true, true),
+            Offset(offset) => (offset >= 0.0, offset < 0.0),
+        };
+        let lowered = |value: f32| value.clamp(0.0, top * MAX_LOWERED);
+        let ramp = |value: f32| value.min((right - left) * 0.25);
+        let mut vertices = vec![Vec2::new(left, 0.0), Vec2::new(right, 0.0)];
+        match self.profile {
+            Flat => {
+                vertices.push(Vec2::new(right, top));
+                vertices.push(Vec2::new(left, top));
+            }
+            Camber(rise) => {
+                vertices.push(Vec2::new(right, top));
+                vertices.push(Vec2::new((left + right) * 0.5, top + rise.max(0.0)));
+                vertices.push(Vec2::new(left, top));
+            }
+            Channel(fall) => {
+                let width = ramp(CHANNEL_WIDTH);
+                vertices.push(Vec2::new(right, top - lowered(fall)));
+                vertices.push(Vec2::new(right - width, top));
+                vertices.push(Vec2::new(left + width, top));
+                vertices.push(Vec2::new(left, top - lowered(fall)));
+            }
+            Kerb => {
+                let chamfer = lowered(KERB_CHAMFER).min(ramp(KERB_CHAMFER));
+                push_edge(&mut vertices, right, -chamfer, top, chamfer, is_right_near);
+                push_edge(&mut vertices, left, chamfer, top, chamfer, is_left_near);
+            }
+            DroppedKerb(drop) => {
+                let width = ramp(DROPPED_KERB_WIDTH);
+                push_edge(
+                    &mut vertices,
+                    right,
+                    -width,
+                    top,
+                    lowered(drop),
+                    is_right_near,
+                );
+                push_edge(&mut vertices, left, width, top, lowered(drop), is_left_near);
+            }
+        }
+        Profile::new(vertices)
+    }
+}
+
+/// Push the top vertices of a side of the profile.
+///
+/// If the side is nearest the path then the top edge is lowered by `drop` over `width`.
+fn push_edge(vertices: &mut Vec<Vec2>, x: f32, width: f32, top: f32, drop: f32, is_near: bool) {
+    if !is_near || drop <= 0.0 {
+        vertices.push(Vec2::new(x, top));
+        return;
+    }
+    let lowered = Vec2::new(x, top - drop);
+    let raised = Vec2::new(x + width, top);
+    if width < 0.0 {
+        vertices.push(lowered);
+        vertices.push(raised);
+    } else {
+        vertices.push(raised);
+        vertices.push(lowered);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use PathSurfaceType::*;
+
+    /// Get the heights of the vertices at the side of the profile at `x`.
+    fn get_heights(profile: &Profile, x: f32) -> Vec<f32> {
+        let mut heights: Vec<f32> = profile
+            .get_vertices()
+            .iter()
+            .filter(|vertex| (vertex.x - x).abs() < 0.001)
+            .map(|vertex| vertex.y)
+            .collect();
+        heights.sort_by(f32::total_cmp);
+        heights
+    }
+
+    fn assert_heights(actual: &[f32], expected: &[f32]) {
+        assert_eq!(actual.len(), expected.len(), "{actual:?} != {expected:?}");
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!((actual - expected).abs() < 0.001, "{actual} != {expected}");
+        }
+    }
+
+    #[test]
+    fn carriageway() {
+        // Arrange
+        let info = PathSurfaceInfo::from_purpose(Carriageway);
+
+        // Act
+        let profile = info.get_profile();
+
+        // Assert
+        assert_eq!(profile.get_vertices().len(), 4);
+        assert_heights(&get_heights(&profile, -2.4), &[0.0, 0.025]);
+        assert_heights(&get_heights(&profile, 2.4), &[0.0, 0.025]);
+    }
+
+    #[test]
+    fn footway() {
+        // Arrange
+        let info = PathSurfaceInfo::from_purpose(Footway);
+
+        // Act
+        let profile = info.get_profile();
+
+        // Assert
+        assert_eq!(profile.get_vertices().len(), 5);
+        assert_heights(&get_heights(&profile, 2.4), &[0.0, 0.105]);
+        assert_heights(&get_heights(&profile, 2.42), &[0.125]);
+        assert_heights(&get_heights(&profile, 4.4), &[0.0, 0.125]);
+    }
+
+    #[test]
+    fn footway_on_right() {
+        // Arrange
+        let info = PathSurfaceInfo {
+            position: Offset(-3.4),
+            ..PathSurfaceInfo::from_purpose(Footway)
+        };
+
+        // Act
+        let profile = info.get_profile();
+
+        // Assert
+        assert_eq!(profile.get_vertices().len(), 5);
+        assert_heights(&get_heights(&profile, -4.4), &[0.0, 0.125]);
+        assert_heights(&get_heights(&profile, -2.42), &[0.125]);
+        assert_heights(&get_heights(&profile, -2.4), &[0.0, 0.105]);
+    }
+
+    #[test]
+    fn verge() {
+        // Arrange
+        let info = PathSurfaceInfo::from_purpose(Verge);
+
+        // Act
+        let profile = info.get_profile();
+
+        // Assert
+        assert_eq!(profile.get_vertices().len(), 4);
+        assert_heights(&get_heights(&profile, 4.4), &[0.0, 0.1]);
+        assert_heights(&get_heights(&profile, 6.4), &[0.0, 0.1]);
+    }
+}
@@ -1,31 +1,36 @@
 use super::*;
 use bevy::camera::primitives::{Aabb, MeshAabb};
 use bevy::prelude::*;
-use konstruo_beziers::Sweep;
+use konstruo_beziers::{CubicBezierSpline, Extrude};
 use konstruo_core::constants::PATH_ELEVATION;
 use konstruo_core::EntityExtensions;
 use konstruo_geometry::*;
-use konstruo_ui::{EntityState, OnEntityState, Selectable};
+use konstruo_ui::{EntityState, OnEntityState, Selectable, Settings};
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 use PathSurfacePosition::*;
 use PathSurfaceType::*;
 
 const SURFACE_TO_PATH_GENERATIONS: usize = 1;
 const EDGE_TO_PATH_GENERATIONS: usize = 2;
-static WIREFRAME_ENABLED: bool = false;
 
-/// A surface formed by two lines from a [`Path`].
-///
-/// At present the surface is extruded up but this will change:
-/// <https://github.com/StudioLE/Konstruo/issues/34>
+/// A surface formed by sweeping a cross-section [`Profile`] along a [`Path`].
 #[derive(Component)]
 #[require(InheritedVisibility, Transform)]
 pub struct PathSurface {
     info: PathSurfaceInfo,
 }
 
+/// A [`PathSurface`] with an edge that crosses over itself on a tight curve.
+///
+/// The loop is trimmed so the surface renders cleanly but the user should be warned.
+#[derive(Component, Debug)]
+pub struct PathSurfaceIntersection {
+    pub error: PolylineIntersectionError,
+}
+
 /// A definition for creating a [`PathSurface`].
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PathSurfaceInfo {
     /// Width from side to side.
     pub width: f32,
@@ -35,12 +40,15 @@ pub struct PathSurfaceInfo {
     pub position: PathSurfacePosition,
     /// Type of surface.
     pub purpose: PathSurfaceType,
+    /// Shape of the cross-section.
+    #[serde(default)]
+    pub profile: PathSurfaceProfile,
 }
 
 /// Positioning of the surface.
 ///
 /// Is it centered over or offset from the path?
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum PathSurfacePosition {
     /// Centered over the [`Path`].
     Centered,
@@ -51,7 +59,7 @@ pub enum PathSurfacePosition {
 }
 
 /// Type of surface.
-#[derive(Copy, Clone, Debug, PartialEq)]
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub enum PathSurfaceType {
     /// - <https://en.wikipedia.org/wiki/Carriagepath>
     Carriageway,
@@ -62,6 +70,34 @@ pub enum PathSurfaceType {
 }
 
 impl PathSurfaceInfo {
+    /// Create a [`PathSurfaceInfo`] with typical dimensions for the type of surface.
+    #[must_use]
+    pub fn from_purpose(purpose: PathSurfaceType) -> Self {
+        match purpose {
+            Carriageway => Self {
+                width: 4.8,
+                depth: 0.025,
+                position: Centered,
+                purpose,
+                profile: PathSurfaceProfile::from_purpose(purpose),
+            },
+            Footway => Self {
+                width: 2.0,
+                depth: 0.125,
+                position: Offset(3.4),
+                purpose,
+                profile: PathSurfaceProfile::from_purpose(purpose),
+            },
+            Verge => Self {
+                width: 2.0,
+                depth: 0.100,
+                position: Offset(5.4),
+                purpose,
+                profile: PathSurfaceProfile::from_purpose(purpose),
+            },
+        }
+    }
+
     #[must_use]
     pub fn get_vec6(&self) -> Vec6 {
         match self.position {
@@ -96,10 +132,30 @@ impl PathSurface {
                 depth,
                 position,
                 purpose,
+                profile: PathSurfaceProfile::from_purpose(purpose),
             },
         }
     }
 
+    /// Create a new [`PathSurface`] from [`PathSurfaceInfo`].
+    #[must_use]
+    pub fn from_info(info: PathSurfaceInfo) -> Self {
+        Self { info }
+    }
+
+    /// Get the [`PathSurfaceInfo`].
+    #[must_use]
+    pub fn get_info(&self) -> &PathSurfaceInfo {
+        &self.info
+    }
+
+    /// Set the [`PathSurfaceInfo`].
+    ///
+    /// The mesh geometry is regenerated by [`PathSurface::on_changed`].
+    pub fn set_info(&mut self, info: PathSurfaceInfo) {
+        self.info = info;
+    }
+
     #[must_use]
     pub fn default_surfaces() -> Vec<PathSurface> {
         vec![
@@ -119,10 +175,6 @@ impl PathSurface {
         mut events: MessageReader<SplineChanged>,
         mut surfaces: Query<(Entity, &PathSurface, &ChildOf, &mut Mesh3d, &mut Aabb)>,
         edges: Query<(Entity, &ChildOf), (With<Edge>, Without<PathSurface>)>,
-        wireframes: Query<
-            (Entity, &ChildOf),
-            (With<Wireframe>, Without<PathSurface>, Without<Edge>),
-        >,
     ) {
         let mut factory = PathFactory {
             commands,
@@ -141,38 +193,153 @@ impl PathSurface {
                 if child_of.parent() != event.path {
                     continue;
                 }
-                let sweep = Sweep::new(&event.spline, surface.info.get_vec6());
-                let triangles = sweep.clone().to_triangle_list();
-                let m = triangles.clone().to_mesh();
-                // TODO: Due to entity picking bug the AABB must also be updated. This will likely be fixed in the future.
-                // https://github.com/bevyengine/bevy/issues/18221
-                *aabb = m.compute_aabb().expect("Should be able to compute AABB");
-                *mesh = Mesh3d(factory.meshes.add(m));
-                entity.despawn_children(&mut factory.commands, &edges);
-                factory.spawn_edges(sweep, entity, true);
-                if WIREFRAME_ENABLED {
-                    entity.despawn_children(&mut factory.commands, &wireframes);
-                    factory.spawn_wireframe(triangles, entity);
-                }
+                factory.regenerate_surface(
+                    entity,
+                    &surface.info,
+                    &event.spline,
+                    &mut mesh,
+                    &mut aabb,
+                    &edges,
+                );
             }
         }
         if duplicates > 0 {
             trace!("Ignored {duplicates} duplicate SplineChanged events");
         }
     }
+
+    /// System to spawn or despawn the [`Wireframe`] of each [`PathSurface`].
+    ///
+    /// Wireframes only exist while [`Settings::is_wireframe_visible`] is set.
+    /// They are rebuilt from the surface mesh when it changes or the setting is toggled.
+    pub(super) fn wireframe_system(
+        mut commands: Commands,
+        settings: Res<Settings>,
+        mut is_visible: Local<bool>,
+        mut meshes: ResMut<Assets<Mesh>>,
+        materials: Res<PathMaterials>,
+        surfaces: Query<(Entity, Ref<Mesh3d>), With<PathSurface>>,
+        wireframes: Query<(Entity, &ChildOf), (With<Wireframe>, Without<PathSurface>)>,
+    ) {
+        let is_toggled = *is_visible != settings.is_wireframe_visible;
+        *is_visible = settings.is_wireframe_visible;
+        if !settings.is_wireframe_visible {
+            if is_toggled {
+                for (wireframe, _) in &wireframes {
+                    commands.entity(wireframe).despawn();
+                }
+            }
+            return;
+        }
+        for (entity, mesh) in &surfaces {
+            if !is_toggled && !mesh.is_changed() {
+                continue;
+            }
+            entity.despawn_children(&mut commands, &wireframes);
+            let Some(lines) = meshes.get(&mesh.0).and_then(get_wireframe_lines) else {
+                warn!("Failed to get positions of PathSurface mesh");
+                continue;
+            };
+            let bundle = (
+                Wireframe,
+                Mesh3d(meshes.add(LineList::from_lines(lines).to_mesh())),
+                MeshMaterial3d(materials.wireframe.clone()),
+                ChildOf(entity),
+            );
+            commands.spawn(bundle);
+        }
+    }
+
+    /// Update the mesh geometry and material when the [`PathSurfaceInfo`] changes.
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn on_changed(
+        commands: Commands,
+        meshes: ResMut<Assets<Mesh>>,
+        path_meshes: Res<PathMeshes>,
+        materials: Res<PathMaterials>,
+        mut surfaces: Query<(
+            Entity,
+            Ref<PathSurface>,
+            &ChildOf,
+            &mut Mesh3d,
+            &mut MeshMaterial3d<StandardMaterial>,
+            &mut Aabb,
+        )>,
+        paths: Query<&Path>,
+        edges: Query<(Entity, &ChildOf), (With<Edge>, Without<PathSurface>)>,
+    ) {
+        let mut factory = PathFactory {
+            commands,
+            meshes,
+            path_meshes,
+            materials,
+        };
+        for (entity, surface, child_of, mut mesh, mut material, mut aabb) in &mut surfaces {
+            if !surface.is_changed() || surface.is_added() {
+                continue;
+            }
+            let Ok(path) = paths.get(child_of.parent()) else {
+                warn!("Failed to get Path of PathSurface");
+                continue;
+            };
+            *material = MeshMaterial3d(factory.materials.get_surface(&surface.info.purpose));
+            factory.regenerate_surface(
+                entity,
+                &surface.info,
+                &path.spline,
+                &mut mesh,
+                &mut aabb,
+                &edges,
+            );
+        }
+    }
 }
 
 impl PathFactory<'_> {
     /// Spawn a [`PathSurface`] with its mesh geometry.
-    pub fn spawn_surface(&mut self, surface: PathSurface, path: &Path, path_entity: Entity) {
-        let sweep = Sweep::new(&path.spline, surface.info.get_vec6());
-        let triangles = sweep.clone().to_triangle_list();
-        let surface_bundle = self.surface_bundle(surface, triangles.clone(), path_entity);
+    #[allow(clippy::must_use_candidate)]
+    pub fn spawn_surface(
+        &mut self,
+        surface: PathSurface,
+        path: &Path,
+        path_entity: Entity,
+    ) -> Entity {
+        let (extrude, triangles, intersection) = get_extrude(&surface.info, &path.spline);
+        let surface_bundle = self.surface_bundle(surface, triangles, path_entity);
         let surface_entity = self.commands.spawn(surface_bundle).id();
-        if WIREFRAME_ENABLED {
-            self.spawn_wireframe(triangles, surface_entity);
+        if let Some(intersection) = intersection {
+            self.commands.entity(surface_entity).insert(intersection);
         }
-        self.spawn_edges(sweep, surface_entity, false);
+        self.spawn_edges(extrude, surface_entity, false);
+        surface_entity
+    }
+
+    /// Regenerate the mesh geometry and edges of an existing [`PathSurface`].
+    #[allow(clippy::too_many_arguments)]
+    fn regenerate_surface(
+        &mut self,
+        entity: Entity,
+        info: &PathSurfaceInfo,
+        spline: &CubicBezierSpline,
+        mesh: &mut Mesh3d,
+        aabb: &mut Aabb,
+        edges: &Query<(Entity, &ChildOf), (With<Edge>, Without<PathSurface>)>,
+    ) {
+        let (extrude, triangles, intersection) = get_extrude(info, spline);
+        if let Some(intersection) = intersection {
+            self.commands.entity(entity).insert(intersection);
+        } else {
+            self.commands
+                .entity(entity)
+                .remove::<PathSurfaceIntersection>();
+        }
+        let m = triangles.to_mesh();
+        // TODO: Due to entity picking bug the AABB must also be updated. This will likely be fixed in the future.
+        // https://github.com/bevyengine/bevy/issues/18221
+        *aabb = m.compute_aabb().expect("Should be able to compute AABB");
+        *mesh = Mesh3d(self.meshes.add(m));
+        entity.despawn_children(&mut self.commands, edges);
+        self.spawn_edges(extrude, entity, true);
     }
 
     /// Spawn a [`PathSurface`] with its mesh geometry.
@@ -185,7 +352,7 @@ impl PathFactory<'_> {
         let material = self.materials.get_surface(&surface.info.purpose);
         (
             surface,
-            Mesh3d(self.meshes.add(triangles.clone().to_mesh())),
+            Mesh3d(self.meshes.add(triangles.to_mesh())),
             MeshMaterial3d(material),
             Transform::from_translation(Vec3::new(0.0, 0.0, PATH_ELEVATION)),
             Selectable::new(SURFACE_TO_PATH_GENERATIONS),
@@ -194,23 +361,8 @@ impl PathFactory<'_> {
         )
     }
 
-    fn spawn_wireframe(&mut self, triangles: TriangleList, parent: Entity) {
-        let lines = triangles
-            .get_triangles()
-            .iter()
-            .flat_map(Triangle::to_lines)
-            .collect();
-        let bundle = (
-            Wireframe,
-            Mesh3d(self.meshes.add(LineList::from_lines(lines).to_mesh())),
-            MeshMaterial3d(self.materials.wireframe.clone()),
-            ChildOf(parent),
-        );
-        self.commands.spawn(bundle);
-    }
-
-    fn spawn_edges(&mut self, sweep: Sweep, parent: Entity, is_selected: bool) {
-        let edges = sweep.get_edges();
+    fn spawn_edges(&mut self, extrude: Extrude, parent: Entity, is_selected: bool) {
+        let edges = extrude.get_edges();
         let lines = edges.iter().flat_map(Polyline::to_lines).collect();
         let lines = LineList::from_lines(lines);
         let material = self.materials.edge.clone();
@@ -233,3 +385,48 @@ impl PathFactory<'_> {
         self.commands.spawn(bundle);
     }
 }
+
+/// Create the [`Extrude`] of a surface with any loops trimmed and its [`TriangleList`].
+///
+/// If an edge crossed over itself then a [`PathSurfaceIntersection`] is also returned.
+/// If the trimmed edges still cross then the unchecked triangles are returned with the
+/// intersection that remains.
+fn get_extrude(
+    info: &PathSurfaceInfo,
+    spline: &CubicBezierSpline,
+) -> (Extrude, TriangleList, Option<PathSurfaceIntersection>) {
+    let mut extrude =
+        Extrude::new(spline, info.get_profile()).expect("surface profile should be valid");
+    let mut intersection = extrude.validate().err().map(|error| {
+        trace!("Trimming {:?} surface: {error:?}", info.purpose);
+        PathSurfaceIntersection { error }
+    });
+    extrude.trim_loops();
+    let triangles = match extrude.clone().try_to_triangle_list(false) {
+        Ok(triangles) => triangles,
+        Err(error) => {
+            warn!("Failed to trim {:?} surface: {error:?}", info.purpose);
+            intersection = Some(PathSurfaceIntersection { error });
+            extrude.clone().to_triangle_list()
+        }
+    };
+    (extrude, triangles, intersection)
+}
+
+/// Get the edges of each triangle in a non-indexed [`Mesh`] as lines.
+fn get_wireframe_lines(mesh: &Mesh) -> Option<Vec<Line>> {
+    let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?.as_float3()?;
+    let lines = positions
+        .chunks_exact(3)
+        .filter_map(|vertices| match vertices {
+            [a, b, c] => Some(Triangle::new([
+                Vec3::from(*a),
+                Vec3::from(*b),
+                Vec3::from(*c),
+            ])),
+            _ => None,
+        })
+        .flat_map(|triangle| triangle.to_lines())
+        .collect();
+    Some(lines)
+}
@@ -0,0 +1,29 @@
+use crate::*;
+use bevy::prelude::*;
+use std::path::PathBuf;
+
+/// Request to save the project to a file.
+#[derive(Debug, Message)]
+pub struct SaveProject {
+    pub path: PathBuf,
+}
+
+/// Request to replace the project with one loaded from a file.
+#[derive(Debug, Message)]
+pub struct LoadProject {
+    pub path: PathBuf,
+}
+
+/// Request to replace the project with one that is already in memory.
+///
+/// Unlike [`LoadProject`] no file is read.
+#[derive(Debug, Message)]
+pub struct RestoreProject {
+    pub project: ProjectInfo,
+}
+
+/// Request to export the scene to a glTF binary file.
+#[derive(Debug, Message)]
+pub struct ExportGltf {
+    pub path: PathBuf,
+}
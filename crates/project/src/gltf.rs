@@ -0,0 +1,681 @@
+use crate::*;
+use bevy::ecs::system::SystemParam;
+use bevy::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
+use bevy::prelude::*;
+use konstruo_architecture::{BuildingMaterials, ModularBuilding};
+use konstruo_paths::{Path, PathIntersection, PathMaterials};
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::f32::consts::FRAC_PI_2;
+use std::fmt::{Display, Formatter};
+use std::fs::write;
+use std::io::Error as IoError;
+use std::path::Path as FilePath;
+
+/// Default file path to export the scene as a glTF binary.
+pub const GLTF_PATH: &str = "project.glb";
+
+/// Magic number at the start of a glTF binary: `glTF`.
+const GLB_MAGIC: u32 = 0x4654_6C67;
+
+/// Version of the glTF binary container.
+const GLB_VERSION: u32 = 2;
+
+/// Chunk type of the JSON chunk: `JSON`.
+const CHUNK_JSON: u32 = 0x4E4F_534A;
+
+/// Chunk type of the binary buffer chunk: `BIN`.
+const CHUNK_BIN: u32 = 0x004E_4942;
+
+/// Accessor component type of `f32`.
+const COMPONENT_FLOAT: u32 = 5126;
+
+/// Accessor component type of `u32`.
+const COMPONENT_UNSIGNED_INT: u32 = 5125;
+
+/// Buffer view target of vertex attributes.
+const TARGET_ARRAY_BUFFER: u32 = 34962;
+
+/// Buffer view target of vertex indices.
+const TARGET_ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+/// Name of the root node that converts from Z-up to Y-up.
+const ROOT_NAME: &str = "Konstruo";
+
+/// A glTF 2.0 document with a single binary buffer.
+/// - <https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html>
+#[derive(Debug, Default)]
+pub struct GltfDocument {
+    scene: Vec<usize>,
+    nodes: Vec<Value>,
+    meshes: Vec<Value>,
+    materials: Vec<Value>,
+    accessors: Vec<Value>,
+    buffer_views: Vec<Value>,
+    buffer: Vec<u8>,
+    is_unlit_used: bool,
+}
+
+#[derive(Debug)]
+pub enum GltfError {
+    Io(IoError),
+    Serialize(serde_json::Error),
+    Size,
+}
+
+/// Queries of the entities and assets to export.
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct GltfQuery<'w, 's> {
+    roots: Query<
+        'w,
+        's,
+        Entity,
+        (
+            Or<(With<Path>, With<PathIntersection>, With<ModularBuilding>)>,
+            With<Transform>,
+            Without<ChildOf>,
+        ),
+    >,
+    nodes: Query<
+        'w,
+        's,
+        (
+            Option<&'static Name>,
+            &'static Transform,
+            Option<&'static InheritedVisibility>,
+            Option<&'static Mesh3d>,
+            Option<&'static MeshMaterial3d<StandardMaterial>>,
+            Option<&'static Children>,
+        ),
+    >,
+    meshes: Res<'w, Assets<Mesh>>,
+    materials: Res<'w, Assets<StandardMaterial>>,
+    building_materials: Option<Res<'w, BuildingMaterials>>,
+    path_materials: Option<Res<'w, PathMaterials>>,
+}
+
+/// Indices of the meshes and materials already added to a [`GltfDocument`].
+#[derive(Default)]
+struct GltfCapture {
+    document: GltfDocument,
+    material_names: HashMap<AssetId<StandardMaterial>, &'static str>,
+    meshes: HashMap<(AssetId<Mesh>, Option<AssetId<StandardMaterial>>), Option<usize>>,
+    materials: HashMap<AssetId<StandardMaterial>, usize>,
+}
+
+impl GltfDocument {
+    /// System to write the scene to a glTF binary file on [`ExportGltf`].
+    pub(super) fn on_export(mut events: MessageReader<ExportGltf>, query: GltfQuery) {
+        for event in events.read() {
+            let document = query.capture();
+            if let Err(e) = document.write(&event.path) {
+                warn!("Failed to export glTF: {e}");
+                continue;
+            }
+            trace!(
+                "Exported {} nodes to {}",
+                document.nodes.len(),
+                event.path.display()
+            );
+        }
+    }
+
+    /// Add a node to the scene.
+    pub fn add_root(&mut self, node: usize) {
+        self.scene.push(node);
+    }
+
+    /// Add a node with an optional mesh and child nodes.
+    pub fn add_node(
+        &mut self,
+        name: Option<&str>,
+        transform: &Transform,
+        mesh: Option<usize>,
+        children: Vec<usize>,
+    ) -> usize {
+        let mut node = Map::new();
+        if let Some(name) = name {
+            node.insert("name".to_owned(), json!(name));
+        }
+        node.insert(
+            "translation".to_owned(),
+            json!(transform.translation.to_array()),
+        );
+        node.insert("rotation".to_owned(), json!(transform.rotation.to_array()));
+        node.insert("scale".to_owned(), json!(transform.scale.to_array()));
+        if let Some(mesh) = mesh {
+            node.insert("mesh".to_owned(), json!(mesh));
+        }
+        if !children.is_empty() {
+            node.insert("children".to_owned(), json!(children));
+        }
+        self.nodes.push(Value::Object(node));
+        self.nodes.len() - 1
+    }
+
+    /// Add a material converted from a [`StandardMaterial`].
+    ///
+    /// Unlit materials use the `KHR_materials_unlit` extension.
+    pub fn add_material(&mut self, name: &str, material: &StandardMaterial) -> usize {
+        let [red, green, blue, _] = material.emissive.to_f32_array();
+        let mut value = Map::new();
+        value.insert("name".to_owned(), json!(name));
+        value.insert(
+            "pbrMetallicRoughness".to_owned(),
+            json!({
+                "baseColorFactor": material.base_color.to_linear().to_f32_array(),
+                "metallicFactor": material.metallic,
+                "roughnessFactor": material.perceptual_roughness,
+            }),
+        );
+        value.insert(
+            "emissiveFactor".to_owned(),
+            json!([red, green, blue].map(|channel| channel.clamp(0.0, 1.0))),
+        );
+        match material.alpha_mode {
+            AlphaMode::Opaque => {
+                value.insert("alphaMode".to_owned(), json!("OPAQUE"));
+            }
+            AlphaMode::Mask(cutoff) => {
+                value.insert("alphaMode".to_owned(), json!("MASK"));
+                value.insert("alphaCutoff".to_owned(), json!(cutoff));
+            }
+            _ => {
+                value.insert("alphaMode".to_owned(), json!("BLEND"));
+            }
+        }
+        value.insert("doubleSided".to_owned(), json!(material.double_sided));
+        if material.unlit {
+            value.insert(
+                "extensions".to_owned(),
+                json!({ "KHR_materials_unlit": {} }),
+            );
+            self.is_unlit_used = true;
+        }
+        self.materials.push(Value::Object(value));
+        self.materials.len() - 1
+    }
+
+    /// Add a mesh with a single primitive of the positions, normals and indices.
+    ///
+    /// Returns `None` if the mesh has no positions.
+    pub fn add_mesh(&mut self, mesh: &Mesh, material: Option<usize>) -> Option<usize> {
+        let mode = match mesh.primitive_topology() {
+            PrimitiveTopology::PointList => 0,
+            PrimitiveTopology::LineList => 1,
+            PrimitiveTopology::LineStrip => 3,
+            PrimitiveTopology::TriangleList => 4,
+            PrimitiveTopology::TriangleStrip => 5,
+        };
+        let Ok(Some(VertexAttributeValues::Float32x3(positions))) =
+            mesh.try_attribute_option(Mesh::ATTRIBUTE_POSITION)
+        else {
+            return None;
+        };
+        if positions.is_empty() {
+            return None;
+        }
+        let mut attributes = Map::new();
+        attributes.insert(
+            "POSITION".to_owned(),
+            json!(self.add_vec3_accessor(positions, true)),
+        );
+        if let Ok(Some(VertexAttributeValues::Float32x3(normals))) =
+            mesh.try_attribute_option(Mesh::ATTRIBUTE_NORMAL)
+        {
+            attributes.insert(
+                "NORMAL".to_owned(),
+                json!(self.add_vec3_accessor(normals, false)),
+            );
+        }
+        let mut primitive = Map::new();
+        primitive.insert("attributes".to_owned(), Value::Object(attributes));
+        primitive.insert("mode".to_owned(), json!(mode));
+        if let Ok(indices) = mesh.try_indices() {
+            let indices: Vec<u32> = match indices {
+                Indices::U16(indices) => indices.iter().copied().map(u32::from).collect(),
+                Indices::U32(indices) => indices.clone(),
+            };
+            primitive.insert(
+                "indices".to_owned(),
+                json!(self.add_index_accessor(&indices)),
+            );
+        }
+        if let Some(material) = material {
+            primitive.insert("material".to_owned(), json!(material));
+        }
+        self.meshes.push(json!({ "primitives": [primitive] }));
+        Some(self.meshes.len() - 1)
+    }
+
+    /// Add an accessor of `VEC3` floats.
+    ///
+    /// Positions require the bounds.
+    fn add_vec3_accessor(&mut self, values: &[[f32; 3]], with_bounds: bool) -> usize {
+        let bytes: Vec<u8> = values
+            .iter()
+            .flatten()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+        let view = self.add_buffer_view(&bytes, TARGET_ARRAY_BUFFER);
+        let mut accessor = Map::new();
+        accessor.insert("bufferView".to_owned(), json!(view));
+        accessor.insert("componentType".to_owned(), json!(COMPONENT_FLOAT));
+        accessor.insert("count".to_owned(), json!(values.len()));
+        accessor.insert("type".to_owned(), json!("VEC3"));
+        if with_bounds {
+            let min = values
+                .iter()
+                .map(|value| Vec3::from(*value))
+                .fold(Vec3::INFINITY, Vec3::min);
+            let max = values
+                .iter()
+                .map(|value| Vec3::from(*value))
+                .fold(Vec3::NEG_INFINITY, Vec3::max);
+            accessor.insert("min".to_owned(), json!(min.to_array()));
+            accessor.insert("max".to_owned(), json!(max.to_array()));
+        }
+        self.accessors.push(Value::Object(accessor));
+        self.accessors.len() - 1
+    }
+
+    /// Add an accessor of `SCALAR` vertex indices.
+    fn add_index_accessor(&mut self, indices: &[u32]) -> usize {
+        let bytes: Vec<u8> = indices
+            .iter()
+            .flat_map(|index| index.to_le_bytes())
+            .collect();
+        let view = self.add_buffer_view(&bytes, TARGET_ELEMENT_ARRAY_BUFFER);
+        self.accessors.push(json!({
+            "bufferView": view,
+            "componentType": COMPONENT_UNSIGNED_INT,
+            "count": indices.len(),
+            "type": "SCALAR",
+        }));
+        self.accessors.len() - 1
+    }
+
+    /// Append the bytes to the buffer aligned to 4 bytes.
+    fn add_buffer_view(&mut self, bytes: &[u8], target: u32) -> usize {
+        pad(&mut self.buffer, 0);
+        self.buffer_views.push(json!({
+            "buffer": 0,
+            "byteOffset": self.buffer.len(),
+            "byteLength": bytes.len(),
+            "target": target,
+        }));
+        self.buffer.extend_from_slice(bytes);
+        self.buffer_views.len() - 1
+    }
+
+    /// Get the JSON of the document.
+    #[must_use]
+    pub fn to_json(&self) -> Value {
+        let mut value = Map::new();
+        value.insert(
+            "asset".to_owned(),
+            json!({ "version": "2.0", "generator": "Konstruo" }),
+        );
+        value.insert("scene".to_owned(), json!(0));
+        value.insert("scenes".to_owned(), json!([{ "nodes": self.scene }]));
+        for (key, values) in [
+            ("nodes", &self.nodes),
+            ("meshes", &self.meshes),
+            ("materials", &self.materials),
+            ("accessors", &self.accessors),
+            ("bufferViews", &self.buffer_views),
+        ] {
+            if !values.is_empty() {
+                value.insert(key.to_owned(), json!(values));
+            }
+        }
+        if !self.buffer.is_empty() {
+            value.insert(
+                "buffers".to_owned(),
+                json!([{ "byteLength": self.buffer.len() }]),
+            );
+        }
+        if self.is_unlit_used {
+            value.insert("extensionsUsed".to_owned(), json!(["KHR_materials_unlit"]));
+        }
+        Value::Object(value)
+    }
+
+    /// Encode as a glTF binary with a JSON chunk followed by a binary buffer chunk.
+    /// - <https://registry.khronos.org/glTF/specs/2.0/glTF-2.0.html#binary-gltf-layout>
+    pub fn to_glb(&self) -> Result<Vec<u8>, GltfError> {
+        let mut json = serde_json::to_vec(&self.to_json()).map_err(GltfError::Serialize)?;
+        pad(&mut json, b' ');
+        let mut buffer = self.buffer.clone();
+        pad(&mut buffer, 0);
+        let mut length = 12 + 8 + json.len();
+        if !buffer.is_empty() {
+            length += 8 + buffer.len();
+        }
+        let mut glb = Vec::with_capacity(length);
+        for value in [GLB_MAGIC, GLB_VERSION, to_u32(length)?] {
+            glb.extend_from_slice(&value.to_le_bytes());
+        }
+        glb.extend_from_slice(&to_u32(json.len())?.to_le_bytes());
+        glb.extend_from_slice(&CHUNK_JSON.to_le_bytes());
+        glb.append(&mut json);
+        if !buffer.is_empty() {
+            glb.extend_from_slice(&to_u32(buffer.len())?.to_le_bytes());
+            glb.extend_from_slice(&CHUNK_BIN.to_le_bytes());
+            glb.append(&mut buffer);
+        }
+        Ok(glb)
+    }
+
+    /// Write the document to a glTF binary file.
+    pub fn write(&self, path: &FilePath) -> Result<(), GltfError> {
+        write(path, self.to_glb()?).map_err(GltfError::Io)
+    }
+}
+
+impl GltfQuery<'_, '_> {
+    /// Capture every visible entity with a [`Mesh3d`] as a [`GltfDocument`].
+    ///
+    /// Only [`Path`], [`PathIntersection`] and [`ModularBuilding`] hierarchies are exported so
+    /// the ground, grid, camera and other scene furniture are omitted.
+    ///
+    /// Nodes keep their [`Name`] and [`Transform`] relative to their parent.
+    /// Entities without a visible mesh in their hierarchy are omitted.
+    /// A root node rotates the scene from Z-up to the Y-up of glTF.
+    #[must_use]
+    pub fn capture(&self) -> GltfDocument {
+        let mut capture = GltfCapture {
+            material_names: self.get_material_names(),
+            ..default()
+        };
+        let children = self
+            .roots
+            .iter()
+            .filter_map(|entity| self.capture_node(entity, &mut capture))
+            .collect();
+        let transform = Transform::from_rotation(Quat::from_rotation_x(-FRAC_PI_2));
+        let root = capture
+            .document
+            .add_node(Some(ROOT_NAME), &transform, None, children);
+        capture.document.add_root(root);
+        capture.document
+    }
+
+    /// Capture an entity and its children.
+    fn capture_node(&self, entity: Entity, capture: &mut GltfCapture) -> Option<usize> {
+        let (name, transform, visibility, mesh, material, children) =
+            self.nodes.get(entity).ok()?;
+        if visibility.is_some_and(|visibility| !visibility.get()) {
+            return None;
+        }
+        let children = children
+            .into_iter()
+            .flat_map(Children::iter)
+            .filter_map(|child| self.capture_node(child, capture))
+            .collect::<Vec<_>>();
+        let mesh = mesh.and_then(|mesh| self.capture_mesh(mesh, material, capture));
+        if mesh.is_none() && children.is_empty() {
+            return None;
+        }
+        Some(
+            capture
+                .document
+                .add_node(name.map(Name::as_str), transform, mesh, children),
+        )
+    }
+
+    /// Capture a mesh with its material unless it's already been captured.
+    fn capture_mesh(
+        &self,
+        mesh: &Mesh3d,
+        material: Option<&MeshMaterial3d<StandardMaterial>>,
+        capture: &mut GltfCapture,
+    ) -> Option<usize> {
+        let material = material.map(|material| material.id());
+        let key = (mesh.id(), material);
+        if let Some(index) = capture.meshes.get(&key) {
+            return *index;
+        }
+        let material = material.and_then(|material| self.capture_material(material, capture));
+        let index = self
+            .meshes
+            .get(mesh.id())
+            .and_then(|mesh| capture.document.add_mesh(mesh, material));
+        capture.meshes.insert(key, index);
+        index
+    }
+
+    /// Capture a material unless it's already been captured.
+    fn capture_material(
+        &self,
+        id: AssetId<StandardMaterial>,
+        capture: &mut GltfCapture,
+    ) -> Option<usize> {
+        if let Some(index) = capture.materials.get(&id) {
+            return Some(*index);
+        }
+        let material = self.materials.get(id)?;
+        let name = capture
+            .material_names
+            .get(&id)
+            .copied()
+            .unwrap_or("Material");
+        let index = capture.document.add_material(name, material);
+        capture.materials.insert(id, index);
+        Some(index)
+    }
+
+    /// Get the names of the [`BuildingMaterials`] and [`PathMaterials`].
+    fn get_material_names(&self) -> HashMap<AssetId<StandardMaterial>, &'static str> {
+        let mut names = HashMap::new();
+        if let Some(materials) = &self.building_materials {
+            names.insert(materials.face.id(), "Building Face");
+            names.insert(materials.edges.id(), "Building Edges");
+        }
+        if let Some(materials) = &self.path_materials {
+            for (material, name) in [
+                (&materials.center_line, "Path Center Line"),
+                (&materials.control_node, "Path Control Node"),
+                (&materials.control_node_over, "Path Control Node Over"),
+                (&materials.control_node_drag, "Path Control Node Drag"),
+                (&materials.control_line, "Path Control Line"),
+                (&materials.edge, "Path Edge"),
+                (&materials.wireframe, "Path Wireframe"),
+                (&materials.carriageway, "Carriageway"),
+                (&materials.footway, "Footway"),
+                (&materials.verge, "Verge"),
+            ] {
+                names.insert(material.id(), name);
+            }
+        }
+        names
+    }
+}
+
+impl Display for GltfError {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            GltfError::Io(e) => format!("Failed to access glTF file: {e}"),
+            GltfError::Serialize(e) => format!("Failed to serialize glTF: {e}"),
+            GltfError::Size => "glTF is larger than 4 GiB".to_owned(),
+        };
+        formatter.write_str(&reason)
+    }
+}
+
+/// Pad the bytes to a multiple of 4.
+fn pad(bytes: &mut Vec<u8>, value: u8) {
+    while !bytes.len().is_multiple_of(4) {
+        bytes.push(value);
+    }
+}
+
+/// Convert a length to the `u32` of a glTF binary.
+fn to_u32(length: usize) -> Result<u32, GltfError> {
+    u32::try_from(length).map_err(|_| GltfError::Size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::RenderAssetUsages;
+    use bevy::ecs::system::RunSystemOnce;
+    use konstruo_beziers::CubicBezierSpline;
+    use konstruo_environment::Ground;
+
+    fn triangle() -> Mesh {
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::default(),
+        )
+        .with_inserted_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 2.0, 0.0]],
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0.0, 0.0, 1.0]; 3])
+        .with_inserted_indices(Indices::U16(vec![0, 1, 2]))
+    }
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        let word = bytes
+            .get(offset..offset + 4)
+            .and_then(|word| word.try_into().ok())
+            .expect("should be 4 bytes");
+        u32::from_le_bytes(word)
+    }
+
+    #[test]
+    fn to_json() {
+        // Arrange
+        let mut document = GltfDocument::default();
+        let material = StandardMaterial {
+            unlit: true,
+            ..default()
+        };
+
+        // Act
+        let material = document.add_material("Edges", &material);
+        let mesh = document.add_mesh(&triangle(), Some(material));
+        let node = document.add_node(Some("Triangle"), &Transform::default(), mesh, Vec::new());
+        document.add_root(node);
+        let json = document.to_json();
+
+        // Assert
+        assert_eq!(mesh, Some(0));
+        assert_eq!(json.pointer("/nodes/0/name"), Some(&json!("Triangle")));
+        assert_eq!(
+            json.pointer("/meshes/0/primitives/0/material"),
+            Some(&json!(0))
+        );
+        assert_eq!(
+            json.pointer("/meshes/0/primitives/0/indices"),
+            Some(&json!(2))
+        );
+        assert_eq!(
+            json.pointer("/accessors/0/max"),
+            Some(&json!([1.0, 2.0, 0.0]))
+        );
+        assert_eq!(json.pointer("/bufferViews/2/byteOffset"), Some(&json!(72)));
+        assert_eq!(json.pointer("/buffers/0/byteLength"), Some(&json!(84)));
+        assert_eq!(
+            json.pointer("/extensionsUsed/0"),
+            Some(&json!("KHR_materials_unlit"))
+        );
+    }
+
+    #[test]
+    fn to_glb() -> Result<(), GltfError> {
+        // Arrange
+        let mut document = GltfDocument::default();
+        let mesh = document.add_mesh(&triangle(), None);
+        let node = document.add_node(None, &Transform::default(), mesh, Vec::new());
+        document.add_root(node);
+
+        // Act
+        let glb = document.to_glb()?;
+
+        // Assert
+        assert_eq!(read_u32(&glb, 0), GLB_MAGIC);
+        assert_eq!(read_u32(&glb, 4), GLB_VERSION);
+        assert_eq!(read_u32(&glb, 8), to_u32(glb.len())?);
+        let json_length = usize::try_from(read_u32(&glb, 12)).expect("should fit");
+        assert!(json_length.is_multiple_of(4));
+        assert_eq!(read_u32(&glb, 16), CHUNK_JSON);
+        let json = glb
+            .get(20..20 + json_length)
+            .expect("should be a JSON chunk");
+        let json: Value = serde_json::from_slice(json).map_err(GltfError::Serialize)?;
+        assert_eq!(json.pointer("/asset/version"), Some(&json!("2.0")));
+        let bin = 20 + json_length;
+        assert_eq!(read_u32(&glb, bin), 84);
+        assert_eq!(read_u32(&glb, bin + 4), CHUNK_BIN);
+        assert_eq!(glb.len(), bin + 8 + 84);
+        Ok(())
+    }
+
+    #[test]
+    fn capture_paths_only() {
+        // Arrange
+        let mut world = World::new();
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<StandardMaterial>>();
+        let mesh = world.resource_mut::<Assets<Mesh>>().add(triangle());
+        world.spawn((
+            Ground,
+            Name::new("Ground"),
+            Mesh3d(mesh.clone()),
+            Transform::default(),
+        ));
+        let path = world
+            .spawn((
+                Path::new(CubicBezierSpline::example()),
+                Name::new("Path"),
+                InheritedVisibility::VISIBLE,
+            ))
+            .id();
+        world.spawn((
+            Name::new("Surface"),
+            Mesh3d(mesh.clone()),
+            Transform::default(),
+            ChildOf(path),
+        ));
+        world.spawn((
+            PathIntersection {
+                paths: Vec::new(),
+                kerb_radius: 1.0,
+            },
+            Name::new("Path Junction"),
+            Mesh3d(mesh),
+            InheritedVisibility::VISIBLE,
+        ));
+
+        // Act
+        let document = world
+            .run_system_once(|query: GltfQuery| query.capture())
+            .expect("system should run");
+
+        // Assert
+        let json = document.to_json();
+        let names: Vec<&str> = json
+            .pointer("/nodes")
+            .and_then(Value::as_array)
+            .expect("should be nodes")
+            .iter()
+            .filter_map(|node| node.get("name")?.as_str())
+            .collect();
+        assert_eq!(names.len(), 4);
+        assert!(names.contains(&"Path"));
+        assert!(names.contains(&"Surface"));
+        assert!(names.contains(&"Path Junction"));
+        assert!(names.contains(&ROOT_NAME));
+        assert!(!names.contains(&"Ground"));
+        assert_eq!(
+            json.pointer("/meshes")
+                .and_then(Value::as_array)
+                .map(Vec::len),
+            Some(1)
+        );
+    }
+}
@@ -0,0 +1,12 @@
+pub use events::*;
+pub use gltf::*;
+pub use plugin::*;
+pub use project::*;
+pub use save::*;
+
+mod events;
+mod gltf;
+mod load;
+mod plugin;
+mod project;
+mod save;
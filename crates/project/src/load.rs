@@ -0,0 +1,197 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_architecture::*;
+use konstruo_beziers::CubicBezierSpline;
+use konstruo_core::HandleError;
+use konstruo_paths::{
+    Path, PathFactory, PathIntersection, PathIntersectionBuilder, PathMaterials, PathMeshes,
+    PathSurface,
+};
+use std::path::PathBuf;
+
+/// A project waiting for the building templates to load before it replaces the current one.
+pub(super) enum PendingProject {
+    /// Read from a file.
+    Load(PathBuf),
+    /// Already in memory.
+    Restore(ProjectInfo),
+}
+
+impl ProjectInfo {
+    /// System to replace the project with one read from a file on [`LoadProject`] or held
+    /// in memory on [`RestoreProject`].
+    ///
+    /// Both are read every frame and the latest is kept until the [`BuildingTemplates`] are
+    /// loaded, with a [`RestoreProject`] taking precedence over a [`LoadProject`].
+    #[allow(clippy::too_many_arguments)]
+    pub(super) fn on_load(
+        mut loads: MessageReader<LoadProject>,
+        mut restores: MessageReader<RestoreProject>,
+        mut commands: Commands,
+        meshes: ResMut<Assets<Mesh>>,
+        path_meshes: Res<PathMeshes>,
+        path_materials: Res<PathMaterials>,
+        building_meshes: Res<BuildingMeshes>,
+        building_materials: Res<BuildingMaterials>,
+        templates: Res<BuildingTemplates>,
+        assets: Res<Assets<ModularBuildingInfo>>,
+        paths: Query<Entity, With<Path>>,
+        junctions: Query<Entity, With<PathIntersection>>,
+        buildings: Query<Entity, (With<ModularBuilding>, Without<ChildOf>)>,
+        mut pending_project: Local<Option<PendingProject>>,
+    ) {
+        let restore = restores.read().last().map(|event| event.project.clone());
+        let load = loads.read().last().map(|event| event.path.clone());
+        if let Some(project) = restore {
+            *pending_project = Some(PendingProject::Restore(project));
+        } else if let Some(path) = load {
+            *pending_project = Some(PendingProject::Load(path));
+        }
+        if pending_project.is_none() {
+            return;
+        }
+        if !templates.is_loaded() {
+            trace!("Deferring project load until building templates are loaded");
+            return;
+        }
+        let Some(project) = pending_project.take().and_then(PendingProject::read) else {
+            return;
+        };
+        for entity in paths.iter().chain(junctions.iter()).chain(buildings.iter()) {
+            commands.entity(entity).despawn();
+        }
+        let mut path_factory = PathFactory {
+            commands,
+            meshes,
+            path_meshes,
+            materials: path_materials,
+        };
+        let mut pending = Vec::new();
+        let mut spawned = Vec::new();
+        for path_info in project.paths {
+            let path = Path::new(path_info.spline);
+            let path_entity = path_factory.spawn_path(path.clone());
+            spawned.push((path_entity, path.spline.clone()));
+            for surface in path_info.surfaces {
+                path_factory.spawn_surface(PathSurface::from_info(surface), &path, path_entity);
+            }
+            for distribution_info in path_info.distributions {
+                let Some(distribution) = distribution_info
+                    .to_distribution(&path.spline)
+                    .handle_error(|e| warn!("Failed to offset Distribution spline: {e}"))
+                else {
+                    continue;
+                };
+                let mut entity = path_factory
+                    .commands
+                    .spawn((distribution, ChildOf(path_entity)));
+                if let Some(fill) = distribution_info.fill {
+                    entity.insert(fill);
+                }
+                let distribution_entity = entity.id();
+                pending.push((distribution_entity, distribution_info.buildings));
+            }
+        }
+        for junction in project.junctions {
+            spawn_junction(&mut path_factory, &spawned, junction);
+        }
+        let PathFactory {
+            commands, meshes, ..
+        } = path_factory;
+        let mut building_factory = ModularBuildingFactory {
+            commands,
+            meshes,
+            building_meshes,
+            materials: building_materials,
+        };
+        for (distribution_entity, buildings) in pending {
+            for building in buildings {
+                let Some(entity) = spawn_template(
+                    &mut building_factory,
+                    &templates,
+                    &assets,
+                    &building.template,
+                    building.is_mirrored,
+                ) else {
+                    continue;
+                };
+                building_factory
+                    .commands
+                    .entity(entity)
+                    .insert(building.distributable)
+                    .insert(ChildOf(distribution_entity));
+            }
+        }
+        for building in project.buildings {
+            let Some(entity) = spawn_template(
+                &mut building_factory,
+                &templates,
+                &assets,
+                &building.template,
+                building.is_mirrored,
+            ) else {
+                continue;
+            };
+            building_factory
+                .commands
+                .entity(entity)
+                .insert(building.transform);
+        }
+    }
+}
+
+impl PendingProject {
+    /// Get the [`ProjectInfo`], reading it from file if required.
+    fn read(self) -> Option<ProjectInfo> {
+        match self {
+            PendingProject::Restore(project) => Some(project),
+            PendingProject::Load(path) => {
+                let project = ProjectInfo::read(&path)
+                    .handle_error(|e| warn!("Failed to load project: {e}"))?;
+                trace!("Loaded project from {}", path.display());
+                Some(project)
+            }
+        }
+    }
+}
+
+/// Spawn a [`PathIntersection`] between the spawned paths.
+fn spawn_junction(
+    factory: &mut PathFactory<'_>,
+    paths: &[(Entity, CubicBezierSpline)],
+    junction: JunctionInfo,
+) {
+    let mut builder = PathIntersectionBuilder::default().with_kerb_radius(junction.kerb_radius);
+    for info in junction.paths {
+        let Some((entity, spline)) = paths.get(info.path) else {
+            warn!("Failed to get Path of junction: {}", info.path);
+            continue;
+        };
+        builder.add(*entity, spline.clone(), info.surface);
+    }
+    match builder.build() {
+        Ok(intersection) => {
+            factory.spawn_junction(intersection);
+        }
+        Err(e) => warn!("Failed to build junction: {e:?}"),
+    }
+}
+
+/// Spawn a [`ModularBuilding`] from a named template.
+fn spawn_template(
+    factory: &mut ModularBuildingFactory<'_>,
+    templates: &BuildingTemplates,
+    assets: &Assets<ModularBuildingInfo>,
+    name: &str,
+    is_mirrored: bool,
+) -> Option<Entity> {
+    let Some(info) = templates.get(name, assets) else {
+        warn!("Failed to get building template: {name}");
+        return None;
+    };
+    if is_mirrored {
+        Some(factory.spawn_mirrored_template(name, info.clone()))
+    } else {
+        Some(factory.spawn_template(name, info.clone()))
+    }
+}
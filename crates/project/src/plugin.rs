@@ -0,0 +1,44 @@
+use crate::*;
+use bevy::prelude::*;
+use std::path::PathBuf;
+use KeyCode::{ControlLeft, ControlRight, KeyO, KeyS};
+
+/// Plugin to save and load a [`ProjectInfo`] and export the scene as glTF.
+///
+/// - `Ctrl + S` saves the project to [`PROJECT_PATH`]
+/// - `Ctrl + O` loads the project from [`PROJECT_PATH`]
+pub struct ProjectPlugin;
+
+impl Plugin for ProjectPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ExportGltf>()
+            .add_message::<LoadProject>()
+            .add_message::<RestoreProject>()
+            .add_message::<SaveProject>()
+            .add_systems(Update, ProjectInfo::on_load)
+            .add_systems(Update, ProjectInfo::on_save)
+            .add_systems(Update, GltfDocument::on_export)
+            .add_systems(Update, shortcut_system);
+    }
+}
+
+/// System to write [`SaveProject`] or [`LoadProject`] on keyboard shortcuts.
+fn shortcut_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut save: MessageWriter<SaveProject>,
+    mut load: MessageWriter<LoadProject>,
+) {
+    if !keys.any_pressed([ControlLeft, ControlRight]) {
+        return;
+    }
+    if keys.just_pressed(KeyS) {
+        save.write(SaveProject {
+            path: PathBuf::from(PROJECT_PATH),
+        });
+    }
+    if keys.just_pressed(KeyO) {
+        load.write(LoadProject {
+            path: PathBuf::from(PROJECT_PATH),
+        });
+    }
+}
@@ -0,0 +1,312 @@
+use bevy::prelude::*;
+use konstruo_architecture::BuildingFill;
+use konstruo_beziers::constants::LENGTH_ACCURACY;
+use konstruo_beziers::{CubicBezierSpline, CubicBezierSplineError};
+use konstruo_distribution::{Distributable, Distribution, FlexFactory};
+use konstruo_paths::PathSurfaceInfo;
+use ron::error::SpannedError;
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs::{read_to_string, write};
+use std::io::Error as IoError;
+use std::path::Path as FilePath;
+
+/// Default file path to save and load a project.
+pub const PROJECT_PATH: &str = "project.konstruo.ron";
+
+/// A serializable definition of an entire project.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ProjectInfo {
+    /// Paths with their surfaces and distributions.
+    pub paths: Vec<PathInfo>,
+    /// Buildings that are not distributed along a path.
+    pub buildings: Vec<BuildingInfo>,
+    /// Junctions where paths meet.
+    #[serde(default)]
+    pub junctions: Vec<JunctionInfo>,
+}
+
+/// A serializable definition of a [`konstruo_paths::Path`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PathInfo {
+    /// Spline defining the center of the path.
+    pub spline: CubicBezierSpline,
+    /// Surfaces swept along the spline.
+    pub surfaces: Vec<PathSurfaceInfo>,
+    /// Distributions of buildings along the spline.
+    pub distributions: Vec<DistributionInfo>,
+}
+
+/// A serializable definition of a [`Distribution`] of buildings along a [`konstruo_paths::Path`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DistributionInfo {
+    /// Flex layout of the distribution.
+    pub flex: FlexFactory,
+    /// How far is the spline offset from the path?
+    pub spline_offset: Option<f32>,
+    /// Is the offset spline reversed?
+    #[serde(default)]
+    pub spline_reversed: bool,
+    /// Should the container be translated to ground level?
+    pub translate_to_ground: bool,
+    /// Buildings to distribute.
+    pub buildings: Vec<DistributedBuildingInfo>,
+    /// Templates to fill the distribution with when the path changes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fill: Option<BuildingFill>,
+}
+
+/// A serializable definition of a [`konstruo_architecture::ModularBuilding`] in a [`Distribution`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DistributedBuildingInfo {
+    /// Name of the template in [`konstruo_architecture::BuildingTemplates`].
+    pub template: String,
+    /// Was the template mirrored from left to right?
+    #[serde(default)]
+    pub is_mirrored: bool,
+    /// How the building is distributed.
+    pub distributable: Distributable,
+}
+
+/// A serializable definition of a [`konstruo_architecture::ModularBuilding`] placed by transform.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BuildingInfo {
+    /// Name of the template in [`konstruo_architecture::BuildingTemplates`].
+    pub template: String,
+    /// Was the template mirrored from left to right?
+    #[serde(default)]
+    pub is_mirrored: bool,
+    /// Transform of the building.
+    pub transform: Transform,
+}
+
+/// A serializable definition of a [`konstruo_paths::PathIntersection`] junction.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct JunctionInfo {
+    /// Paths that meet at the junction.
+    pub paths: Vec<JunctionPathInfo>,
+    /// Radius of the kerb where the edges of adjacent paths meet.
+    pub kerb_radius: f32,
+}
+
+/// A serializable definition of a path that meets at a junction.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct JunctionPathInfo {
+    /// Index of the path in [`ProjectInfo::paths`].
+    pub path: usize,
+    /// Surface that defines the width of the path at the junction.
+    pub surface: PathSurfaceInfo,
+}
+
+#[derive(Debug)]
+pub enum ProjectError {
+    Io(IoError),
+    Serialize(ron::Error),
+    Deserialize(SpannedError),
+}
+
+impl ProjectInfo {
+    /// Read a [`ProjectInfo`] from a RON file.
+    pub fn read(path: &FilePath) -> Result<Self, ProjectError> {
+        let contents = read_to_string(path).map_err(ProjectError::Io)?;
+        ron::from_str(&contents).map_err(ProjectError::Deserialize)
+    }
+
+    /// Write the [`ProjectInfo`] to a RON file.
+    pub fn write(&self, path: &FilePath) -> Result<(), ProjectError> {
+        let contents =
+            to_string_pretty(self, PrettyConfig::default()).map_err(ProjectError::Serialize)?;
+        write(path, contents).map_err(ProjectError::Io)
+    }
+}
+
+impl DistributionInfo {
+    /// Create a [`DistributionInfo`] from a [`Distribution`].
+    #[must_use]
+    pub fn from_distribution(
+        distribution: &Distribution,
+        buildings: Vec<DistributedBuildingInfo>,
+        fill: Option<BuildingFill>,
+    ) -> Self {
+        Self {
+            flex: distribution.flex.clone(),
+            spline_offset: distribution.spline_offset,
+            spline_reversed: distribution.spline_reversed,
+            translate_to_ground: distribution.translate_to_ground,
+            buildings,
+            fill,
+        }
+    }
+
+    /// Create a [`Distribution`] along the spline of a [`konstruo_paths::Path`].
+    ///
+    /// The bounds are updated to match the length of the offset spline.
+    pub fn to_distribution(
+        &self,
+        spline: &CubicBezierSpline,
+    ) -> Result<Distribution, CubicBezierSplineError> {
+        let mut distribution = Distribution {
+            flex: self.flex.clone(),
+            spline: None,
+            spline_offset: self.spline_offset,
+            spline_reversed: self.spline_reversed,
+            translate_to_ground: self.translate_to_ground,
+        };
+        let spline = distribution.get_spline_from_path(spline)?;
+        let length = spline.get_length(LENGTH_ACCURACY);
+        distribution.flex.bounds = distribution.flex.bounds.map(|bounds| bounds.with_x(length));
+        distribution.spline = Some(spline);
+        Ok(distribution)
+    }
+}
+
+impl Display for ProjectError {
+    #[allow(clippy::absolute_paths)]
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            ProjectError::Io(e) => format!("Failed to access project file: {e}"),
+            ProjectError::Serialize(e) => format!("Failed to serialize project: {e}"),
+            ProjectError::Deserialize(e) => format!("Failed to deserialize project: {e}"),
+        };
+        formatter.write_str(&reason)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::math::Vec3;
+    use konstruo_beziers::NodeContinuity;
+    use konstruo_paths::PathSurfaceType;
+    use std::env::temp_dir;
+
+    fn example() -> ProjectInfo {
+        let mut spline = CubicBezierSpline::example();
+        spline
+            .set_continuity(1, NodeContinuity::Corner)
+            .expect("node should exist");
+        let distributable = Distributable {
+            order: 3,
+            rotation: Some(Quat::from_rotation_z(0.5)),
+            ..default()
+        };
+        ProjectInfo {
+            paths: vec![
+                PathInfo {
+                    spline,
+                    surfaces: vec![
+                        PathSurfaceInfo::from_purpose(PathSurfaceType::Carriageway),
+                        PathSurfaceInfo::from_purpose(PathSurfaceType::Footway),
+                    ],
+                    distributions: vec![DistributionInfo {
+                        flex: FlexFactory::default(),
+                        spline_offset: Some(10.0),
+                        spline_reversed: true,
+                        translate_to_ground: true,
+                        buildings: vec![DistributedBuildingInfo {
+                            template: String::from("2b4p1s0g"),
+                            is_mirrored: true,
+                            distributable,
+                        }],
+                        fill: Some(BuildingFill {
+                            templates: vec![String::from("2b4p1s0g")],
+                        }),
+                    }],
+                },
+                PathInfo {
+                    spline: CubicBezierSpline::example_2(),
+                    surfaces: Vec::new(),
+                    distributions: Vec::new(),
+                },
+            ],
+            buildings: vec![BuildingInfo {
+                template: String::from("3b6p2s2g"),
+                is_mirrored: true,
+                transform: Transform::from_translation(Vec3::new(1.0, 2.0, 0.0)),
+            }],
+            junctions: vec![JunctionInfo {
+                paths: vec![
+                    JunctionPathInfo {
+                        path: 0,
+                        surface: PathSurfaceInfo::from_purpose(PathSurfaceType::Carriageway),
+                    },
+                    JunctionPathInfo {
+                        path: 1,
+                        surface: PathSurfaceInfo::from_purpose(PathSurfaceType::Carriageway),
+                    },
+                ],
+                kerb_radius: 2.5,
+            }],
+        }
+    }
+
+    #[test]
+    fn write_and_read() -> Result<(), ProjectError> {
+        // Arrange
+        let project = example();
+        let path = temp_dir().join("write_and_read.konstruo.ron");
+
+        // Act
+        project.write(&path)?;
+        let result = ProjectInfo::read(&path)?;
+
+        // Assert
+        assert_eq!(result.paths.len(), 2);
+        let path = result.paths.first().expect("should be a path");
+        let expected = project.paths.first().expect("should be a path");
+        assert_eq!(path.spline.get_controls(), expected.spline.get_controls());
+        assert_eq!(
+            path.spline.get_continuities(),
+            expected.spline.get_continuities()
+        );
+        let purposes: Vec<_> = path
+            .surfaces
+            .iter()
+            .map(|surface| surface.purpose)
+            .collect();
+        assert_eq!(
+            purposes,
+            vec![PathSurfaceType::Carriageway, PathSurfaceType::Footway]
+        );
+        let distribution = path
+            .distributions
+            .first()
+            .expect("should be a distribution");
+        assert_eq!(distribution.spline_offset, Some(10.0));
+        assert!(distribution.spline_reversed);
+        let building = distribution
+            .buildings
+            .first()
+            .expect("should be a building");
+        assert_eq!(building.template, "2b4p1s0g");
+        assert!(building.is_mirrored);
+        assert_eq!(building.distributable.order, 3);
+        let fill = distribution.fill.as_ref().expect("should be a fill");
+        assert_eq!(fill.templates, vec![String::from("2b4p1s0g")]);
+        let building = result.buildings.first().expect("should be a building");
+        assert!(building.is_mirrored);
+        assert_eq!(building.transform.translation, Vec3::new(1.0, 2.0, 0.0));
+        let junction = result.junctions.first().expect("should be a junction");
+        let paths: Vec<_> = junction.paths.iter().map(|info| info.path).collect();
+        assert_eq!(paths, vec![0, 1]);
+        assert!((junction.kerb_radius - 2.5).abs() < f32::EPSILON);
+        Ok(())
+    }
+
+    #[test]
+    fn read_invalid_spline() -> Result<(), ProjectError> {
+        // Arrange
+        let path = temp_dir().join("read_invalid_spline.konstruo.ron");
+        let contents =
+            "(paths: [(spline: (curves: []), surfaces: [], distributions: [])], buildings: [])";
+        write(&path, contents).map_err(ProjectError::Io)?;
+
+        // Act
+        let result = ProjectInfo::read(&path);
+
+        // Assert
+        assert!(matches!(result, Err(ProjectError::Deserialize(_))));
+        Ok(())
+    }
+}
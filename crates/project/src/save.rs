@@ -0,0 +1,141 @@
+use crate::*;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use konstruo_architecture::{BuildingFill, BuildingTemplate, ModularBuilding};
+use konstruo_distribution::{Distributable, Distribution};
+use konstruo_paths::{Path, PathIntersection, PathSurface};
+
+/// Queries of the entities that make up a project.
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct ProjectQuery<'w, 's> {
+    paths: Query<'w, 's, (Entity, &'static Path)>,
+    surfaces: Query<'w, 's, (&'static PathSurface, &'static ChildOf)>,
+    junctions: Query<'w, 's, &'static PathIntersection>,
+    distributions: Query<
+        'w,
+        's,
+        (
+            Entity,
+            &'static Distribution,
+            Option<&'static BuildingFill>,
+            &'static ChildOf,
+        ),
+        Without<Distributable>,
+    >,
+    buildings: Query<
+        'w,
+        's,
+        (
+            Option<&'static BuildingTemplate>,
+            &'static Transform,
+            Option<&'static Distributable>,
+            Option<&'static ChildOf>,
+        ),
+        With<ModularBuilding>,
+    >,
+}
+
+impl ProjectInfo {
+    /// System to write the project to a file on [`SaveProject`].
+    pub(super) fn on_save(mut events: MessageReader<SaveProject>, query: ProjectQuery) {
+        for event in events.read() {
+            let project = query.capture();
+            if let Err(e) = project.write(&event.path) {
+                warn!("Failed to save project: {e}");
+                continue;
+            }
+            trace!(
+                "Saved project with {} paths to {}",
+                project.paths.len(),
+                event.path.display()
+            );
+        }
+    }
+}
+
+impl ProjectQuery<'_, '_> {
+    /// Capture the [`Path`], [`PathSurface`], [`PathIntersection`], [`Distribution`] and
+    /// [`ModularBuilding`] entities as a [`ProjectInfo`].
+    #[must_use]
+    pub fn capture(&self) -> ProjectInfo {
+        let mut project = ProjectInfo::default();
+        let mut indices = Vec::new();
+        for (path_entity, path) in self.paths.iter() {
+            let surfaces = self
+                .surfaces
+                .iter()
+                .filter(|(_, child_of)| child_of.parent() == path_entity)
+                .map(|(surface, _)| surface.get_info().clone())
+                .collect();
+            let distributions = self
+                .distributions
+                .iter()
+                .filter(|(_, _, _, child_of)| child_of.parent() == path_entity)
+                .map(|(entity, distribution, fill, _)| {
+                    let buildings = self.capture_distributed_buildings(entity);
+                    DistributionInfo::from_distribution(distribution, buildings, fill.cloned())
+                })
+                .collect();
+            indices.push(path_entity);
+            project.paths.push(PathInfo {
+                spline: path.spline.clone(),
+                surfaces,
+                distributions,
+            });
+        }
+        for junction in self.junctions.iter() {
+            let paths = junction
+                .paths
+                .iter()
+                .filter_map(|info| {
+                    let path = indices.iter().position(|entity| *entity == info.entity)?;
+                    Some(JunctionPathInfo {
+                        path,
+                        surface: info.info.clone(),
+                    })
+                })
+                .collect();
+            project.junctions.push(JunctionInfo {
+                paths,
+                kerb_radius: junction.kerb_radius,
+            });
+        }
+        for (template, transform, distributable, _) in self.buildings.iter() {
+            if distributable.is_some() {
+                continue;
+            }
+            let Some(template) = template else {
+                warn!("Unable to save a building that was not spawned from a template");
+                continue;
+            };
+            project.buildings.push(BuildingInfo {
+                template: template.name.clone(),
+                is_mirrored: template.is_mirrored,
+                transform: *transform,
+            });
+        }
+        project
+    }
+
+    /// Capture the [`ModularBuilding`] distributed by a [`Distribution`].
+    fn capture_distributed_buildings(&self, distribution: Entity) -> Vec<DistributedBuildingInfo> {
+        self.buildings
+            .iter()
+            .filter_map(|(template, _, distributable, child_of)| {
+                if child_of?.parent() != distribution {
+                    return None;
+                }
+                let Some(template) = template else {
+                    warn!("Unable to save a building that was not spawned from a template");
+                    return None;
+                };
+                Some(DistributedBuildingInfo {
+                    template: template.name.clone(),
+                    is_mirrored: template.is_mirrored,
+                    distributable: distributable?.clone(),
+                })
+            })
+            .collect()
+    }
+}
@@ -4,3 +4,4 @@ pub const VIEW_CUBE_CAMERA_ORDER: isize = 3;
 pub const ACTION_BAR_Z: i32 = 2;
 pub const PRESSED_KEYS_Z: i32 = 2;
 pub const INTERCEPTOR_Z: i32 = 1;
+pub const PANEL_Z: i32 = 2;
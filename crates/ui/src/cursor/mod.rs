@@ -1,6 +1,8 @@
 pub use cursor::*;
 pub use gizmo::*;
+pub use snap::*;
 
 #[allow(clippy::module_inception)]
 mod cursor;
 mod gizmo;
+mod snap;
@@ -0,0 +1,479 @@
+use crate::gizmos::Bold;
+use crate::Settings;
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+use konstruo_beziers::constants::INTERSECTION_ACCURACY;
+use konstruo_beziers::CubicBezierSpline;
+use serde::{Deserialize, Serialize};
+use std::f32::consts::PI;
+use SnapKind::*;
+
+/// Distance in metres within which the cursor snaps to a point or spline.
+const SNAP_DISTANCE: f32 = 2.0;
+
+/// Angle in radians within which the cursor snaps to an angle increment.
+const ANGLE_TOLERANCE: f32 = PI / 60.0;
+
+/// Radius of the [`SnapIndicator`] gizmo.
+const INDICATOR_RADIUS: f32 = 0.75;
+
+/// Plugin to snap the cursor and display a [`SnapIndicator`].
+pub struct SnapPlugin;
+
+impl Plugin for SnapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Snapping>()
+            .init_resource::<SnapIndicator>()
+            .add_systems(Update, Snapping::settings_system)
+            .add_systems(Update, SnapIndicator::update_system);
+    }
+}
+
+/// Spacing of the grid intersections to snap to.
+///
+/// Matches the minor, medium and major lines of the grid.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum GridSnap {
+    /// Every 1 metre.
+    #[default]
+    Minor,
+    /// Every 10 metres.
+    Medium,
+    /// Every 100 metres.
+    Major,
+}
+
+/// Settings to snap the cursor to the grid, existing geometry and angle increments.
+#[derive(Clone, Debug, Resource)]
+pub struct Snapping {
+    /// Should the cursor be snapped?
+    pub is_enabled: bool,
+    /// Spacing of the grid intersections to snap to.
+    pub grid: Option<GridSnap>,
+    /// Increment in radians of the angle from the origin to snap to.
+    pub angle: Option<f32>,
+    /// Distance in metres within which the cursor snaps to a point or spline.
+    pub distance: f32,
+}
+
+/// What the cursor was snapped to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnapKind {
+    /// The cursor was not snapped.
+    Free,
+    /// An intersection of the grid.
+    Grid,
+    /// An angle increment from the origin.
+    Angle,
+    /// An existing point such as the start or end of a path.
+    Point,
+    /// The nearest point on an existing spline.
+    Spline,
+}
+
+/// A snapped cursor position.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Snap {
+    pub position: Vec3,
+    pub kind: SnapKind,
+}
+
+/// Existing geometry that the cursor can snap to.
+#[derive(Clone, Debug, Default)]
+pub struct SnapTargets {
+    /// Points such as the start and end of each path.
+    points: Vec<Vec3>,
+    /// Splines such as the center of each path.
+    splines: Vec<CubicBezierSpline>,
+    /// Origin from which angle increments are measured.
+    origin: Option<Vec3>,
+}
+
+/// The last [`Snap`] displayed as a gizmo.
+#[derive(Debug, Default, Resource)]
+pub struct SnapIndicator {
+    snap: Option<Snap>,
+}
+
+impl Default for Snapping {
+    fn default() -> Self {
+        Self {
+            is_enabled: true,
+            grid: Some(GridSnap::default()),
+            angle: Some(PI / 12.0),
+            distance: SNAP_DISTANCE,
+        }
+    }
+}
+
+impl GridSnap {
+    /// Get the spacing in metres.
+    #[must_use]
+    pub fn get_spacing(self) -> f32 {
+        match self {
+            GridSnap::Minor => 1.0,
+            GridSnap::Medium => 10.0,
+            GridSnap::Major => 100.0,
+        }
+    }
+}
+
+impl SnapTargets {
+    /// Add a point to snap to.
+    pub fn add_point(&mut self, point: Vec3) {
+        self.points.push(point);
+    }
+
+    /// Add a spline to snap to.
+    pub fn add_spline(&mut self, spline: CubicBezierSpline) {
+        self.splines.push(spline);
+    }
+
+    /// Set the origin from which angle increments are measured.
+    #[must_use]
+    pub fn with_origin(mut self, origin: Option<Vec3>) -> Self {
+        self.origin = origin;
+        self
+    }
+}
+
+impl Snapping {
+    /// System to update [`Snapping`] when [`Settings`] change.
+    fn settings_system(settings: Res<Settings>, mut snapping: ResMut<Snapping>) {
+        if !settings.is_changed() {
+            return;
+        }
+        snapping.is_enabled = settings.is_snapping_enabled;
+        snapping.grid = Some(settings.grid_snap);
+    }
+
+    /// Snap the cursor.
+    ///
+    /// In order of precedence the cursor snaps to:
+    /// - the nearest point within [`Snapping::distance`]
+    /// - the nearest point on a spline within [`Snapping::distance`]
+    /// - an angle increment from the origin
+    /// - the nearest grid intersection
+    #[must_use]
+    pub fn snap(&self, cursor: Vec3, targets: &SnapTargets) -> Snap {
+        if !self.is_enabled {
+            return Snap::free(cursor);
+        }
+        if let Some(position) = get_nearest(cursor, targets.points.iter().copied(), self.distance) {
+            return Snap {
+                position,
+                kind: Point,
+            };
+        }
+        let on_splines = targets
+            .splines
+            .iter()
+            .map(|spline| get_nearest_on_spline(spline, cursor));
+        if let Some(position) = get_nearest(cursor, on_splines, self.distance) {
+            return Snap {
+                position,
+                kind: Spline,
+            };
+        }
+        if let (Some(origin), Some(increment)) = (targets.origin, self.angle) {
+            if let Some(position) = snap_to_angle(cursor, origin, increment) {
+                return Snap {
+                    position,
+                    kind: Angle,
+                };
+            }
+        }
+        if let Some(grid) = self.grid {
+            let spacing = grid.get_spacing();
+            let position = (cursor / spacing).round() * spacing;
+            return Snap {
+                position: position.with_z(cursor.z),
+                kind: Grid,
+            };
+        }
+        Snap::free(cursor)
+    }
+}
+
+impl Snap {
+    /// Create a [`Snap`] of the unmodified cursor position.
+    #[must_use]
+    pub fn free(position: Vec3) -> Self {
+        Self {
+            position,
+            kind: Free,
+        }
+    }
+}
+
+impl SnapIndicator {
+    /// Set the [`Snap`] to display.
+    ///
+    /// Nothing is displayed if the cursor was not snapped.
+    pub fn set(&mut self, snap: Snap) {
+        self.snap = (snap.kind != Free).then_some(snap);
+    }
+
+    /// Stop displaying the indicator.
+    pub fn clear(&mut self) {
+        self.snap = None;
+    }
+
+    /// System to draw the [`SnapIndicator`] as a gizmo.
+    fn update_system(indicator: Res<SnapIndicator>, mut gizmos: Gizmos<Bold>) {
+        let Some(snap) = indicator.snap else {
+            return;
+        };
+        let color = match snap.kind {
+            Free => return,
+            Grid => tailwind::LIME_500,
+            Angle => tailwind::SKY_500,
+            Point => tailwind::AMBER_500,
+            Spline => tailwind::ORANGE_500,
+        };
+        let isometry = Isometry3d::from_translation(snap.position);
+        gizmos.circle(isometry, INDICATOR_RADIUS, color);
+        if snap.kind == Point {
+            gizmos.circle(isometry, INDICATOR_RADIUS * 0.5, color);
+        }
+    }
+}
+
+/// Get the candidate nearest to the cursor if it's within the distance.
+fn get_nearest(
+    cursor: Vec3,
+    candidates: impl Iterator<Item = Vec3>,
+    distance: f32,
+) -> Option<Vec3> {
+    candidates
+        .map(|candidate| (candidate, candidate.distance(cursor)))
+        .filter(|(_, candidate_distance)| *candidate_distance < distance)
+        .min_by(|(_, a), (_, b)| a.partial_cmp(b).expect("should be able to compare"))
+        .map(|(candidate, _)| candidate)
+}
+
+/// Get the point on the spline nearest to the cursor.
+fn get_nearest_on_spline(spline: &CubicBezierSpline, cursor: Vec3) -> Vec3 {
+    let param = spline.get_param_nearest_to(cursor, INTERSECTION_ACCURACY);
+    if param >= 1.0 {
+        return spline.get_end();
+    }
+    spline.get_point_at_param(param)
+}
+
+/// Rotate the cursor about the origin to the nearest angle increment if it's within
+/// [`ANGLE_TOLERANCE`].
+fn snap_to_angle(cursor: Vec3, origin: Vec3, increment: f32) -> Option<Vec3> {
+    let offset = (cursor - origin).with_z(0.0);
+    let length = offset.length();
+    if length < f32::EPSILON || increment <= 0.0 {
+        return None;
+    }
+    let angle = offset.y.atan2(offset.x);
+    let snapped = (angle / increment).round() * increment;
+    if (angle - snapped).abs() > ANGLE_TOLERANCE {
+        return None;
+    }
+    let direction = Vec3::new(snapped.cos(), snapped.sin(), 0.0);
+    Some(origin.with_z(cursor.z) + direction * length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use konstruo_beziers::CubicBezier;
+    use konstruo_geometry::Line;
+
+    const EPSILON: f32 = 0.001;
+
+    fn line(start: Vec3, end: Vec3) -> CubicBezierSpline {
+        let curve = CubicBezier::from_line(Line::new(start, end)).expect("should be valid");
+        CubicBezierSpline::new(vec![curve]).expect("should be valid")
+    }
+
+    /// Get a point at an angle in degrees and distance from the origin.
+    fn polar(degrees: f32, length: f32) -> Vec3 {
+        let angle = degrees.to_radians();
+        Vec3::new(angle.cos(), angle.sin(), 0.0) * length
+    }
+
+    #[test]
+    fn snap_disabled() {
+        // Arrange
+        let snapping = Snapping {
+            is_enabled: false,
+            ..default()
+        };
+        let cursor = Vec3::new(1.4, 2.6, 0.0);
+
+        // Act
+        let result = snapping.snap(cursor, &SnapTargets::default());
+
+        // Assert
+        assert_eq!(result, Snap::free(cursor));
+    }
+
+    #[test]
+    fn snap_to_grid() {
+        // Arrange
+        let snapping = Snapping::default();
+        let cursor = Vec3::new(1.4, 2.6, 0.3);
+
+        // Act
+        let result = snapping.snap(cursor, &SnapTargets::default());
+
+        // Assert
+        assert_eq!(result.kind, Grid);
+        assert_eq!(result.position, Vec3::new(1.0, 3.0, 0.3));
+    }
+
+    #[test]
+    fn snap_to_major_grid() {
+        // Arrange
+        let snapping = Snapping {
+            grid: Some(GridSnap::Major),
+            ..default()
+        };
+        let cursor = Vec3::new(149.0, -160.0, 0.0);
+
+        // Act
+        let result = snapping.snap(cursor, &SnapTargets::default());
+
+        // Assert
+        assert_eq!(result.kind, Grid);
+        assert_eq!(result.position, Vec3::new(100.0, -200.0, 0.0));
+    }
+
+    #[test]
+    fn snap_without_grid() {
+        // Arrange
+        let snapping = Snapping {
+            grid: None,
+            ..default()
+        };
+        let cursor = Vec3::new(1.4, 2.6, 0.0);
+
+        // Act
+        let result = snapping.snap(cursor, &SnapTargets::default());
+
+        // Assert
+        assert_eq!(result, Snap::free(cursor));
+    }
+
+    #[test]
+    fn snap_to_angle_within_tolerance() {
+        // Arrange
+        let snapping = Snapping::default();
+        let targets = SnapTargets::default().with_origin(Some(Vec3::ZERO));
+        let cursor = polar(14.0, 10.0);
+
+        // Act
+        let result = snapping.snap(cursor, &targets);
+
+        // Assert
+        assert_eq!(result.kind, Angle);
+        assert!(result.position.abs_diff_eq(polar(15.0, 10.0), EPSILON));
+    }
+
+    #[test]
+    fn snap_to_angle_outside_tolerance() {
+        // Arrange
+        let snapping = Snapping::default();
+        let targets = SnapTargets::default().with_origin(Some(Vec3::ZERO));
+        let cursor = polar(10.0, 10.0);
+
+        // Act
+        let result = snapping.snap(cursor, &targets);
+
+        // Assert
+        assert_eq!(result.kind, Grid);
+        assert_eq!(result.position, Vec3::new(10.0, 2.0, 0.0));
+    }
+
+    #[test]
+    fn snap_to_nearest_point() {
+        // Arrange
+        let snapping = Snapping::default();
+        let mut targets = SnapTargets::default();
+        targets.add_point(Vec3::new(5.0, 1.5, 0.0));
+        targets.add_point(Vec3::new(5.0, 0.5, 0.0));
+        targets.add_point(Vec3::new(50.0, 0.0, 0.0));
+        let cursor = Vec3::new(5.2, 0.2, 0.0);
+
+        // Act
+        let result = snapping.snap(cursor, &targets);
+
+        // Assert
+        assert_eq!(result.kind, Point);
+        assert_eq!(result.position, Vec3::new(5.0, 0.5, 0.0));
+    }
+
+    #[test]
+    fn snap_to_spline() {
+        // Arrange
+        let snapping = Snapping::default();
+        let mut targets = SnapTargets::default();
+        targets.add_point(Vec3::new(50.0, 0.0, 0.0));
+        targets.add_spline(line(Vec3::ZERO, Vec3::new(10.0, 0.0, 0.0)));
+        let cursor = Vec3::new(4.3, 1.0, 0.0);
+
+        // Act
+        let result = snapping.snap(cursor, &targets);
+
+        // Assert
+        assert_eq!(result.kind, Spline);
+        assert!(result
+            .position
+            .abs_diff_eq(Vec3::new(4.3, 0.0, 0.0), EPSILON));
+    }
+
+    #[test]
+    fn snap_precedence() {
+        // Arrange
+        let snapping = Snapping::default();
+        let point = Vec3::new(5.0, 1.5, 0.0);
+        let spline = line(Vec3::ZERO, Vec3::new(10.0, 0.0, 0.0));
+        let cursor = polar(14.0, 5.0);
+        let mut all = SnapTargets::default().with_origin(Some(Vec3::ZERO));
+        all.add_point(point);
+        all.add_spline(spline.clone());
+        let mut without_point = SnapTargets::default().with_origin(Some(Vec3::ZERO));
+        without_point.add_spline(spline);
+        let without_spline = SnapTargets::default().with_origin(Some(Vec3::ZERO));
+
+        // Act
+        let point_result = snapping.snap(cursor, &all);
+        let spline_result = snapping.snap(cursor, &without_point);
+        let angle_result = snapping.snap(cursor, &without_spline);
+        let grid_result = snapping.snap(cursor, &SnapTargets::default());
+
+        // Assert
+        assert_eq!(point_result.kind, Point);
+        assert_eq!(spline_result.kind, Spline);
+        assert_eq!(angle_result.kind, Angle);
+        assert_eq!(grid_result.kind, Grid);
+    }
+
+    #[test]
+    fn settings_system() {
+        // Arrange
+        let mut world = World::new();
+        world.insert_resource(Settings {
+            is_snapping_enabled: false,
+            grid_snap: GridSnap::Major,
+            ..default()
+        });
+        world.init_resource::<Snapping>();
+
+        // Act
+        world
+            .run_system_once(Snapping::settings_system)
+            .expect("system should run");
+
+        // Assert
+        let snapping = world.resource::<Snapping>();
+        assert!(!snapping.is_enabled);
+        assert_eq!(snapping.grid, Some(GridSnap::Major));
+    }
+}
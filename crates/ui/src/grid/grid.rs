@@ -1,4 +1,5 @@
 use super::*;
+use crate::Settings;
 use bevy::prelude::*;
 use konstruo_core::constants::{GRID_ELEVATION, GRID_MAX};
 use konstruo_geometry::LineList;
@@ -45,6 +46,27 @@ impl Grid {
         commands.spawn(medium);
         commands.spawn(major);
     }
+
+    /// System to show or hide the [`Grid`] when [`Settings`] change.
+    pub(super) fn settings_system(
+        settings: Res<Settings>,
+        mut grids: Query<(Ref<Grid>, &mut Visibility)>,
+    ) {
+        for (grid, mut visibility) in &mut grids {
+            if !settings.is_changed() && !grid.is_added() {
+                continue;
+            }
+            let is_visible = match *grid {
+                Grid::Minor => settings.is_grid_visible && settings.is_minor_grid_visible,
+                Grid::Medium | Grid::Major => settings.is_grid_visible,
+            };
+            *visibility = if is_visible {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
 }
 
 #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
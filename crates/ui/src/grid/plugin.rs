@@ -7,6 +7,7 @@ pub struct GridPlugin;
 impl Plugin for GridPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, GridMaterials::startup_system)
-            .add_systems(PostStartup, Grid::startup_system);
+            .add_systems(PostStartup, Grid::startup_system)
+            .add_systems(Update, Grid::settings_system);
     }
 }
@@ -7,6 +7,7 @@ pub use observer_marker::*;
 pub use pan_orbit::*;
 pub use pressed::*;
 pub use selection::*;
+pub use settings::*;
 pub use view_cube::*;
 
 mod axis_marker;
@@ -18,4 +19,5 @@ mod observer_marker;
 mod pan_orbit;
 mod pressed;
 mod selection;
+mod settings;
 mod view_cube;
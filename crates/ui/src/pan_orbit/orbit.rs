@@ -1,4 +1,4 @@
-use crate::{PrimaryCamera, PRIMARY_CAMERA_ORDER};
+use crate::{PrimaryCamera, Settings, PRIMARY_CAMERA_ORDER};
 use bevy::input::mouse::MouseMotion;
 use bevy::prelude::*;
 use konstruo_core::constants::{CAMERA_MAX, CAMERA_MIN};
@@ -9,9 +9,6 @@ use konstruo_geometry::Orientation;
 use konstruo_geometry::Orientation::{Bottom, Top};
 use konstruo_geometry::SphericalCoordinates;
 
-pub(super) const DEFAULT_RADIUS: f32 = 250.0;
-pub(super) const SPEED_MODIFIER: f32 = 2.0;
-
 /// 3D orbital translation of the [`PanOrbitCameraPlugin`] around an origin.
 ///
 /// The [`Orbit`] entity is a child of the [`Pan`] entity.
@@ -28,28 +25,29 @@ pub struct Orbit {
     pub(super) dragging: bool,
 }
 
-impl Default for Orbit {
-    fn default() -> Self {
-        Self {
+impl Orbit {
+    /// Create an [`Orbit`] at the camera distance and speeds of the [`Settings`].
+    pub(super) fn new(settings: &Settings) -> Self {
+        let mut orbit = Self {
             translation: Translation {
-                current: Vec3::new(DEFAULT_RADIUS, 0.0, -HALF_PI),
+                current: Vec3::new(settings.camera_distance, 0.0, -HALF_PI),
                 clamp: ClampVec3 {
                     x: ClampFloat::Fixed(CAMERA_MIN, CAMERA_MAX),
                     y: ClampFloat::Fixed(0.0, PI),
                     z: ClampFloat::Wrapped(TWO_PI),
                 },
                 target: None,
-                speed: Vec3::new(DEFAULT_RADIUS * SPEED_MODIFIER, HALF_PI, PI),
+                speed: Vec3::ZERO,
             },
             dragging: false,
-        }
+        };
+        orbit.update_speed(settings);
+        orbit
     }
-}
 
-impl Orbit {
     /// Create an [`Orbit`] with [`Camera`].
-    pub(super) fn bundle() -> impl Bundle {
-        let orbit = Orbit::default();
+    pub(super) fn bundle(settings: &Settings) -> impl Bundle {
+        let orbit = Orbit::new(settings);
         let transform = orbit.get_cartesian_transform();
         (
             PrimaryCamera,
@@ -62,6 +60,7 @@ impl Orbit {
             },
         )
     }
+
     /// Distance from the origin in metres.
     #[must_use]
     pub fn get_spherical_coordinates(&self) -> SphericalCoordinates {
@@ -1,17 +1,31 @@
 use super::*;
-use crate::pan_orbit::orbit::SPEED_MODIFIER;
-use bevy::prelude::{Query, Transform};
+use crate::Settings;
+use bevy::prelude::{DetectChanges, Query, Res, Transform, Vec3};
+use konstruo_core::{HALF_PI, PI};
 
 impl Orbit {
     /// System to update [`Orbit`] once per frame.
-    pub fn update_system(mut query: Query<(&mut Orbit, &mut Transform)>) {
+    ///
+    /// The speed is updated according to the distance from the origin and [`Settings`].
+    pub fn update_system(settings: Res<Settings>, mut query: Query<(&mut Orbit, &mut Transform)>) {
         for (mut orbit, mut transform) in &mut query {
             if orbit.translation.is_update_required() {
                 orbit.translation.update();
-                let radius = orbit.get_spherical_coordinates().get_radius();
-                orbit.translation.speed.x = radius * SPEED_MODIFIER;
+                orbit.update_speed(&settings);
                 *transform = orbit.get_cartesian_transform();
+            } else if settings.is_changed() {
+                orbit.update_speed(&settings);
             }
         }
     }
+
+    /// Update the speed according to the distance from the origin and [`Settings`].
+    pub(super) fn update_speed(&mut self, settings: &Settings) {
+        let radius = self.get_spherical_coordinates().get_radius();
+        self.translation.speed = Vec3::new(
+            radius * settings.zoom_speed,
+            HALF_PI * settings.orbit_speed,
+            PI * settings.orbit_speed,
+        );
+    }
 }
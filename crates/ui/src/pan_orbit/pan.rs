@@ -1,10 +1,8 @@
-use crate::pan_orbit::orbit::DEFAULT_RADIUS;
+use crate::Settings;
 use bevy::prelude::*;
 use konstruo_core::constants::CAMERA_MAX;
 use konstruo_core::{ClampFloat, ClampVec3, Translation};
 
-pub(super) const SPEED_MODIFIER: f32 = 2.0;
-
 /// 2D translation of the [`PanOrbitCameraPlugin`] on the XY plane.
 ///
 /// The [`Orbit`] entity is a child of the [`Pan`] entity.
@@ -24,8 +22,9 @@ pub struct Pan {
     pub(super) dragging: Option<Vec3>,
 }
 
-impl Default for Pan {
-    fn default() -> Self {
+impl Pan {
+    /// Create a [`Pan`] with the speed of the [`Settings`] at the camera distance.
+    pub(super) fn new(settings: &Settings) -> Self {
         Self {
             translation: Translation {
                 current: Vec3::ZERO,
@@ -35,17 +34,15 @@ impl Default for Pan {
                     z: ClampFloat::Fixed(-CAMERA_MAX, CAMERA_MAX),
                 },
                 target: None,
-                speed: Vec3::splat(DEFAULT_RADIUS * SPEED_MODIFIER),
+                speed: Vec3::splat(settings.camera_distance * settings.pan_speed),
             },
             dragging: None,
         }
     }
-}
 
-impl Pan {
     /// Create a [`Pan`].
-    pub(super) fn bundle() -> impl Bundle {
-        let pan = Pan::default();
+    pub(super) fn bundle(settings: &Settings) -> impl Bundle {
+        let pan = Pan::new(settings);
         let transform = pan.get_transform();
         (pan, transform)
     }
@@ -1,5 +1,5 @@
 use super::*;
-use crate::pan_orbit::orbit::SPEED_MODIFIER;
+use crate::Settings;
 use bevy::prelude::*;
 
 impl Pan {
@@ -13,21 +13,25 @@ impl Pan {
         }
     }
 
-    /// System to update [`Pan`] speed when the orbit changes
+    /// System to update [`Pan`] speed when the orbit or [`Settings`] change
     ///
     /// Note `Changed` is also triggered by `&mut T` mutable borrows
     /// - <https://bevy-cheatbook.github.io/programming/change-detection.html#what-gets-detected>
     pub(super) fn orbit_changed_system(
         mut query: Query<&mut Pan>,
-        orbit: Query<&Orbit, Changed<Orbit>>,
+        orbit: Query<Ref<Orbit>>,
+        settings: Res<Settings>,
     ) {
         let Ok(orbit) = orbit.single() else {
             return;
         };
+        if !orbit.is_changed() && !settings.is_changed() {
+            return;
+        }
         // trace!("Updating Pan as Orbit has changed");
         for mut pan in &mut query {
             let radius = orbit.get_spherical_coordinates().get_radius();
-            let speed = radius * SPEED_MODIFIER;
+            let speed = radius * settings.pan_speed;
             pan.translation.speed = Vec3::splat(speed);
         }
     }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::Settings;
 use bevy::prelude::*;
 
 /// Plugin to enable the creation of [`Camera3d`] that is controller by [`Pan`] and [`Orbit`].
@@ -21,6 +22,8 @@ impl Plugin for PanOrbitCameraPlugin {
 }
 
 /// System to spawn [`Pan`] and [`Orbit`] with a [`Camera`].
-fn startup_system(mut commands: Commands) {
-    commands.spawn(Pan::bundle()).with_child(Orbit::bundle());
+fn startup_system(mut commands: Commands, settings: Res<Settings>) {
+    commands
+        .spawn(Pan::bundle(&settings))
+        .with_child(Orbit::bundle(&settings));
 }
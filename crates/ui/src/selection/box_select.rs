@@ -0,0 +1,184 @@
+use crate::*;
+use bevy::camera::primitives::Aabb;
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+use konstruo_core::{AncestryExtensions, HandleError};
+use std::collections::HashSet;
+use KeyCode::{ShiftLeft, ShiftRight};
+
+const FRAME_COLOR: Srgba = tailwind::SKY_400;
+
+/// Select every [`Selectable`] within a rectangle dragged across the ground in screen space.
+///
+/// If shift is held then the entities are added to the current selection.
+#[derive(Debug, Resource)]
+pub struct BoxSelect {
+    /// Can a rectangle be started?
+    ///
+    /// Disabled by modes that handle drags across the ground themselves.
+    pub is_enabled: bool,
+    /// Viewport position where the drag started.
+    start: Option<Vec2>,
+    /// Viewport position of the pointer.
+    end: Vec2,
+}
+
+/// A UI frame displaying the rectangle of a [`BoxSelect`].
+#[derive(Component)]
+pub struct BoxSelectFrame;
+
+impl Default for BoxSelect {
+    fn default() -> Self {
+        Self {
+            is_enabled: true,
+            start: None,
+            end: Vec2::ZERO,
+        }
+    }
+}
+
+impl BoxSelect {
+    /// Is a rectangle being dragged?
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.start.is_some()
+    }
+
+    /// Get the rectangle in viewport coordinates.
+    fn get_rect(&self) -> Option<Rect> {
+        Some(Rect::from_corners(self.start?, self.end))
+    }
+
+    /// Start the rectangle when a drag starts on the ground if [`BoxSelect::is_enabled`].
+    pub(super) fn on_drag_start(
+        trigger: On<Pointer<DragStart>>,
+        mut box_select: ResMut<BoxSelect>,
+    ) {
+        if trigger.button != PointerButton::Primary || !box_select.is_enabled {
+            return;
+        }
+        let position = trigger.pointer_location.position;
+        box_select.start = Some(position);
+        box_select.end = position;
+    }
+
+    /// Update the rectangle when the pointer is dragged.
+    pub(super) fn on_drag(trigger: On<Pointer<Drag>>, mut box_select: ResMut<BoxSelect>) {
+        if trigger.button != PointerButton::Primary || !box_select.is_active() {
+            return;
+        }
+        box_select.end = trigger.pointer_location.position;
+    }
+
+    /// Select every [`Selectable`] with the center of its bounds inside the rectangle when
+    /// the drag ends.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub(super) fn on_drag_end(
+        trigger: On<Pointer<DragEnd>>,
+        mut box_select: ResMut<BoxSelect>,
+        keys: Res<ButtonInput<KeyCode>>,
+        camera: Query<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+        selectables: Query<(Entity, &Selectable, &GlobalTransform, Option<&Aabb>)>,
+        ancestors: Query<Option<&ChildOf>>,
+        mut states: Query<(Entity, &mut EntityState)>,
+        mut changed: MessageWriter<EntityStateChanged>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        box_select.end = trigger.pointer_location.position;
+        let Some(rect) = box_select.get_rect() else {
+            return;
+        };
+        box_select.start = None;
+        let Some((camera, camera_transform)) = camera
+            .single()
+            .handle_error(|e| warn!("Failed to get PrimaryCamera: {e}"))
+        else {
+            return;
+        };
+        let mut selected = HashSet::new();
+        for (entity, selectable, transform, aabb) in selectables.iter() {
+            let center = aabb.map_or(Vec3::ZERO, |aabb| Vec3::from(aabb.center));
+            let Ok(position) =
+                camera.world_to_viewport(camera_transform, transform.transform_point(center))
+            else {
+                continue;
+            };
+            if !rect.contains(position) {
+                continue;
+            }
+            if let Ok(ancestor) = entity.get_ancestor(&ancestors, selectable.get_generation()) {
+                selected.insert(ancestor);
+            }
+        }
+        let is_additive = keys.any_pressed([ShiftLeft, ShiftRight]);
+        trace!("Box selected {} entities", selected.len());
+        for (entity, mut state) in &mut states {
+            let is_selected = selected.contains(&entity);
+            let target = if is_selected {
+                EntityState::Selected
+            } else if !is_additive && *state == EntityState::Selected {
+                EntityState::Default
+            } else {
+                continue;
+            };
+            if *state == target {
+                continue;
+            }
+            *state = target;
+            changed.write(EntityStateChanged {
+                entity,
+                state: target,
+            });
+        }
+    }
+
+    /// System to spawn, update or despawn the [`BoxSelectFrame`].
+    pub(super) fn update_system(
+        mut commands: Commands,
+        box_select: Res<BoxSelect>,
+        mut frames: Query<(Entity, &mut Node), With<BoxSelectFrame>>,
+        camera: Query<Entity, With<PrimaryCamera>>,
+    ) {
+        if !box_select.is_changed() {
+            return;
+        }
+        let Some(rect) = box_select.get_rect() else {
+            for (entity, _) in frames.iter() {
+                commands.entity(entity).despawn();
+            }
+            return;
+        };
+        if let Ok((_, mut node)) = frames.single_mut() {
+            set_node_rect(&mut node, rect);
+            return;
+        }
+        let Ok(camera) = camera.single() else {
+            warn!("Failed to get PrimaryCamera");
+            return;
+        };
+        let mut node = Node {
+            position_type: PositionType::Absolute,
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        };
+        set_node_rect(&mut node, rect);
+        commands.spawn((
+            BoxSelectFrame,
+            node,
+            BorderColor::from(FRAME_COLOR),
+            BackgroundColor::from(FRAME_COLOR.with_alpha(0.1)),
+            UiTargetCamera(camera),
+            Pickable::IGNORE,
+        ));
+    }
+}
+
+/// Position and size a [`Node`] to the rectangle.
+fn set_node_rect(node: &mut Node, rect: Rect) {
+    node.left = Val::Px(rect.min.x);
+    node.top = Val::Px(rect.min.y);
+    node.width = Val::Px(rect.width());
+    node.height = Val::Px(rect.height());
+}
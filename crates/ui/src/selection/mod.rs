@@ -1,8 +1,10 @@
+pub use box_select::*;
 pub use entity_state::*;
 pub use on_entity_state::*;
 pub use plugin::*;
 pub use selectable::*;
 
+mod box_select;
 mod entity_state;
 mod on_entity_state;
 mod plugin;
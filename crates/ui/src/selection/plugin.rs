@@ -2,13 +2,19 @@ use super::*;
 use bevy::prelude::*;
 
 /// Plugin to handle entity selection.
+///
+/// - Click to select
+/// - `Shift + Click` to toggle the selection of an entity
+/// - Drag across the ground to box select, with `Shift` to add to the selection
 pub struct SelectionPlugin;
 
 impl Plugin for SelectionPlugin {
     fn build(&self, app: &mut App) {
         app.add_message::<EntityStateChanged>()
+            .init_resource::<BoxSelect>()
             .add_systems(PostStartup, Selectable::startup_system)
             .add_systems(Update, Selectable::added_system)
-            .add_systems(Update, OnEntityState::on_state_changed);
+            .add_systems(Update, OnEntityState::on_state_changed)
+            .add_systems(Update, BoxSelect::update_system);
     }
 }
@@ -1,7 +1,8 @@
-use crate::{EntityState, EntityStateChanged};
+use crate::{BoxSelect, EntityState, EntityStateChanged};
 use bevy::prelude::*;
 use konstruo_core::{AncestryExtensions, EntityExtensions, HandleError};
 use konstruo_environment::Ground;
+use KeyCode::{ShiftLeft, ShiftRight};
 
 #[derive(Component)]
 pub struct Selectable {
@@ -16,7 +17,12 @@ impl Selectable {
         Self { generation }
     }
 
-    /// System to create [`Observer`] when [`Selectable`] is added.
+    /// Get the number of levels above in the ancestry.
+    pub(super) fn get_generation(&self) -> usize {
+        self.generation
+    }
+
+    /// System to create [`Observer`] on the [`Ground`] to deselect or box select.
     pub fn startup_system(mut commands: Commands, grounds: Query<Entity, With<Ground>>) {
         let Some(entity) = grounds
             .single()
@@ -25,6 +31,9 @@ impl Selectable {
             return;
         };
         commands.spawn(Observer::new(ground_on_pointer_click).with_entity(entity));
+        commands.spawn(Observer::new(BoxSelect::on_drag_start).with_entity(entity));
+        commands.spawn(Observer::new(BoxSelect::on_drag).with_entity(entity));
+        commands.spawn(Observer::new(BoxSelect::on_drag_end).with_entity(entity));
     }
 
     /// System to create [`Observer`] when [`Selectable`] is added.
@@ -104,6 +113,9 @@ fn on_pointer_out(
     }
 }
 
+/// Select the entity on click.
+///
+/// If shift is held then the entity is toggled without changing the selection of others.
 fn on_pointer_click(
     trigger: On<Pointer<Click>>,
     selectables: Query<&Selectable>,
@@ -111,6 +123,7 @@ fn on_pointer_click(
     mut states: Query<(Entity, &mut EntityState)>,
     mut changed: MessageWriter<EntityStateChanged>,
     names: Query<&Name>,
+    keys: Res<ButtonInput<KeyCode>>,
 ) {
     if trigger.button != PointerButton::Primary {
         return;
@@ -123,6 +136,21 @@ fn on_pointer_click(
     ) else {
         return;
     };
+    if keys.any_pressed([ShiftLeft, ShiftRight]) {
+        let toggled = if *state == EntityState::Selected {
+            trace!("De-selected `{}`", ancestor.id_with_name(&names));
+            EntityState::Default
+        } else {
+            trace!("Selected `{}`", ancestor.id_with_name(&names));
+            EntityState::Selected
+        };
+        *state = toggled;
+        changed.write(EntityStateChanged {
+            entity: ancestor,
+            state: toggled,
+        });
+        return;
+    }
     if *state != EntityState::Selected {
         *state = EntityState::Selected;
         trace!("Selected `{}`", ancestor.id_with_name(&names));
@@ -146,15 +174,21 @@ fn on_pointer_click(
     }
 }
 
+/// Deselect every entity on click unless shift is held or a box is being dragged.
 fn ground_on_pointer_click(
     trigger: On<Pointer<Click>>,
     mut states: Query<(Entity, &mut EntityState)>,
     mut changed: MessageWriter<EntityStateChanged>,
     names: Query<&Name>,
+    keys: Res<ButtonInput<KeyCode>>,
+    box_select: Res<BoxSelect>,
 ) {
     if trigger.button != PointerButton::Primary {
         return;
     }
+    if keys.any_pressed([ShiftLeft, ShiftRight]) || box_select.is_active() {
+        return;
+    }
     for (entity, mut state) in &mut states {
         if *state != EntityState::Selected {
             continue;
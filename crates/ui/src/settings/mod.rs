@@ -0,0 +1,6 @@
+pub use plugin::*;
+pub use settings::*;
+
+mod plugin;
+#[allow(clippy::module_inception)]
+mod settings;
@@ -0,0 +1,32 @@
+use super::*;
+use bevy::light::DirectionalLightShadowMap;
+use bevy::prelude::*;
+use konstruo_environment::Sun;
+
+/// Plugin to restore [`Settings`] on startup and save them when they change.
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Settings::load())
+            .add_systems(Update, Settings::shadows_system)
+            .add_systems(PostUpdate, Settings::save_system);
+    }
+}
+
+impl Settings {
+    /// System to update the shadows of the [`Sun`] when [`Settings`] change.
+    fn shadows_system(
+        settings: Res<Settings>,
+        mut shadow_map: ResMut<DirectionalLightShadowMap>,
+        mut lights: Query<&mut DirectionalLight, With<Sun>>,
+    ) {
+        if !settings.is_changed() {
+            return;
+        }
+        shadow_map.size = settings.shadow_map_size;
+        for mut light in &mut lights {
+            light.shadows_enabled = settings.is_shadows_enabled;
+        }
+    }
+}
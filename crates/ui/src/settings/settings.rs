@@ -0,0 +1,294 @@
+use crate::GridSnap;
+use bevy::prelude::*;
+use ron::error::SpannedError;
+use ron::ser::{to_string_pretty, PrettyConfig};
+use serde::{Deserialize, Serialize};
+use std::fs::{read_to_string, write};
+use std::io::{Error as IoError, ErrorKind};
+use std::path::Path as FilePath;
+use Units::*;
+
+/// Path of the file [`Settings`] are saved to.
+pub const SETTINGS_PATH: &str = "settings.konstruo.ron";
+
+/// Minimum width and height in pixels of the shadow map.
+pub const MIN_SHADOW_MAP_SIZE: usize = 512;
+
+/// Maximum width and height in pixels of the shadow map.
+pub const MAX_SHADOW_MAP_SIZE: usize = 8192;
+
+/// Feet in one metre.
+const FEET_PER_METRE: f32 = 3.280_84;
+
+/// User preferences that can be changed at runtime.
+///
+/// Settings are restored from [`SETTINGS_PATH`] on startup and saved whenever they change.
+#[derive(Clone, Debug, Deserialize, PartialEq, Resource, Serialize)]
+#[serde(default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct Settings {
+    /// Speed of panning relative to the distance of the camera from its origin.
+    pub pan_speed: f32,
+    /// Speed of zooming relative to the distance of the camera from its origin.
+    pub zoom_speed: f32,
+    /// Multiplier of the speed the camera orbits around its origin.
+    pub orbit_speed: f32,
+    /// Distance in metres of the camera from its origin on startup.
+    pub camera_distance: f32,
+    /// Should the grid be displayed?
+    pub is_grid_visible: bool,
+    /// Should the minor 1 metre lines of the grid be displayed?
+    pub is_minor_grid_visible: bool,
+    /// Should the wireframe of each surface be displayed for debugging?
+    pub is_wireframe_visible: bool,
+    /// Should the sun cast shadows?
+    pub is_shadows_enabled: bool,
+    /// Width and height in pixels of the shadow map.
+    ///
+    /// Must be a power of two from [`MIN_SHADOW_MAP_SIZE`] to [`MAX_SHADOW_MAP_SIZE`].
+    pub shadow_map_size: usize,
+    /// Should the cursor be snapped?
+    pub is_snapping_enabled: bool,
+    /// Spacing of the grid intersections the cursor snaps to.
+    pub grid_snap: GridSnap,
+    /// Units to display lengths in.
+    pub units: Units,
+    /// Path of the file to import paths from as SVG.
+    pub svg_path: String,
+    /// Path of the file to export paths to as SVG.
+    ///
+    /// Must differ from [`Settings::svg_path`] so an export never overwrites the import.
+    pub svg_export_path: String,
+    /// Metres per SVG user unit.
+    pub svg_scale: f32,
+}
+
+/// Units to display lengths in.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub enum Units {
+    #[default]
+    Metric,
+    Imperial,
+}
+
+#[derive(Debug)]
+pub enum SettingsError {
+    Io(IoError),
+    Serialize(ron::Error),
+    Deserialize(SpannedError),
+    ShadowMapSize(usize),
+    SvgScale(f32),
+    SvgExportPath(String),
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            pan_speed: 2.0,
+            zoom_speed: 2.0,
+            orbit_speed: 1.0,
+            camera_distance: 250.0,
+            is_grid_visible: true,
+            is_minor_grid_visible: true,
+            is_wireframe_visible: false,
+            is_shadows_enabled: true,
+            shadow_map_size: 2048,
+            is_snapping_enabled: true,
+            grid_snap: GridSnap::default(),
+            units: Units::default(),
+            svg_path: String::from("paths.svg"),
+            svg_export_path: String::from("paths.export.svg"),
+            svg_scale: 1.0,
+        }
+    }
+}
+
+impl Settings {
+    /// Read [`Settings`] from a RON file.
+    pub fn read(path: &FilePath) -> Result<Self, SettingsError> {
+        let contents = read_to_string(path).map_err(SettingsError::Io)?;
+        let settings: Self = ron::from_str(&contents).map_err(SettingsError::Deserialize)?;
+        settings.validate()?;
+        Ok(settings)
+    }
+
+    /// Check the [`Settings`] can be applied.
+    pub fn validate(&self) -> Result<(), SettingsError> {
+        let size = self.shadow_map_size;
+        if !size.is_power_of_two() || !(MIN_SHADOW_MAP_SIZE..=MAX_SHADOW_MAP_SIZE).contains(&size) {
+            return Err(SettingsError::ShadowMapSize(size));
+        }
+        if !self.svg_scale.is_finite() || self.svg_scale <= 0.0 {
+            return Err(SettingsError::SvgScale(self.svg_scale));
+        }
+        if self.svg_export_path == self.svg_path {
+            return Err(SettingsError::SvgExportPath(self.svg_export_path.clone()));
+        }
+        Ok(())
+    }
+
+    /// Write [`Settings`] to a RON file.
+    pub fn write(&self, path: &FilePath) -> Result<(), SettingsError> {
+        let contents =
+            to_string_pretty(self, PrettyConfig::default()).map_err(SettingsError::Serialize)?;
+        write(path, contents).map_err(SettingsError::Io)
+    }
+
+    /// Read [`Settings`] from [`SETTINGS_PATH`] or use the defaults if they can't be read.
+    #[must_use]
+    pub fn load() -> Self {
+        match Settings::read(FilePath::new(SETTINGS_PATH)) {
+            Ok(settings) => {
+                trace!("Loaded settings from {SETTINGS_PATH}");
+                settings
+            }
+            Err(SettingsError::Io(e)) if e.kind() == ErrorKind::NotFound => Settings::default(),
+            Err(e) => {
+                warn!("Failed to load settings: {e:?}");
+                Settings::default()
+            }
+        }
+    }
+
+    /// System to write [`Settings`] to [`SETTINGS_PATH`] when they change.
+    pub(super) fn save_system(settings: Res<Settings>) {
+        if !settings.is_changed() || settings.is_added() {
+            return;
+        }
+        if let Err(e) = settings.write(FilePath::new(SETTINGS_PATH)) {
+            warn!("Failed to save settings: {e:?}");
+            return;
+        }
+        trace!("Saved settings to {SETTINGS_PATH}");
+    }
+}
+
+impl Units {
+    /// Format a length in metres.
+    #[must_use]
+    pub fn format_length(self, metres: f32) -> String {
+        self.format_dimensions(&[metres])
+    }
+
+    /// Format dimensions in metres such as width by length by height.
+    #[must_use]
+    pub fn format_dimensions(self, metres: &[f32]) -> String {
+        let (factor, symbol) = match self {
+            Metric => (1.0, "m"),
+            Imperial => (FEET_PER_METRE, "ft"),
+        };
+        let values: Vec<String> = metres
+            .iter()
+            .map(|value| format!("{:.2}", value * factor))
+            .collect();
+        format!("{} {symbol}", values.join(" × "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env::temp_dir;
+
+    #[test]
+    fn write_and_read() -> Result<(), SettingsError> {
+        // Arrange
+        let settings = Settings {
+            pan_speed: 1.5,
+            camera_distance: 100.0,
+            is_wireframe_visible: true,
+            shadow_map_size: 4096,
+            is_snapping_enabled: false,
+            grid_snap: GridSnap::Medium,
+            units: Imperial,
+            svg_path: String::from("plan.svg"),
+            svg_export_path: String::from("plan.export.svg"),
+            svg_scale: 0.5,
+            ..default()
+        };
+        let path = temp_dir().join("write_and_read.settings.konstruo.ron");
+
+        // Act
+        settings.write(&path)?;
+        let result = Settings::read(&path)?;
+
+        // Assert
+        assert_eq!(result, settings);
+        Ok(())
+    }
+
+    #[test]
+    fn read_missing_fields() -> Result<(), SettingsError> {
+        // Arrange
+        let path = temp_dir().join("read_missing_fields.settings.konstruo.ron");
+        write(&path, "(zoom_speed: 3.0)").map_err(SettingsError::Io)?;
+
+        // Act
+        let result = Settings::read(&path)?;
+
+        // Assert
+        let expected = Settings {
+            zoom_speed: 3.0,
+            ..default()
+        };
+        assert_eq!(result, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn read_invalid_shadow_map_size() -> Result<(), SettingsError> {
+        // Arrange
+        let path = temp_dir().join("read_invalid_shadow_map_size.settings.konstruo.ron");
+        let sizes = [0, 256, 1000, 16384];
+
+        // Act
+        let results: Vec<_> = sizes
+            .into_iter()
+            .map(|shadow_map_size| {
+                let contents = format!("(shadow_map_size: {shadow_map_size})");
+                write(&path, contents).map_err(SettingsError::Io)?;
+                Ok(Settings::read(&path))
+            })
+            .collect::<Result<_, SettingsError>>()?;
+
+        // Assert
+        for (size, result) in sizes.into_iter().zip(results) {
+            assert!(
+                matches!(result, Err(SettingsError::ShadowMapSize(s)) if s == size),
+                "{size} should be invalid"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn read_same_svg_paths() -> Result<(), SettingsError> {
+        // Arrange
+        let path = temp_dir().join("read_same_svg_paths.settings.konstruo.ron");
+        let contents = r#"(svg_path: "plan.svg", svg_export_path: "plan.svg")"#;
+        write(&path, contents).map_err(SettingsError::Io)?;
+
+        // Act
+        let result = Settings::read(&path);
+
+        // Assert
+        assert!(matches!(result, Err(SettingsError::SvgExportPath(p)) if p == "plan.svg"));
+        Ok(())
+    }
+
+    #[test]
+    fn format_dimensions() {
+        // Arrange
+        let dimensions = [10.0, 2.5, 0.0];
+
+        // Act
+        let metric = Metric.format_dimensions(&dimensions);
+        let imperial = Imperial.format_dimensions(&dimensions);
+        let length = Imperial.format_length(1.0);
+
+        // Assert
+        assert_eq!(metric, "10.00 × 2.50 × 0.00 m");
+        assert_eq!(imperial, "32.81 × 8.20 × 0.00 ft");
+        assert_eq!(length, "3.28 ft");
+    }
+}
@@ -0,0 +1,367 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_architecture::BuildingTemplates;
+use konstruo_paths::PathEdited;
+use konstruo_project::{LoadProject, ProjectInfo, ProjectQuery, RestoreProject};
+use KeyCode::{ControlLeft, ControlRight, KeyY, KeyZ, ShiftLeft, ShiftRight};
+
+/// Maximum number of [`HistoryEntry`] that can be undone.
+const MAX_HISTORY: usize = 100;
+
+/// A reversible editing operation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EditCommand {
+    /// A path was drawn.
+    CreatePath,
+    /// A control of a path was moved.
+    MoveControl,
+    /// Paths were imported from a file.
+    ImportPaths,
+    /// Nodes of a path were inserted or removed, or the path was extended.
+    EditPath,
+    /// Surfaces of a path were added, removed or edited.
+    EditSurfaces,
+    /// Buildings were placed along a path.
+    PlaceBuildings,
+    /// Buildings were translated or rotated.
+    TransformBuildings,
+    /// Buildings were duplicated.
+    DuplicateBuildings,
+    /// Buildings were mirrored.
+    MirrorBuildings,
+    /// The template of a building was changed.
+    EditBuildings,
+    /// An entity was removed.
+    Remove,
+}
+
+/// An [`EditCommand`] with the state of the project before and after it was applied.
+pub struct HistoryEntry {
+    pub command: EditCommand,
+    before: ProjectInfo,
+    after: ProjectInfo,
+}
+
+/// Undo and redo stacks of [`EditCommand`].
+///
+/// Each command is reversed by restoring the [`ProjectInfo`] captured before it was applied,
+/// so the effect of any path splitting or redistribution it caused is reversed too.
+#[derive(Default, Resource)]
+pub struct History {
+    undo: Vec<HistoryEntry>,
+    redo: Vec<HistoryEntry>,
+    /// State of the project after the last [`EditCommand`].
+    current: Option<ProjectInfo>,
+}
+
+/// Request to record an [`EditCommand`] once it has been applied.
+#[derive(Debug, Message)]
+pub struct RecordEdit {
+    pub command: EditCommand,
+}
+
+/// Request to undo the last [`EditCommand`].
+#[derive(Debug, Message)]
+pub struct UndoEdit;
+
+/// Request to redo the last undone [`EditCommand`].
+#[derive(Debug, Message)]
+pub struct RedoEdit;
+
+impl History {
+    /// Record an [`EditCommand`] with the state of the project after it was applied.
+    ///
+    /// Anything that could be redone is discarded.
+    pub fn push(&mut self, command: EditCommand, after: ProjectInfo) {
+        let Some(before) = self.current.replace(after.clone()) else {
+            warn!("Unable to record {command:?} without the prior state of the project");
+            return;
+        };
+        self.redo.clear();
+        self.undo.push(HistoryEntry {
+            command,
+            before,
+            after,
+        });
+        if self.undo.len() > MAX_HISTORY {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Move the last [`EditCommand`] to the redo stack.
+    ///
+    /// Returns the [`ProjectInfo`] to restore.
+    pub fn undo(&mut self) -> Option<ProjectInfo> {
+        let entry = self.undo.pop()?;
+        trace!("Undoing {:?}", entry.command);
+        self.current = Some(entry.before.clone());
+        self.redo.push(entry);
+        self.current.clone()
+    }
+
+    /// Move the last undone [`EditCommand`] back to the undo stack.
+    ///
+    /// Returns the [`ProjectInfo`] to restore.
+    pub fn redo(&mut self) -> Option<ProjectInfo> {
+        let entry = self.redo.pop()?;
+        trace!("Redoing {:?}", entry.command);
+        self.current = Some(entry.after.clone());
+        self.undo.push(entry);
+        self.current.clone()
+    }
+
+    /// Is there an [`EditCommand`] to undo?
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Is there an [`EditCommand`] to redo?
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Remove all entries and the captured state of the project.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.current = None;
+    }
+
+    /// System to capture the project after an [`EditCommand`] has been applied.
+    ///
+    /// A [`PathEdited`] without an explicit [`RecordEdit`] is recorded as
+    /// [`EditCommand::MoveControl`]. Multiple edits in the same frame are recorded as one.
+    ///
+    /// Must run after the commands of the edit have been applied.
+    pub(crate) fn record_system(
+        mut records: MessageReader<RecordEdit>,
+        mut edited: MessageReader<PathEdited>,
+        mut loads: MessageReader<LoadProject>,
+        mut history: ResMut<History>,
+        templates: Res<BuildingTemplates>,
+        query: ProjectQuery,
+    ) {
+        if loads.read().count() > 0 {
+            history.clear();
+        }
+        let command = records.read().map(|event| event.command).next();
+        let is_edited = edited.read().count() > 0;
+        if history.current.is_none() {
+            if templates.is_loaded() {
+                history.current = Some(query.capture());
+            }
+            return;
+        }
+        let Some(command) = command.or(is_edited.then_some(EditCommand::MoveControl)) else {
+            return;
+        };
+        history.push(command, query.capture());
+    }
+
+    /// System to restore the project before the last [`EditCommand`] on [`UndoEdit`].
+    ///
+    /// Every mode and the selection is reset as the entities they refer to are replaced.
+    pub(crate) fn on_undo(
+        mut events: MessageReader<UndoEdit>,
+        mut commands: Commands,
+        mut history: ResMut<History>,
+        mut interface: ResMut<InterfaceState>,
+        mut restore: MessageWriter<RestoreProject>,
+    ) {
+        if events.read().count() == 0 {
+            return;
+        }
+        if !interface.is_idle() {
+            warn!("Unable to undo while in {interface:?}");
+            return;
+        }
+        let Some(project) = history.undo() else {
+            warn!("Nothing to undo");
+            return;
+        };
+        restore.write(RestoreProject { project });
+        reset_modes(&mut commands, &mut interface);
+    }
+
+    /// System to restore the project after the last undone [`EditCommand`] on [`RedoEdit`].
+    ///
+    /// Every mode and the selection is reset as the entities they refer to are replaced.
+    pub(crate) fn on_redo(
+        mut events: MessageReader<RedoEdit>,
+        mut commands: Commands,
+        mut history: ResMut<History>,
+        mut interface: ResMut<InterfaceState>,
+        mut restore: MessageWriter<RestoreProject>,
+    ) {
+        if events.read().count() == 0 {
+            return;
+        }
+        if !interface.is_idle() {
+            warn!("Unable to redo while in {interface:?}");
+            return;
+        }
+        let Some(project) = history.redo() else {
+            warn!("Nothing to redo");
+            return;
+        };
+        restore.write(RestoreProject { project });
+        reset_modes(&mut commands, &mut interface);
+    }
+
+    /// System to write [`UndoEdit`] or [`RedoEdit`] on keyboard shortcuts.
+    ///
+    /// - `Ctrl + Z` to undo
+    /// - `Ctrl + Shift + Z` or `Ctrl + Y` to redo
+    pub(crate) fn shortcut_system(
+        keys: Res<ButtonInput<KeyCode>>,
+        mut undo: MessageWriter<UndoEdit>,
+        mut redo: MessageWriter<RedoEdit>,
+    ) {
+        if !keys.any_pressed([ControlLeft, ControlRight]) {
+            return;
+        }
+        let is_shift = keys.any_pressed([ShiftLeft, ShiftRight]);
+        if keys.just_pressed(KeyZ) {
+            if is_shift {
+                redo.write(RedoEdit);
+            } else {
+                undo.write(UndoEdit);
+            }
+        }
+        if keys.just_pressed(KeyY) {
+            redo.write(RedoEdit);
+        }
+    }
+}
+
+/// Remove the resource of every mode and reset the selection.
+fn reset_modes(commands: &mut Commands, interface: &mut InterfaceState) {
+    commands.remove_resource::<InfoMode>();
+    commands.remove_resource::<SurfacesMode>();
+    commands.remove_resource::<NodesMode>();
+    commands.remove_resource::<AddBuildingsMode>();
+    *interface = InterfaceState::Default;
+}
+
+/// Write [`UndoEdit`] on action button press.
+pub(crate) fn undo_action(trigger: On<Pointer<Release>>, mut undo: MessageWriter<UndoEdit>) {
+    if trigger.button != PointerButton::Primary {
+        return;
+    }
+    trace!("Undo button was pressed.");
+    undo.write(UndoEdit);
+}
+
+/// Write [`RedoEdit`] on action button press.
+pub(crate) fn redo_action(trigger: On<Pointer<Release>>, mut redo: MessageWriter<RedoEdit>) {
+    if trigger.button != PointerButton::Primary {
+        return;
+    }
+    trace!("Redo button was pressed.");
+    redo.write(RedoEdit);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use konstruo_project::BuildingInfo;
+
+    /// Create a [`ProjectInfo`] identified by the template of its only building.
+    fn project(id: usize) -> ProjectInfo {
+        ProjectInfo {
+            buildings: vec![BuildingInfo {
+                template: id.to_string(),
+                is_mirrored: false,
+                transform: Transform::default(),
+            }],
+            ..default()
+        }
+    }
+
+    fn get_id(project: Option<ProjectInfo>) -> Option<String> {
+        project?
+            .buildings
+            .first()
+            .map(|building| building.template.clone())
+    }
+
+    fn history() -> History {
+        History {
+            current: Some(project(0)),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn push_without_current() {
+        // Arrange
+        let mut history = History::default();
+
+        // Act
+        history.push(EditCommand::CreatePath, project(1));
+
+        // Assert
+        assert!(!history.can_undo());
+        assert_eq!(get_id(history.current), Some(String::from("1")));
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        // Arrange
+        let mut history = history();
+        history.push(EditCommand::CreatePath, project(1));
+        history.push(EditCommand::MoveControl, project(2));
+
+        // Act
+        let first = history.undo();
+        let second = history.undo();
+        let third = history.undo();
+        let redone = history.redo();
+
+        // Assert
+        assert_eq!(get_id(first), Some(String::from("1")));
+        assert_eq!(get_id(second), Some(String::from("0")));
+        assert!(third.is_none());
+        assert_eq!(get_id(redone), Some(String::from("1")));
+        assert!(history.can_undo());
+        assert!(history.can_redo());
+    }
+
+    #[test]
+    fn push_clears_redo() {
+        // Arrange
+        let mut history = history();
+        history.push(EditCommand::CreatePath, project(1));
+        let _ = history.undo();
+
+        // Act
+        history.push(EditCommand::PlaceBuildings, project(2));
+
+        // Assert
+        assert!(!history.can_redo());
+        assert!(history.redo().is_none());
+        assert_eq!(get_id(history.undo()), Some(String::from("0")));
+    }
+
+    #[test]
+    fn push_truncates_at_max_history() {
+        // Arrange
+        let mut history = history();
+
+        // Act
+        for id in 1..=MAX_HISTORY + 5 {
+            history.push(EditCommand::MoveControl, project(id));
+        }
+
+        // Assert
+        assert_eq!(history.undo.len(), MAX_HISTORY);
+        let mut oldest = None;
+        while let Some(project) = history.undo() {
+            oldest = Some(project);
+        }
+        assert_eq!(get_id(oldest), Some(String::from("5")));
+        assert_eq!(history.redo.len(), MAX_HISTORY);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::*;
 use bevy::prelude::*;
+use konstruo_paths::Path;
 use konstruo_ui::{PrimaryCamera, ACTION_BAR_Z};
 use FloatingActionButtonSize::{Medium, Small};
 
@@ -30,6 +31,7 @@ impl ActionBar {
         mut commands: Commands,
         state: Res<InterfaceState>,
         assets: Res<AssetServer>,
+        paths: Query<&Path>,
         buttons: Query<Entity, With<FloatingActionButton>>,
         bars: Query<Entity, With<ActionBar>>,
     ) {
@@ -43,7 +45,7 @@ impl ActionBar {
         for entity in buttons.iter() {
             commands.entity(entity).despawn();
         }
-        let actions = state.get_actions();
+        let actions = state.get_actions(&paths);
         spawn_actions(&mut commands, &assets, actions, bar);
     }
 }
@@ -36,7 +36,8 @@ impl Interceptor {
                 });
             })
             .observe(DrawMode::on_pointer_down)
-            .observe(DrawMode::on_pointer_up);
+            .observe(DrawMode::on_pointer_up)
+            .observe(NodesMode::on_pointer_up);
     }
 
     /// System to update the [`Interceptor`] visibility when [`InterfaceState`] is changed.
@@ -57,11 +58,12 @@ impl Interceptor {
             return;
         };
         *visibility = match *interface {
-            InterfaceState::DrawPath => Visibility::Visible,
+            InterfaceState::DrawPath | InterfaceState::EditNodes => Visibility::Visible,
             _ => Visibility::Hidden,
         };
         *text = match *interface {
             InterfaceState::DrawPath => Text::new("Drawing"),
+            InterfaceState::EditNodes => Text::new("Editing nodes"),
             _ => Text::new("Default"),
         };
     }
@@ -3,6 +3,7 @@ pub use bar::*;
 pub use button::*;
 pub use icon::*;
 pub use interceptor::*;
+pub use panel::*;
 pub use state::*;
 
 mod action;
@@ -10,4 +11,5 @@ mod bar;
 mod button;
 mod icon;
 mod interceptor;
+mod panel;
 mod state;
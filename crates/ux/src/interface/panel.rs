@@ -0,0 +1,199 @@
+use bevy::color::palettes::basic::BLACK;
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+use konstruo_ui::{DEFAULT_FONT, PANEL_Z};
+
+const BACKGROUND: Srgba = tailwind::SLATE_200;
+const ENABLED: Srgba = tailwind::SLATE_400;
+const HOVERED: Srgba = tailwind::BLUE_400;
+const ACTIVE: Srgba = tailwind::BLUE_300;
+
+/// A side panel of [`PanelRow`] to display and edit values.
+///
+/// Panels are immutable. When the values change the panel should be despawned and
+/// spawned again.
+#[derive(Component)]
+pub struct Panel;
+
+/// A button of a [`Panel`].
+#[derive(Component)]
+pub struct PanelButton {
+    is_active: bool,
+}
+
+/// A labelled row of a [`Panel`].
+pub struct PanelRow {
+    /// Label describing the row.
+    pub label: String,
+    /// Current value.
+    pub value: Option<String>,
+    /// Buttons to edit the value.
+    pub buttons: Vec<PanelAction>,
+}
+
+/// A button to edit the value of a [`PanelRow`].
+pub struct PanelAction {
+    /// Short label of the button.
+    pub label: String,
+    /// Is the button currently active?
+    pub is_active: bool,
+    /// Observer triggered on button press.
+    pub on_press: Observer,
+}
+
+impl PanelRow {
+    /// Create a [`PanelRow`] with a value and no buttons.
+    #[must_use]
+    pub fn value(label: &str, value: String) -> Self {
+        Self {
+            label: label.to_owned(),
+            value: Some(value),
+            buttons: Vec::new(),
+        }
+    }
+}
+
+impl PanelAction {
+    /// Create a [`PanelAction`].
+    #[must_use]
+    pub fn new(label: &str, on_press: Observer) -> Self {
+        Self {
+            label: label.to_owned(),
+            is_active: false,
+            on_press,
+        }
+    }
+
+    /// Set whether the button is active.
+    #[must_use]
+    pub fn with_active(mut self, is_active: bool) -> Self {
+        self.is_active = is_active;
+        self
+    }
+}
+
+impl Panel {
+    /// Spawn a [`Panel`] to the top left of the camera.
+    #[allow(clippy::must_use_candidate)]
+    pub fn spawn(
+        commands: &mut Commands,
+        assets: &AssetServer,
+        camera: Entity,
+        title: &str,
+        rows: Vec<PanelRow>,
+    ) -> Entity {
+        let font: Handle<Font> = assets.load(DEFAULT_FONT);
+        let panel = commands.spawn(panel_bundle(camera)).id();
+        commands.spawn((text_bundle(title, font.clone(), 18.0), ChildOf(panel)));
+        for row in rows {
+            let row_entity = commands.spawn((row_bundle(), ChildOf(panel))).id();
+            commands.spawn((
+                text_bundle(&row.label, font.clone(), 14.0),
+                ChildOf(row_entity),
+            ));
+            if let Some(value) = row.value {
+                commands.spawn((text_bundle(&value, font.clone(), 14.0), ChildOf(row_entity)));
+            }
+            for button in row.buttons {
+                spawn_button(commands, button, font.clone(), row_entity);
+            }
+        }
+        panel
+    }
+}
+
+fn spawn_button(
+    commands: &mut Commands,
+    button: PanelAction,
+    font: Handle<Font>,
+    parent: Entity,
+) -> Entity {
+    let color = if button.is_active { ACTIVE } else { ENABLED };
+    let entity = commands
+        .spawn((
+            PanelButton {
+                is_active: button.is_active,
+            },
+            Node {
+                padding: UiRect::px(8.0, 8.0, 4.0, 4.0),
+                border_radius: BorderRadius::all(Val::Px(8.0)),
+                ..default()
+            },
+            BackgroundColor(color.into()),
+            ChildOf(parent),
+        ))
+        .observe(on_pointer_over)
+        .observe(on_pointer_out)
+        .with_child(text_bundle(&button.label, font, 14.0))
+        .id();
+    let mut observer = button.on_press;
+    observer.watch_entity(entity);
+    commands.spawn(observer);
+    entity
+}
+
+fn panel_bundle(camera: Entity) -> impl Bundle {
+    (
+        Panel,
+        UiTargetCamera(camera),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(16.0),
+            left: Val::Px(16.0),
+            padding: UiRect::all(Val::Px(16.0)),
+            row_gap: Val::Px(8.0),
+            flex_direction: FlexDirection::Column,
+            border_radius: BorderRadius::all(Val::Px(16.0)),
+            ..default()
+        },
+        BackgroundColor(BACKGROUND.into()),
+        ZIndex(PANEL_Z),
+    )
+}
+
+fn row_bundle() -> impl Bundle {
+    (
+        Node {
+            column_gap: Val::Px(8.0),
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        Pickable::IGNORE,
+    )
+}
+
+fn text_bundle(text: &str, font: Handle<Font>, font_size: f32) -> impl Bundle {
+    (
+        Text::new(text),
+        TextColor::from(BLACK),
+        TextFont {
+            font,
+            font_size,
+            ..default()
+        },
+        Pickable::IGNORE,
+    )
+}
+
+fn on_pointer_over(
+    trigger: On<Pointer<Over>>,
+    mut query: Query<&mut BackgroundColor, With<PanelButton>>,
+) {
+    let Ok(mut bg) = query.get_mut(trigger.original_event_target()) else {
+        error!("Failed to get PanelButton");
+        return;
+    };
+    *bg = BackgroundColor(HOVERED.into());
+}
+
+fn on_pointer_out(
+    trigger: On<Pointer<Out>>,
+    mut query: Query<(&mut BackgroundColor, &PanelButton)>,
+) {
+    let Ok((mut bg, button)) = query.get_mut(trigger.original_event_target()) else {
+        error!("Failed to get PanelButton");
+        return;
+    };
+    let color = if button.is_active { ACTIVE } else { ENABLED };
+    *bg = BackgroundColor(color.into());
+}
@@ -1,57 +1,117 @@
 use crate::*;
 use bevy::prelude::*;
 use konstruo_architecture::ModularBuilding;
+use konstruo_beziers::{CubicBezierSpline, SvgOptions};
 use konstruo_core::HandleError;
-use konstruo_paths::Path;
-use konstruo_ui::{EntityState, EntityStateChanged};
+use konstruo_paths::{
+    Path, PathControl, PathEdited, PathFactory, PathMaterials, PathMeshes, PathSurface,
+};
+use konstruo_project::{ExportGltf, LoadProject, SaveProject, GLTF_PATH, PROJECT_PATH};
+use konstruo_ui::{BoxSelect, EntityState, EntityStateChanged, Settings};
+use std::collections::BTreeSet;
+use std::path::Path as FilePath;
 
 #[derive(Debug, Resource, Default, PartialEq)]
 pub enum InterfaceState {
     #[default]
     Default,
     DrawPath,
-    Selection(SelectionMode, Entity),
+    AddBuildings,
+    EditSurfaces,
+    EditNodes,
+    /// One or more selected entities.
+    Selection(SelectionMode, BTreeSet<Entity>),
 }
 
 impl InterfaceState {
     /// Get the [`Action`] for the current interface state.
+    ///
+    /// A selected [`Path`] can only be extended if its spline is open.
     #[must_use]
-    pub(super) fn get_actions(&self) -> Vec<Action> {
+    pub(super) fn get_actions(&self, paths: &Query<&Path>) -> Vec<Action> {
         match self {
             InterfaceState::Default => default_actions(),
             InterfaceState::DrawPath => DrawMode::actions(),
-            InterfaceState::Selection(mode, _) => mode.actions(),
+            InterfaceState::AddBuildings => AddBuildingsMode::actions(),
+            InterfaceState::EditSurfaces => SurfacesMode::actions(),
+            InterfaceState::EditNodes => NodesMode::actions(),
+            InterfaceState::Selection(mode, entities) => {
+                let is_extendable = entities
+                    .first()
+                    .and_then(|entity| paths.get(*entity).ok())
+                    .is_some_and(|path| !path.spline.is_closed());
+                mode.actions(entities.len(), is_extendable)
+            }
+        }
+    }
+
+    /// Is the interface free of any mode that holds entities or handles the pointer?
+    ///
+    /// Modes such as [`DrawMode`] hold entities that would be despawned if the project
+    /// was replaced.
+    #[must_use]
+    pub(crate) fn is_idle(&self) -> bool {
+        matches!(
+            self,
+            InterfaceState::Default | InterfaceState::Selection(..)
+        )
+    }
+
+    /// System to only enable [`BoxSelect`] while the interface is idle.
+    pub(crate) fn box_select_system(
+        interface: Res<InterfaceState>,
+        mut box_select: ResMut<BoxSelect>,
+    ) {
+        if interface.is_changed() {
+            box_select.is_enabled = interface.is_idle();
         }
     }
 
     /// Update [`InterfaceState`] on [`EntityStateChanged`].
+    ///
+    /// The selection is the set of every entity with [`EntityState::Selected`].
+    /// If nothing is selected then the [`InterfaceState`] is reset.
+    #[allow(clippy::type_complexity)]
     pub(crate) fn on_entity_state_changed(
         mut events: MessageReader<EntityStateChanged>,
         mut interface: ResMut<InterfaceState>,
-        query: Query<(Option<&ModularBuilding>, Option<&Path>)>,
+        query: Query<
+            (
+                Entity,
+                &EntityState,
+                Option<&ModularBuilding>,
+                Option<&Path>,
+            ),
+            Without<PathControl>,
+        >,
     ) {
-        for event in events.read() {
-            if event.state == EntityState::Selected {
-                if event.entity == Entity::PLACEHOLDER {
-                    *interface = InterfaceState::Default;
-                    continue;
-                }
-                let Some((building, path)) = query
-                    .get(event.entity)
-                    .handle_error(|e| warn!("Failed to get entity: {e}"))
-                else {
-                    continue;
-                };
-                let mode = if building.is_some() {
-                    SelectionMode::Building
-                } else if path.is_some() {
-                    SelectionMode::Path
-                } else {
-                    SelectionMode::Default
-                };
-                *interface = InterfaceState::Selection(mode, event.entity);
-            }
+        let count = events
+            .read()
+            .filter(|event| event.state != EntityState::Hovered)
+            .count();
+        if count == 0 {
+            return;
+        }
+        let selected: Vec<_> = query
+            .iter()
+            .filter(|(_, state, _, _)| **state == EntityState::Selected)
+            .collect();
+        if selected.is_empty() {
+            *interface = InterfaceState::Default;
+            return;
         }
+        let mode = if selected
+            .iter()
+            .all(|(_, _, building, _)| building.is_some())
+        {
+            SelectionMode::Building
+        } else if selected.iter().all(|(_, _, _, path)| path.is_some()) {
+            SelectionMode::Path
+        } else {
+            SelectionMode::Default
+        };
+        let entities = selected.iter().map(|(entity, _, _, _)| *entity).collect();
+        interface.set_if_neq(InterfaceState::Selection(mode, entities));
     }
 }
 
@@ -62,6 +122,41 @@ fn default_actions() -> Vec<Action> {
             icon: Icon::font_awesome("cog"),
             on_press: Observer::new(settings_action),
         },
+        Action {
+            label: String::from("Open Project"),
+            icon: Icon::font_awesome("folder-open"),
+            on_press: Observer::new(open_project_action),
+        },
+        Action {
+            label: String::from("Save Project"),
+            icon: Icon::font_awesome("save"),
+            on_press: Observer::new(save_project_action),
+        },
+        Action {
+            label: String::from("Import SVG"),
+            icon: Icon::font_awesome("file-import"),
+            on_press: Observer::new(import_svg_action),
+        },
+        Action {
+            label: String::from("Export SVG"),
+            icon: Icon::font_awesome("file-export"),
+            on_press: Observer::new(export_svg_action),
+        },
+        Action {
+            label: String::from("Export glTF"),
+            icon: Icon::font_awesome("cube"),
+            on_press: Observer::new(export_gltf_action),
+        },
+        Action {
+            label: String::from("Undo"),
+            icon: Icon::font_awesome("undo"),
+            on_press: Observer::new(undo_action),
+        },
+        Action {
+            label: String::from("Redo"),
+            icon: Icon::font_awesome("redo"),
+            on_press: Observer::new(redo_action),
+        },
         Action {
             label: String::from("Draw Path"),
             icon: Icon::font_awesome("bezier-curve"),
@@ -70,10 +165,125 @@ fn default_actions() -> Vec<Action> {
     ]
 }
 
-fn settings_action(trigger: On<Pointer<Release>>) {
+/// Toggle the [`SettingsPanel`] on action button press.
+fn settings_action(
+    trigger: On<Pointer<Release>>,
+    mut commands: Commands,
+    mode: Option<Res<SettingsMode>>,
+) {
     if trigger.button != PointerButton::Primary {
         return;
     }
     trace!("Settings button was pressed.");
-    warn!("Settings was pressed but it is not implemented.");
+    if mode.is_some() {
+        commands.remove_resource::<SettingsMode>();
+    } else {
+        commands.insert_resource(SettingsMode);
+    }
+}
+
+/// Write [`LoadProject`] on action button press.
+fn open_project_action(trigger: On<Pointer<Release>>, mut load: MessageWriter<LoadProject>) {
+    if trigger.button != PointerButton::Primary {
+        return;
+    }
+    trace!("Open Project button was pressed.");
+    load.write(LoadProject {
+        path: PROJECT_PATH.into(),
+    });
+}
+
+/// Write [`SaveProject`] on action button press.
+fn save_project_action(trigger: On<Pointer<Release>>, mut save: MessageWriter<SaveProject>) {
+    if trigger.button != PointerButton::Primary {
+        return;
+    }
+    trace!("Save Project button was pressed.");
+    save.write(SaveProject {
+        path: PROJECT_PATH.into(),
+    });
+}
+
+/// Write [`ExportGltf`] on action button press.
+fn export_gltf_action(trigger: On<Pointer<Release>>, mut export: MessageWriter<ExportGltf>) {
+    if trigger.button != PointerButton::Primary {
+        return;
+    }
+    trace!("Export glTF button was pressed.");
+    export.write(ExportGltf {
+        path: GLTF_PATH.into(),
+    });
+}
+
+/// Spawn a [`Path`] with the default surfaces for each path in [`Settings::svg_path`] on action
+/// button press.
+#[allow(clippy::too_many_arguments)]
+fn import_svg_action(
+    trigger: On<Pointer<Release>>,
+    commands: Commands,
+    meshes: ResMut<Assets<Mesh>>,
+    path_meshes: Res<PathMeshes>,
+    materials: Res<PathMaterials>,
+    mut path_edited: MessageWriter<PathEdited>,
+    mut record: MessageWriter<RecordEdit>,
+    settings: Res<Settings>,
+) {
+    if trigger.button != PointerButton::Primary {
+        return;
+    }
+    trace!("Import SVG button was pressed.");
+    let Some(splines) =
+        CubicBezierSpline::read_svg(FilePath::new(&settings.svg_path), svg_options(&settings))
+            .handle_error(|e| warn!("Failed to import SVG: {e:?}"))
+    else {
+        return;
+    };
+    if splines.is_empty() {
+        warn!("No paths found in {}", settings.svg_path);
+        return;
+    }
+    let mut factory = PathFactory {
+        commands,
+        meshes,
+        path_meshes,
+        materials,
+    };
+    for spline in splines {
+        let path = Path::new(spline);
+        let entity = factory.spawn_path(path.clone());
+        for surface in PathSurface::default_surfaces() {
+            factory.spawn_surface(surface, &path, entity);
+        }
+        path_edited.write(PathEdited { path: entity });
+    }
+    record.write(RecordEdit {
+        command: EditCommand::ImportPaths,
+    });
+}
+
+/// Write every [`Path`] to [`Settings::svg_export_path`] on action button press.
+fn export_svg_action(trigger: On<Pointer<Release>>, paths: Query<&Path>, settings: Res<Settings>) {
+    if trigger.button != PointerButton::Primary {
+        return;
+    }
+    trace!("Export SVG button was pressed.");
+    let splines: Vec<CubicBezierSpline> = paths.iter().map(|path| path.spline.clone()).collect();
+    let path = FilePath::new(&settings.svg_export_path);
+    if let Err(e) = CubicBezierSpline::write_svg(&splines, path, svg_options(&settings)) {
+        warn!("Failed to export SVG: {e:?}");
+        return;
+    }
+    trace!(
+        "Exported {} paths to {}",
+        splines.len(),
+        settings.svg_export_path
+    );
+}
+
+/// Get the [`SvgOptions`] of the [`Settings`].
+fn svg_options(settings: &Settings) -> SvgOptions {
+    SvgOptions {
+        scale: settings.svg_scale,
+        ..default()
+    }
 }
@@ -1,7 +1,9 @@
+pub use history::*;
 pub use interface::*;
 pub use modes::*;
 pub use plugin::*;
 
+mod history;
 mod interface;
 mod modes;
 mod plugin;
@@ -0,0 +1,129 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_architecture::*;
+use konstruo_beziers::constants::LENGTH_ACCURACY;
+use konstruo_core::HandleError;
+use konstruo_distribution::{Distribution, FlexBuilder};
+use konstruo_paths::Path;
+
+impl AddBuildingsMode {
+    /// Get the actions when [`AddBuildingsMode`] is active.
+    pub(crate) fn actions() -> Vec<Action> {
+        vec![
+            Action {
+                label: String::from("Cancel"),
+                icon: Icon::font_awesome("times"),
+                on_press: Observer::new(AddBuildingsMode::cancel_action),
+            },
+            Action {
+                label: String::from("Complete"),
+                icon: Icon::font_awesome("check"),
+                on_press: Observer::new(AddBuildingsMode::complete_action),
+            },
+        ]
+    }
+
+    /// Return to [`SelectionMode::Path`] without adding buildings.
+    fn cancel_action(
+        trigger: On<Pointer<Release>>,
+        mut commands: Commands,
+        mut interface: ResMut<InterfaceState>,
+        mode: Res<AddBuildingsMode>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Cancel button was pressed.");
+        *interface = mode.get_selection();
+        commands.remove_resource::<AddBuildingsMode>();
+    }
+
+    /// Spawn a [`Distribution`] of [`ModularBuilding`] on each side of each [`Path`].
+    #[allow(clippy::too_many_arguments)]
+    fn complete_action(
+        trigger: On<Pointer<Release>>,
+        mut commands: Commands,
+        mut interface: ResMut<InterfaceState>,
+        mode: Res<AddBuildingsMode>,
+        templates: Res<BuildingTemplates>,
+        assets: Res<Assets<ModularBuildingInfo>>,
+        paths: Query<&Path>,
+        mut record: MessageWriter<RecordEdit>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Complete button was pressed.");
+        let buildings: Vec<&str> = mode
+            .selected
+            .iter()
+            .filter(|name| {
+                templates
+                    .get(name, &assets)
+                    .is_some_and(|info| info.get_width() > 0.0)
+            })
+            .map(String::as_str)
+            .collect();
+        if buildings.is_empty() {
+            warn!("Unable to add buildings without any templates selected");
+            return;
+        }
+        for entity in &mode.paths {
+            let Ok(path) = paths.get(*entity) else {
+                warn!("Failed to get Path: {entity:?}");
+                continue;
+            };
+            for (direction, reversed) in mode.side.get_offsets() {
+                spawn_distribution(
+                    &mut commands,
+                    &buildings,
+                    *entity,
+                    path,
+                    mode.setback * direction,
+                    reversed,
+                );
+            }
+        }
+        record.write(RecordEdit {
+            command: EditCommand::PlaceBuildings,
+        });
+        *interface = mode.get_selection();
+        commands.remove_resource::<AddBuildingsMode>();
+    }
+}
+
+/// Spawn a [`Distribution`] along an offset of the [`Path`] filled with buildings.
+///
+/// The [`BuildingFill`] repeats the templates in turn until the length of the offset spline is filled.
+fn spawn_distribution(
+    commands: &mut Commands,
+    templates: &[&str],
+    path_entity: Entity,
+    path: &Path,
+    offset: f32,
+    reversed: bool,
+) -> Option<Entity> {
+    let mut distribution = Distribution {
+        flex: FlexBuilder::new()
+            .with_justify_content(JustifyContent::SpaceAround)
+            .with_align_items_cross(AlignItems::FlexStart)
+            .build(),
+        spline_offset: Some(offset),
+        spline_reversed: reversed,
+        translate_to_ground: true,
+        ..default()
+    };
+    let spline = distribution
+        .get_spline_from_path(&path.spline)
+        .handle_error(|e| warn!("Failed to offset Distribution spline: {e}"))?;
+    let length = spline.get_length(LENGTH_ACCURACY);
+    distribution.flex.bounds = Some(Vec3::new(length, 0.0, 0.0));
+    distribution.spline = Some(spline);
+    let fill = BuildingFill {
+        templates: templates.iter().map(|name| (*name).to_owned()).collect(),
+    };
+    let entity = commands
+        .spawn((distribution, fill, ChildOf(path_entity)))
+        .id();
+    Some(entity)
+}
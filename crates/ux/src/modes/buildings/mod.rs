@@ -0,0 +1,5 @@
+pub use resource::*;
+
+mod actions;
+mod panel;
+mod resource;
@@ -0,0 +1,105 @@
+use super::resource::{MIN_SETBACK, SETBACK_STEP};
+use crate::*;
+use bevy::prelude::*;
+use konstruo_ui::PrimaryCamera;
+
+/// Marker for the [`Panel`] of [`AddBuildingsMode`].
+#[derive(Component)]
+pub struct AddBuildingsPanel;
+
+impl AddBuildingsMode {
+    /// System to respawn the [`AddBuildingsPanel`] when [`AddBuildingsMode`] is changed.
+    pub(crate) fn panel_system(
+        mut commands: Commands,
+        mode: Option<Res<AddBuildingsMode>>,
+        assets: Res<AssetServer>,
+        panels: Query<Entity, With<AddBuildingsPanel>>,
+        cameras: Query<Entity, With<PrimaryCamera>>,
+    ) {
+        let is_changed = mode.as_ref().is_none_or(Res::is_changed);
+        if !is_changed {
+            return;
+        }
+        for entity in panels.iter() {
+            commands.entity(entity).despawn();
+        }
+        let Some(mode) = mode else {
+            return;
+        };
+        let Ok(camera) = cameras.single() else {
+            warn!("Failed to get PrimaryCamera");
+            return;
+        };
+        let panel = Panel::spawn(&mut commands, &assets, camera, "Add Buildings", mode.rows());
+        commands.entity(panel).insert(AddBuildingsPanel);
+    }
+
+    /// Get the [`PanelRow`] to edit the options.
+    fn rows(&self) -> Vec<PanelRow> {
+        let mut rows = vec![
+            PanelRow {
+                label: String::from("Side"),
+                value: None,
+                buttons: [PathSide::Left, PathSide::Right, PathSide::Both]
+                    .into_iter()
+                    .map(|side| side_action(side, self.side))
+                    .collect(),
+            },
+            PanelRow {
+                label: String::from("Setback"),
+                value: Some(format!("{:.1} m", self.setback)),
+                buttons: vec![
+                    setback_action("-", -SETBACK_STEP),
+                    setback_action("+", SETBACK_STEP),
+                ],
+            },
+        ];
+        for name in &self.templates {
+            let is_selected = self.selected.contains(name);
+            rows.push(PanelRow {
+                label: name.clone(),
+                value: None,
+                buttons: vec![template_action(name.clone(), is_selected)],
+            });
+        }
+        rows
+    }
+}
+
+fn side_action(side: PathSide, current: PathSide) -> PanelAction {
+    let observer = Observer::new(
+        move |trigger: On<Pointer<Release>>, mut mode: ResMut<AddBuildingsMode>| {
+            if trigger.button != PointerButton::Primary {
+                return;
+            }
+            mode.side = side;
+        },
+    );
+    PanelAction::new(&format!("{side:?}"), observer).with_active(side == current)
+}
+
+fn setback_action(label: &str, step: f32) -> PanelAction {
+    let observer = Observer::new(
+        move |trigger: On<Pointer<Release>>, mut mode: ResMut<AddBuildingsMode>| {
+            if trigger.button != PointerButton::Primary {
+                return;
+            }
+            mode.setback = (mode.setback + step).max(MIN_SETBACK);
+        },
+    );
+    PanelAction::new(label, observer)
+}
+
+fn template_action(name: String, is_selected: bool) -> PanelAction {
+    let observer = Observer::new(
+        move |trigger: On<Pointer<Release>>, mut mode: ResMut<AddBuildingsMode>| {
+            if trigger.button != PointerButton::Primary {
+                return;
+            }
+            if !mode.selected.remove(&name) {
+                mode.selected.insert(name.clone());
+            }
+        },
+    );
+    PanelAction::new("Include", observer).with_active(is_selected)
+}
@@ -0,0 +1,65 @@
+use crate::*;
+use bevy::prelude::*;
+use std::collections::BTreeSet;
+
+pub(super) const DEFAULT_SETBACK: f32 = 10.0;
+pub(super) const SETBACK_STEP: f32 = 1.0;
+pub(super) const MIN_SETBACK: f32 = 1.0;
+
+/// Which side of a [`konstruo_paths::Path`] to add buildings.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PathSide {
+    /// Positive offset from the path.
+    Left,
+    /// Negative offset from the path.
+    Right,
+    #[default]
+    Both,
+}
+
+/// Options to add a spline following distribution of buildings to a [`konstruo_paths::Path`].
+#[derive(Resource)]
+pub struct AddBuildingsMode {
+    /// The paths to add buildings to.
+    pub(super) paths: Vec<Entity>,
+    /// Which side or sides of the path.
+    pub(super) side: PathSide,
+    /// Distance from the path to the front of the buildings.
+    pub(super) setback: f32,
+    /// Names of every available template.
+    pub(super) templates: Vec<String>,
+    /// Names of the templates to distribute.
+    pub(super) selected: BTreeSet<String>,
+}
+
+impl AddBuildingsMode {
+    /// Create a new [`AddBuildingsMode`] with every template selected.
+    pub(crate) fn new(paths: Vec<Entity>, templates: Vec<String>) -> Self {
+        Self {
+            paths,
+            side: PathSide::default(),
+            setback: DEFAULT_SETBACK,
+            selected: templates.iter().cloned().collect(),
+            templates,
+        }
+    }
+}
+
+impl AddBuildingsMode {
+    /// Get the [`InterfaceState`] to return to.
+    pub(super) fn get_selection(&self) -> InterfaceState {
+        let paths = self.paths.iter().copied().collect();
+        InterfaceState::Selection(SelectionMode::Path, paths)
+    }
+}
+
+impl PathSide {
+    /// Get the sides as spline offset direction and whether the spline should be reversed.
+    pub(super) fn get_offsets(self) -> Vec<(f32, bool)> {
+        match self {
+            PathSide::Left => vec![(1.0, false)],
+            PathSide::Right => vec![(-1.0, true)],
+            PathSide::Both => vec![(1.0, false), (-1.0, true)],
+        }
+    }
+}
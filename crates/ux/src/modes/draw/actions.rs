@@ -4,6 +4,7 @@ use konstruo_beziers::*;
 use konstruo_core::Vec3Extensions;
 use konstruo_paths::*;
 use konstruo_ui::*;
+use std::collections::BTreeSet;
 
 impl DrawMode {
     /// Get the actions when [`DrawMode`] is active.
@@ -23,6 +24,10 @@ impl DrawMode {
     }
 
     /// Update the [`Path`] on action button pressed.
+    ///
+    /// If an existing [`Path`] is being extended then the drawn curves are added to it
+    /// and the temporary [`Path`] is despawned.
+    #[allow(clippy::too_many_arguments)]
     fn complete_action(
         trigger: On<Pointer<Release>>,
         mut commands: Commands,
@@ -30,13 +35,17 @@ impl DrawMode {
         drawing: Res<DrawMode>,
         mut paths: Query<&mut Path>,
         mut curve_added: MessageWriter<CurveAdded>,
+        mut path_edited: MessageWriter<PathEdited>,
+        mut record: MessageWriter<RecordEdit>,
+        mut indicator: ResMut<SnapIndicator>,
     ) {
         if trigger.button != PointerButton::Primary {
             return;
         }
         trace!("Complete button was pressed.");
         *interface = InterfaceState::Default;
-        let spline = match CubicBezierSpline::by_origins_and_handles(
+        indicator.clear();
+        let mut spline = match CubicBezierSpline::by_origins_and_handles(
             drawing.origins.clone(),
             drawing.handles.clone(),
         ) {
@@ -46,15 +55,50 @@ impl DrawMode {
                 return;
             }
         };
-        let Ok(mut path) = paths.get_mut(drawing.path) else {
-            warn!("Failed to get Path: {:?}", drawing.path);
+        if drawing.is_closed {
+            if let Err(e) = spline.close() {
+                warn!("Failed to close spline: {e:?}");
+                return;
+            }
+        }
+        let (entity, command) = if let Some(extension) = drawing.extension {
+            let Ok(mut path) = paths.get_mut(extension.path) else {
+                warn!("Failed to get Path: {:?}", extension.path);
+                return;
+            };
+            let mut extended = path.spline.clone();
+            let result = if extension.is_start {
+                spline.reverse();
+                extended.prepend(spline)
+            } else {
+                extended.append(spline)
+            };
+            if let Err(e) = result {
+                warn!("Failed to extend spline: {e:?}");
+                return;
+            }
+            *path = Path::new(extended);
+            commands.entity(drawing.path).despawn();
+            *interface =
+                InterfaceState::Selection(SelectionMode::Path, BTreeSet::from([extension.path]));
+            (extension.path, EditCommand::EditPath)
+        } else {
+            let Ok(mut path) = paths.get_mut(drawing.path) else {
+                warn!("Failed to get Path: {:?}", drawing.path);
+                return;
+            };
+            *path = Path::new(spline);
+            (drawing.path, EditCommand::CreatePath)
+        };
+        let Ok(path) = paths.get(entity) else {
             return;
         };
-        *path = Path::new(spline);
         curve_added.write(CurveAdded {
-            path: drawing.path,
+            path: entity,
             spline: path.spline.clone(),
         });
+        path_edited.write(PathEdited { path: entity });
+        record.write(RecordEdit { command });
         commands.remove_resource::<DrawMode>();
     }
 
@@ -82,40 +126,133 @@ impl DrawMode {
         factory.commands.insert_resource(drawing);
     }
 
+    /// Activate [`InterfaceState::DrawPath`] to extend the start of the selected [`Path`].
+    pub(crate) fn extend_start_action(
+        trigger: On<Pointer<Release>>,
+        interface: ResMut<InterfaceState>,
+        commands: Commands,
+        meshes: ResMut<Assets<Mesh>>,
+        path_meshes: Res<PathMeshes>,
+        materials: Res<PathMaterials>,
+        paths: Query<&Path>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Extend Start button was pressed.");
+        let factory = PathFactory {
+            commands,
+            meshes,
+            path_meshes,
+            materials,
+        };
+        DrawMode::start_extension(interface, factory, &paths, true);
+    }
+
+    /// Activate [`InterfaceState::DrawPath`] to extend the end of the selected [`Path`].
+    pub(crate) fn extend_end_action(
+        trigger: On<Pointer<Release>>,
+        interface: ResMut<InterfaceState>,
+        commands: Commands,
+        meshes: ResMut<Assets<Mesh>>,
+        path_meshes: Res<PathMeshes>,
+        materials: Res<PathMaterials>,
+        paths: Query<&Path>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Extend End button was pressed.");
+        let factory = PathFactory {
+            commands,
+            meshes,
+            path_meshes,
+            materials,
+        };
+        DrawMode::start_extension(interface, factory, &paths, false);
+    }
+
+    /// Insert a [`DrawMode`] to extend the selected [`Path`] unless it is closed.
+    fn start_extension(
+        mut interface: ResMut<InterfaceState>,
+        mut factory: PathFactory,
+        paths: &Query<&Path>,
+        is_start: bool,
+    ) {
+        let InterfaceState::Selection(_, entities) = interface.as_ref() else {
+            warn!("Expected InterfaceState::Selection: {interface:?}");
+            return;
+        };
+        let Some(entity) = entities.first().copied() else {
+            warn!("Expected a selected entity");
+            return;
+        };
+        let Ok(path) = paths.get(entity) else {
+            warn!("Failed to get Path: {entity:?}");
+            return;
+        };
+        if path.spline.is_closed() {
+            warn!("Failed to extend a closed Path: {entity:?}");
+            return;
+        }
+        let drawing = DrawMode::extend(&mut factory, entity, &path.spline, is_start);
+        factory.commands.insert_resource(drawing);
+        *interface = InterfaceState::DrawPath;
+    }
+
     /// Remove the last control and handle.
     fn undo_action(trigger: On<Pointer<Release>>, mut drawing: ResMut<DrawMode>) {
         if trigger.button != PointerButton::Primary {
             return;
         }
         trace!("Undo button was pressed.");
+        if drawing.extension.is_some() && drawing.origins.len() < 2 {
+            return;
+        }
+        drawing.is_closed = false;
         drawing.handles.pop();
         drawing.origins.pop();
     }
 
     /// Add origin controls on pointer down.
+    ///
+    /// Pressing the first origin closes the loop.
     pub(crate) fn on_pointer_down(
         trigger: On<Pointer<Press>>,
         drawing: Option<ResMut<DrawMode>>,
         camera: Query<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+        snapping: Res<Snapping>,
+        paths: Query<(Entity, &Path)>,
     ) {
         let Some(mut drawing) = drawing else {
             return;
         };
-        if trigger.button != PointerButton::Primary {
+        if trigger.button != PointerButton::Primary || drawing.is_closed {
             return;
         }
         let Ok(cursor) = Cursor::from_position(&camera, trigger.pointer_location.position) else {
             warn!("Failed to get cursor position");
             return;
         };
+        let cursor = drawing.snap(cursor, &snapping, paths.iter()).position;
+        if drawing.is_closing(cursor) {
+            let first = *drawing.origins.first().expect("should be a first origin");
+            drawing.origins.push(first);
+            drawing.is_closed = true;
+            return;
+        }
         drawing.origins.push(cursor);
     }
 
     /// Add handle controls on pointer up.
+    ///
+    /// If the loop has been closed then the first handle is repeated so the loop is smooth.
     pub(crate) fn on_pointer_up(
         trigger: On<Pointer<Release>>,
         drawing: Option<ResMut<DrawMode>>,
         camera: Query<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+        snapping: Res<Snapping>,
+        paths: Query<(Entity, &Path)>,
     ) {
         let Some(mut drawing) = drawing else {
             return;
@@ -123,10 +260,18 @@ impl DrawMode {
         if trigger.button != PointerButton::Primary {
             return;
         }
+        if drawing.is_closed {
+            if drawing.origins.len() == drawing.handles.len() + 1 {
+                let first = *drawing.handles.first().expect("should be a first handle");
+                drawing.handles.push(first);
+            }
+            return;
+        }
         let Ok(cursor) = Cursor::from_position(&camera, trigger.pointer_location.position) else {
             warn!("Failed to get cursor position");
             return;
         };
+        let cursor = drawing.snap(cursor, &snapping, paths.iter()).position;
         assert_eq!(
             drawing.origins.len(),
             drawing.handles.len() + 1,
@@ -1,5 +1,7 @@
+pub use panel::*;
 pub use resource::*;
 
 mod actions;
+mod panel;
 mod resource;
 mod systems;
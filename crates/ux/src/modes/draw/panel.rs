@@ -0,0 +1,47 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_paths::PathSurfaceIntersection;
+use konstruo_ui::PrimaryCamera;
+
+/// Marker for the [`Panel`] warning that the [`Path`] being drawn is too tight.
+#[derive(Component)]
+pub struct DrawWarningPanel;
+
+impl DrawMode {
+    /// System to show a [`DrawWarningPanel`] while a surface of the [`Path`] being drawn
+    /// crosses over itself.
+    pub(crate) fn warning_system(
+        mut commands: Commands,
+        mode: Option<Res<DrawMode>>,
+        assets: Res<AssetServer>,
+        surfaces: Query<&ChildOf, With<PathSurfaceIntersection>>,
+        panels: Query<Entity, With<DrawWarningPanel>>,
+        cameras: Query<Entity, With<PrimaryCamera>>,
+    ) {
+        let is_warning = mode.is_some_and(|mode| {
+            surfaces
+                .iter()
+                .any(|child_of| child_of.parent() == mode.path)
+        });
+        let is_shown = !panels.is_empty();
+        if is_warning == is_shown {
+            return;
+        }
+        for entity in panels.iter() {
+            commands.entity(entity).despawn();
+        }
+        if !is_warning {
+            return;
+        }
+        let Ok(camera) = cameras.single() else {
+            warn!("Failed to get PrimaryCamera");
+            return;
+        };
+        let rows = vec![PanelRow::value(
+            "Curve is too tight",
+            String::from("Surfaces have been trimmed"),
+        )];
+        let panel = Panel::spawn(&mut commands, &assets, camera, "Warning", rows);
+        commands.entity(panel).insert(DrawWarningPanel);
+    }
+}
@@ -2,6 +2,10 @@ use bevy::prelude::*;
 use konstruo_beziers::*;
 use konstruo_geometry::*;
 use konstruo_paths::*;
+use konstruo_ui::{Snap, Snapping};
+
+/// Maximum distance in metres from the first origin at which a press closes the loop.
+const CLOSE_DISTANCE: f32 = 1.0;
 
 #[derive(Resource)]
 pub struct DrawMode {
@@ -11,6 +15,17 @@ pub struct DrawMode {
     pub(super) control: Entity,
     pub(super) line: Entity,
     pub(super) is_ready: bool,
+    /// The end of an existing [`Path`] that the drawn curves are added to.
+    pub(super) extension: Option<PathExtension>,
+    /// Has the loop been closed by pressing the first origin?
+    pub(super) is_closed: bool,
+}
+
+/// An end of an existing [`Path`] to continue drawing from.
+#[derive(Clone, Copy, Debug)]
+pub struct PathExtension {
+    pub(super) path: Entity,
+    pub(super) is_start: bool,
 }
 
 impl DrawMode {
@@ -44,6 +59,72 @@ impl DrawMode {
             control,
             line,
             is_ready: false,
+            extension: None,
+            is_closed: false,
+        }
+    }
+
+    /// Create a new [`DrawMode`] resource to continue drawing from the start or end of a
+    /// [`Path`].
+    ///
+    /// The first handle mirrors the adjacent handle so the extension is smooth.
+    pub(super) fn extend(
+        factory: &mut PathFactory,
+        path: Entity,
+        spline: &CubicBezierSpline,
+        is_start: bool,
+    ) -> Self {
+        let mut drawing = Self::new(factory);
+        let (origin, handle) = if is_start {
+            (
+                spline.get_start(),
+                spline.get_control(ControlType::StartHandle, 0),
+            )
+        } else {
+            let last = spline.get_curves().len() - 1;
+            (
+                spline.get_end(),
+                spline.get_control(ControlType::EndHandle, last),
+            )
+        };
+        let handle = handle.unwrap_or(origin);
+        drawing.origins.push(origin);
+        drawing.handles.push(origin + (origin - handle));
+        drawing.extension = Some(PathExtension { path, is_start });
+        drawing
+    }
+}
+
+impl DrawMode {
+    /// Can the loop be closed by pressing at the cursor?
+    ///
+    /// A loop needs at least two curves and can't be closed while extending a [`Path`].
+    pub(super) fn is_closing(&self, cursor: Vec3) -> bool {
+        let Some(first) = self.origins.first() else {
+            return false;
+        };
+        self.extension.is_none()
+            && self.origins.len() >= 2
+            && self.origins.len() == self.handles.len()
+            && first.distance(cursor) < CLOSE_DISTANCE
+    }
+
+    /// Snap the cursor to the first origin, other paths, an angle from the last origin, or the
+    /// grid.
+    pub(super) fn snap<'a>(
+        &self,
+        cursor: Vec3,
+        snapping: &Snapping,
+        paths: impl Iterator<Item = (Entity, &'a Path)>,
+    ) -> Snap {
+        let others = paths
+            .filter(|(entity, _)| *entity != self.path)
+            .map(|(_, path)| path);
+        let mut targets = Path::get_snap_targets(others);
+        if let Some(first) = self.origins.first() {
+            targets.add_point(*first);
         }
+        let targets = targets.with_origin(self.origins.last().copied());
+        snapping.snap(cursor, &targets)
     }
 }
@@ -12,7 +12,7 @@ impl DrawMode {
     #[allow(clippy::too_many_arguments, clippy::type_complexity)]
     pub(crate) fn update_system(
         drawing: Option<ResMut<DrawMode>>,
-        mut paths: Query<(&mut Path, &mut Visibility)>,
+        mut paths: Query<(Entity, &mut Path, &mut Visibility)>,
         mut controls: Query<(&mut Transform, &mut Visibility), (With<PathControl>, Without<Path>)>,
         mut meshes: ResMut<Assets<Mesh>>,
         mut lines: Query<
@@ -23,6 +23,8 @@ impl DrawMode {
         motion: MessageReader<MouseMotion>,
         window: Query<&Window, With<PrimaryWindow>>,
         camera: Query<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+        snapping: Res<Snapping>,
+        mut indicator: ResMut<SnapIndicator>,
     ) {
         let Some(mut drawing) = drawing else {
             return;
@@ -32,13 +34,17 @@ impl DrawMode {
         }
         let mut origins = drawing.origins.clone();
         let mut handles = drawing.handles.clone();
-        let is_handle_next = origins.len() > handles.len();
+        let is_handle_next = !drawing.is_closed && origins.len() > handles.len();
         let Ok(cursor) = Cursor::from_window(&window, &camera) else {
             return;
         };
+        let others = paths.iter().map(|(entity, path, _)| (entity, path));
+        let snap = drawing.snap(cursor, &snapping, others);
+        indicator.set(snap);
+        let cursor = snap.position;
         if is_handle_next {
             handles.push(cursor);
-        } else {
+        } else if !drawing.is_closed {
             origins.push(cursor);
         }
         // Update Control
@@ -70,14 +76,20 @@ impl DrawMode {
         if origins.len() < 2 {
             return;
         }
-        let spline = match CubicBezierSpline::by_origins_and_handles(origins, handles) {
+        let mut spline = match CubicBezierSpline::by_origins_and_handles(origins, handles) {
             Ok(spline) => spline,
             Err(e) => {
                 warn!("Failed to create spline: {e:?}");
                 return;
             }
         };
-        let Ok((mut path, mut visibility)) = paths.get_mut(drawing.path) else {
+        if drawing.is_closed {
+            if let Err(e) = spline.close() {
+                warn!("Failed to close spline: {e:?}");
+                return;
+            }
+        }
+        let Ok((_, mut path, mut visibility)) = paths.get_mut(drawing.path) else {
             warn!("Failed to get Path: {:?}", drawing.path);
             return;
         };
@@ -0,0 +1,245 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_architecture::*;
+use konstruo_beziers::constants::LENGTH_ACCURACY;
+use konstruo_distribution::{Distributable, Distribution};
+use konstruo_paths::{Path, PathSurface, PathSurfaceInfo};
+use konstruo_ui::{PrimaryCamera, Settings, Units};
+use std::collections::BTreeMap;
+use std::iter::once;
+
+const WIDTH_STEP: f32 = 0.1;
+const MIN_WIDTH: f32 = 0.1;
+
+/// Marker for the [`Panel`] of [`InfoMode`].
+#[derive(Component)]
+pub struct InfoPanel;
+
+impl InfoMode {
+    /// System to respawn the [`InfoPanel`] when [`InfoMode`] or the properties of its
+    /// entity are changed.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    pub(crate) fn panel_system(
+        mut commands: Commands,
+        mode: Option<Res<InfoMode>>,
+        settings: Res<Settings>,
+        assets: Res<AssetServer>,
+        paths: Query<Ref<Path>>,
+        surfaces: Query<(Entity, Ref<PathSurface>, &ChildOf)>,
+        mut removed: RemovedComponents<PathSurface>,
+        buildings: Query<Ref<BuildingTemplate>, With<ModularBuilding>>,
+        templates: Res<BuildingTemplates>,
+        building_assets: Res<Assets<ModularBuildingInfo>>,
+        panels: Query<Entity, With<InfoPanel>>,
+        cameras: Query<Entity, With<PrimaryCamera>>,
+    ) {
+        let is_removed = removed.read().count() > 0;
+        let is_changed = mode.as_ref().is_none_or(|mode| {
+            mode.is_changed()
+                || settings.is_changed()
+                || is_removed
+                || paths.get(mode.entity).is_ok_and(|path| path.is_changed())
+                || buildings
+                    .get(mode.entity)
+                    .is_ok_and(|template| template.is_changed())
+                || surfaces.iter().any(|(_, surface, child_of)| {
+                    child_of.parent() == mode.entity && surface.is_changed()
+                })
+        });
+        if !is_changed {
+            return;
+        }
+        for entity in panels.iter() {
+            commands.entity(entity).despawn();
+        }
+        let Some(mode) = mode else {
+            return;
+        };
+        let Ok(camera) = cameras.single() else {
+            warn!("Failed to get PrimaryCamera");
+            return;
+        };
+        let (title, rows) = if let Ok(path) = paths.get(mode.entity) {
+            let surfaces: Vec<(Entity, &PathSurfaceInfo)> = surfaces
+                .iter()
+                .filter(|(_, _, child_of)| child_of.parent() == mode.entity)
+                .map(|(entity, surface, _)| (entity, surface.into_inner().get_info()))
+                .collect();
+            ("Path", path_rows(&path, &surfaces, settings.units))
+        } else if let Ok(template) = buildings.get(mode.entity) {
+            let Some(info) = templates.get(&template.name, &building_assets) else {
+                warn!("Failed to get building template: {}", template.name);
+                return;
+            };
+            (
+                "Building",
+                building_rows(mode.entity, &template, info, settings.units),
+            )
+        } else {
+            warn!("Unable to display info for {:?}", mode.entity);
+            return;
+        };
+        let panel = Panel::spawn(&mut commands, &assets, camera, title, rows);
+        commands.entity(panel).insert(InfoPanel);
+    }
+}
+
+/// Get the [`PanelRow`] to display a [`Path`] and edit the width of its surfaces.
+fn path_rows(path: &Path, surfaces: &[(Entity, &PathSurfaceInfo)], units: Units) -> Vec<PanelRow> {
+    let length = path.spline.get_length(LENGTH_ACCURACY);
+    let mut rows = vec![
+        PanelRow::value("Length", units.format_length(length)),
+        PanelRow::value("Curves", path.spline.get_curves().len().to_string()),
+    ];
+    let total: f32 = surfaces.iter().map(|(_, info)| info.width).sum();
+    rows.push(PanelRow::value("Width", units.format_length(total)));
+    for (entity, info) in surfaces {
+        rows.push(PanelRow {
+            label: format!("{:?}", info.purpose),
+            value: Some(units.format_length(info.width)),
+            buttons: vec![
+                width_action("-", *entity, -WIDTH_STEP),
+                width_action("+", *entity, WIDTH_STEP),
+            ],
+        });
+    }
+    rows
+}
+
+/// Get the [`PanelRow`] to display a [`ModularBuilding`] and change its template.
+fn building_rows(
+    entity: Entity,
+    template: &BuildingTemplate,
+    info: &ModularBuildingInfo,
+    units: Units,
+) -> Vec<PanelRow> {
+    let mut rows = vec![
+        PanelRow {
+            label: String::from("Template"),
+            value: Some(template.name.clone()),
+            buttons: vec![
+                template_action("<", entity, false),
+                template_action(">", entity, true),
+            ],
+        },
+        PanelRow::value(
+            "Footprint",
+            units.format_dimensions(&[info.get_width(), info.get_length()]),
+        ),
+        PanelRow::value("Storeys", info.get_storeys().to_string()),
+        PanelRow::value("Modules", info.get_modules().count().to_string()),
+    ];
+    let mut dimensions: BTreeMap<String, usize> = BTreeMap::new();
+    for module in info.get_modules() {
+        let dimension = units.format_dimensions(&[module.width, module.length, module.height]);
+        *dimensions.entry(dimension).or_default() += 1;
+    }
+    for (dimension, count) in dimensions {
+        rows.push(PanelRow::value(&format!("{count} ×"), dimension));
+    }
+    rows.push(PanelRow::value(
+        "Openings",
+        info.get_openings_count().to_string(),
+    ));
+    rows
+}
+
+/// Change the width of a [`PathSurface`] by a step.
+fn width_action(label: &str, entity: Entity, step: f32) -> PanelAction {
+    let observer = Observer::new(
+        move |trigger: On<Pointer<Release>>,
+              mut surfaces: Query<&mut PathSurface>,
+              mut record: MessageWriter<RecordEdit>| {
+            if trigger.button != PointerButton::Primary {
+                return;
+            }
+            let Ok(mut surface) = surfaces.get_mut(entity) else {
+                warn!("Failed to get PathSurface: {entity:?}");
+                return;
+            };
+            let mut info = surface.get_info().clone();
+            info.width = (info.width + step).max(MIN_WIDTH);
+            surface.set_info(info);
+            record.write(RecordEdit {
+                command: EditCommand::EditSurfaces,
+            });
+        },
+    );
+    PanelAction::new(label, observer)
+}
+
+/// Respawn a [`ModularBuilding`] from the next or previous template in alphabetical order.
+#[allow(clippy::type_complexity)]
+fn template_action(label: &str, entity: Entity, is_next: bool) -> PanelAction {
+    let observer = Observer::new(
+        move |trigger: On<Pointer<Release>>,
+              commands: Commands,
+              meshes: ResMut<Assets<Mesh>>,
+              building_meshes: Res<BuildingMeshes>,
+              materials: Res<BuildingMaterials>,
+              templates: Res<BuildingTemplates>,
+              assets: Res<Assets<ModularBuildingInfo>>,
+              buildings: Query<(&BuildingTemplate, Option<&Children>), With<ModularBuilding>>,
+              stacks: Query<(), With<BuildingModuleStack>>,
+              parents: Query<&ChildOf>,
+              roots: Query<(), (With<Distribution>, Without<Distributable>)>,
+              mut record: MessageWriter<RecordEdit>| {
+            if trigger.button != PointerButton::Primary {
+                return;
+            }
+            let Ok((template, children)) = buildings.get(entity) else {
+                warn!("Failed to get ModularBuilding: {entity:?}");
+                return;
+            };
+            let names = templates.get_names();
+            let Some(index) = names.iter().position(|name| *name == template.name) else {
+                warn!("Failed to get building template: {}", template.name);
+                return;
+            };
+            let count = names.len();
+            let index = if is_next {
+                (index + 1) % count
+            } else {
+                (index + count - 1) % count
+            };
+            let Some(name) = names.get(index) else {
+                return;
+            };
+            let Some(info) = templates.get(name, &assets) else {
+                warn!("Failed to get building template: {name}");
+                return;
+            };
+            let mut factory = ModularBuildingFactory {
+                commands,
+                meshes,
+                building_meshes,
+                materials,
+            };
+            let children = children.into_iter().flatten().copied();
+            let template = BuildingTemplate {
+                name: (*name).to_owned(),
+                is_mirrored: template.is_mirrored,
+            };
+            factory.respawn_template(
+                entity,
+                children.filter(|child| stacks.contains(*child)),
+                template,
+                info.clone(),
+            );
+            let root = once(entity)
+                .chain(parents.iter_ancestors(entity))
+                .find(|ancestor| roots.contains(*ancestor));
+            if let Some(root) = root {
+                factory
+                    .commands
+                    .entity(root)
+                    .entry::<Distribution>()
+                    .and_modify(|mut distribution| distribution.set_changed());
+            }
+            record.write(RecordEdit {
+                command: EditCommand::EditBuildings,
+            });
+        },
+    );
+    PanelAction::new(label, observer)
+}
@@ -0,0 +1,35 @@
+use crate::*;
+use bevy::prelude::*;
+
+/// Display and edit the properties of a selected [`konstruo_paths::Path`] or
+/// [`konstruo_architecture::ModularBuilding`] in an [`InfoPanel`].
+#[derive(Resource)]
+pub struct InfoMode {
+    /// The entity to display.
+    pub(super) entity: Entity,
+}
+
+impl InfoMode {
+    /// Create a new [`InfoMode`].
+    pub(crate) fn new(entity: Entity) -> Self {
+        Self { entity }
+    }
+
+    /// System to remove [`InfoMode`] when its entity is no longer selected.
+    pub(crate) fn selection_system(
+        mut commands: Commands,
+        mode: Option<Res<InfoMode>>,
+        interface: Res<InterfaceState>,
+    ) {
+        let Some(mode) = mode else {
+            return;
+        };
+        let is_selected = matches!(
+            interface.as_ref(),
+            InterfaceState::Selection(_, entities) if entities.contains(&mode.entity)
+        );
+        if !is_selected {
+            commands.remove_resource::<InfoMode>();
+        }
+    }
+}
@@ -1,5 +1,15 @@
+pub use buildings::*;
 pub use draw::*;
+pub use info::*;
+pub use nodes::*;
 pub use selection::*;
+pub use settings::*;
+pub use surfaces::*;
 
+mod buildings;
 mod draw;
+mod info;
+mod nodes;
 mod selection;
+mod settings;
+mod surfaces;
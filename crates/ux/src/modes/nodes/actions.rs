@@ -0,0 +1,188 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_beziers::constants::INTERSECTION_ACCURACY;
+use konstruo_beziers::{CubicBezierSpline, NodeContinuity};
+use konstruo_paths::*;
+use konstruo_ui::{Cursor, PrimaryCamera};
+use std::collections::BTreeSet;
+
+/// Maximum distance in metres from the cursor to a [`Path`] or node for it to be edited.
+const PICK_DISTANCE: f32 = 3.0;
+
+impl NodesMode {
+    /// Get the actions when [`NodesMode`] is active.
+    pub(crate) fn actions() -> Vec<Action> {
+        vec![
+            Action {
+                label: String::from("Insert Node"),
+                icon: Icon::font_awesome("plus"),
+                on_press: Observer::new(NodesMode::insert_action),
+            },
+            Action {
+                label: String::from("Remove Node"),
+                icon: Icon::font_awesome("minus"),
+                on_press: Observer::new(NodesMode::remove_action),
+            },
+            Action {
+                label: String::from("Corner Node"),
+                icon: Icon::font_awesome("angle-right"),
+                on_press: NodesMode::continuity_action(NodeContinuity::Corner),
+            },
+            Action {
+                label: String::from("Smooth Node"),
+                icon: Icon::font_awesome("bezier-curve"),
+                on_press: NodesMode::continuity_action(NodeContinuity::Smooth),
+            },
+            Action {
+                label: String::from("Symmetric Node"),
+                icon: Icon::font_awesome("arrows-alt-h"),
+                on_press: NodesMode::continuity_action(NodeContinuity::Symmetric),
+            },
+            Action {
+                label: String::from("Done"),
+                icon: Icon::font_awesome("check"),
+                on_press: Observer::new(NodesMode::done_action),
+            },
+        ]
+    }
+
+    /// Activate [`InterfaceState::EditNodes`] for the selected [`Path`].
+    pub(crate) fn start_action(
+        trigger: On<Pointer<Release>>,
+        mut commands: Commands,
+        mut interface: ResMut<InterfaceState>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Edit Nodes button was pressed.");
+        let InterfaceState::Selection(_, entities) = interface.as_ref() else {
+            warn!("Expected InterfaceState::Selection: {interface:?}");
+            return;
+        };
+        let Some(entity) = entities.first() else {
+            warn!("Expected a selected entity");
+            return;
+        };
+        commands.insert_resource(NodesMode::new(*entity));
+        *interface = InterfaceState::EditNodes;
+    }
+
+    /// Use [`NodesTool::Insert`] on action button press.
+    fn insert_action(trigger: On<Pointer<Release>>, mut mode: ResMut<NodesMode>) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Insert Node button was pressed.");
+        mode.tool = NodesTool::Insert;
+    }
+
+    /// Use [`NodesTool::Remove`] on action button press.
+    fn remove_action(trigger: On<Pointer<Release>>, mut mode: ResMut<NodesMode>) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Remove Node button was pressed.");
+        mode.tool = NodesTool::Remove;
+    }
+
+    /// Get an [`Observer`] to use [`NodesTool::Continuity`] on action button press.
+    fn continuity_action(continuity: NodeContinuity) -> Observer {
+        Observer::new(
+            move |trigger: On<Pointer<Release>>, mut mode: ResMut<NodesMode>| {
+                if trigger.button != PointerButton::Primary {
+                    return;
+                }
+                trace!("{continuity:?} Node button was pressed.");
+                mode.tool = NodesTool::Continuity(continuity);
+            },
+        )
+    }
+
+    /// Return to [`SelectionMode::Path`].
+    fn done_action(
+        trigger: On<Pointer<Release>>,
+        mut commands: Commands,
+        mut interface: ResMut<InterfaceState>,
+        mode: Res<NodesMode>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Done button was pressed.");
+        *interface = InterfaceState::Selection(SelectionMode::Path, BTreeSet::from([mode.path]));
+        commands.remove_resource::<NodesMode>();
+    }
+
+    /// Apply the [`NodesTool`] to the node nearest to the cursor on pointer up.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn on_pointer_up(
+        trigger: On<Pointer<Release>>,
+        mode: Option<Res<NodesMode>>,
+        camera: Query<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+        mut paths: Query<&mut Path>,
+        mut curve_added: MessageWriter<CurveAdded>,
+        mut path_edited: MessageWriter<PathEdited>,
+        mut record: MessageWriter<RecordEdit>,
+    ) {
+        let Some(mode) = mode else {
+            return;
+        };
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        let Ok(cursor) = Cursor::from_position(&camera, trigger.pointer_location.position) else {
+            warn!("Failed to get cursor position");
+            return;
+        };
+        let Ok(mut path) = paths.get_mut(mode.path) else {
+            warn!("Failed to get Path: {:?}", mode.path);
+            return;
+        };
+        let mut spline = path.spline.clone();
+        let result = match mode.tool {
+            NodesTool::Insert => {
+                let param = spline.get_param_nearest_to(cursor, INTERSECTION_ACCURACY);
+                if spline.get_point_at_param(param).distance(cursor) > PICK_DISTANCE {
+                    return;
+                }
+                spline.insert_node(param).map(|_| ())
+            }
+            NodesTool::Remove => {
+                let Some(index) = get_nearest_node(&spline, cursor) else {
+                    return;
+                };
+                spline.remove_node(index)
+            }
+            NodesTool::Continuity(continuity) => {
+                let Some(index) = get_nearest_node(&spline, cursor) else {
+                    return;
+                };
+                spline.set_continuity(index, continuity)
+            }
+        };
+        if let Err(e) = result {
+            warn!("Failed to edit nodes of Path: {e:?}");
+            return;
+        }
+        *path = Path::new(spline);
+        curve_added.write(CurveAdded {
+            path: mode.path,
+            spline: path.spline.clone(),
+        });
+        path_edited.write(PathEdited { path: mode.path });
+        record.write(RecordEdit {
+            command: EditCommand::EditPath,
+        });
+    }
+}
+
+/// Get the index of the node nearest to the cursor if it's within [`PICK_DISTANCE`].
+fn get_nearest_node(spline: &CubicBezierSpline, cursor: Vec3) -> Option<usize> {
+    let (index, node) = spline
+        .get_nodes()
+        .into_iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| a.distance(cursor).total_cmp(&b.distance(cursor)))?;
+    (node.distance(cursor) <= PICK_DISTANCE).then_some(index)
+}
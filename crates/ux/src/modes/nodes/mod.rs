@@ -0,0 +1,4 @@
+pub use resource::*;
+
+mod actions;
+mod resource;
@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+use konstruo_beziers::NodeContinuity;
+
+/// Insert and remove the nodes of a [`konstruo_paths::Path`].
+#[derive(Resource)]
+pub struct NodesMode {
+    /// The path to edit.
+    pub(super) path: Entity,
+    /// The operation applied on pointer up.
+    pub(super) tool: NodesTool,
+}
+
+/// Operation of [`NodesMode`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum NodesTool {
+    /// Split the curve nearest to the cursor.
+    #[default]
+    Insert,
+    /// Merge the curves either side of the node nearest to the cursor.
+    Remove,
+    /// Set the continuity of the node nearest to the cursor.
+    Continuity(NodeContinuity),
+}
+
+impl NodesMode {
+    /// Create a new [`NodesMode`] with the [`NodesTool::Insert`].
+    pub(crate) fn new(path: Entity) -> Self {
+        Self {
+            path,
+            tool: NodesTool::default(),
+        }
+    }
+}
@@ -1,11 +1,21 @@
 use crate::*;
 use bevy::log::warn;
 use bevy::prelude::*;
+use konstruo_architecture::*;
+use konstruo_distribution::{Distributable, Distribution};
 use konstruo_ui::{EntityState, EntityStateChanged};
+use std::iter::once;
+
+/// Gap in metres between a building placed by transform and its duplicate.
+const DUPLICATE_GAP: f32 = 2.0;
 
 impl SelectionMode {
     /// Get the actions when [`SelectionMode`] is active.
-    pub(crate) fn actions(self) -> Vec<Action> {
+    ///
+    /// Actions apply to every selected entity.
+    ///
+    /// A single path can only be extended if `is_extendable`.
+    pub(crate) fn actions(self, count: usize, is_extendable: bool) -> Vec<Action> {
         let mut actions = vec![
             Action {
                 label: String::from("Deselect"),
@@ -23,12 +33,43 @@ impl SelectionMode {
                 on_press: Observer::new(SelectionMode::info_action),
             },
         ];
-        if self == SelectionMode::Path {
+        if self == SelectionMode::Building {
+            actions.push(Action {
+                label: String::from("Duplicate"),
+                icon: Icon::font_awesome("clone"),
+                on_press: Observer::new(SelectionMode::duplicate_action),
+            });
             actions.push(Action {
-                label: String::from("Add Surface"),
-                icon: Icon::font_awesome("road"),
-                on_press: Observer::new(SelectionMode::add_surface_action),
+                label: String::from("Mirror"),
+                icon: Icon::font_awesome("arrows-alt-h"),
+                on_press: Observer::new(SelectionMode::mirror_action),
             });
+        }
+        if self == SelectionMode::Path {
+            if count == 1 {
+                actions.push(Action {
+                    label: String::from("Add Surface"),
+                    icon: Icon::font_awesome("road"),
+                    on_press: Observer::new(SelectionMode::add_surface_action),
+                });
+                actions.push(Action {
+                    label: String::from("Edit Nodes"),
+                    icon: Icon::font_awesome("project-diagram"),
+                    on_press: Observer::new(NodesMode::start_action),
+                });
+            }
+            if count == 1 && is_extendable {
+                actions.push(Action {
+                    label: String::from("Extend Start"),
+                    icon: Icon::font_awesome("step-backward"),
+                    on_press: Observer::new(DrawMode::extend_start_action),
+                });
+                actions.push(Action {
+                    label: String::from("Extend End"),
+                    icon: Icon::font_awesome("step-forward"),
+                    on_press: Observer::new(DrawMode::extend_end_action),
+                });
+            }
             actions.push(Action {
                 label: String::from("Add Buildings"),
                 icon: Icon::font_awesome("home"),
@@ -38,23 +79,58 @@ impl SelectionMode {
         actions
     }
 
-    fn add_buildings_action(trigger: On<Pointer<Release>>) {
+    /// Activate [`InterfaceState::AddBuildings`] for each selected [`konstruo_paths::Path`].
+    fn add_buildings_action(
+        trigger: On<Pointer<Release>>,
+        mut commands: Commands,
+        mut interface: ResMut<InterfaceState>,
+        templates: Res<BuildingTemplates>,
+    ) {
         if trigger.button != PointerButton::Primary {
             return;
         }
         trace!("Add buildings button was pressed.");
-        warn!("Add buildings action not implemented");
+        let InterfaceState::Selection(_, entities) = interface.as_ref() else {
+            warn!("Expected InterfaceState::Selection: {interface:?}");
+            return;
+        };
+        let paths = entities.iter().copied().collect();
+        if !templates.is_loaded() {
+            warn!("Unable to add buildings before building templates are loaded");
+            return;
+        }
+        let names = templates
+            .get_names()
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        commands.insert_resource(AddBuildingsMode::new(paths, names));
+        *interface = InterfaceState::AddBuildings;
     }
 
-    fn add_surface_action(trigger: On<Pointer<Release>>) {
+    /// Activate [`InterfaceState::EditSurfaces`] for the selected [`konstruo_paths::Path`].
+    fn add_surface_action(
+        trigger: On<Pointer<Release>>,
+        mut commands: Commands,
+        mut interface: ResMut<InterfaceState>,
+    ) {
         if trigger.button != PointerButton::Primary {
             return;
         }
         trace!("Add surface button was pressed.");
-        warn!("Add surface action not implemented");
+        let InterfaceState::Selection(_, entities) = interface.as_ref() else {
+            warn!("Expected InterfaceState::Selection: {interface:?}");
+            return;
+        };
+        let Some(entity) = entities.first() else {
+            warn!("Expected a selected entity");
+            return;
+        };
+        commands.insert_resource(SurfacesMode::new(*entity));
+        *interface = InterfaceState::EditSurfaces;
     }
 
-    /// Deselect the selected entity on action button press.
+    /// Deselect every selected entity on action button press.
     fn deselect_action(
         trigger: On<Pointer<Release>>,
         mut changed: MessageWriter<EntityStateChanged>,
@@ -65,45 +141,201 @@ impl SelectionMode {
             return;
         }
         trace!("Deselect button was pressed.");
-        let InterfaceState::Selection(_, entity) = *interface else {
+        let InterfaceState::Selection(_, entities) = interface.as_ref() else {
+            warn!("Expected InterfaceState::Selection: {interface:?}");
+            return;
+        };
+        for entity in entities {
+            let Ok(mut entity_state) = entity_states.get_mut(*entity) else {
+                warn!("Failed to get EntityState for {entity:?}");
+                continue;
+            };
+            *entity_state = EntityState::Default;
+            changed.write(EntityStateChanged {
+                entity: *entity,
+                state: EntityState::Default,
+            });
+        }
+        *interface = InterfaceState::Default;
+    }
+
+    /// Duplicate every selected [`ModularBuilding`] on action button press.
+    ///
+    /// A building in a [`Distribution`] is duplicated with the same [`Distributable`] order
+    /// so the copy is distributed directly after it. Otherwise the copy is placed alongside.
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    fn duplicate_action(
+        trigger: On<Pointer<Release>>,
+        interface: Res<InterfaceState>,
+        commands: Commands,
+        meshes: ResMut<Assets<Mesh>>,
+        building_meshes: Res<BuildingMeshes>,
+        materials: Res<BuildingMaterials>,
+        templates: Res<BuildingTemplates>,
+        assets: Res<Assets<ModularBuildingInfo>>,
+        buildings: Query<
+            (
+                &BuildingTemplate,
+                &Transform,
+                Option<&Distributable>,
+                Option<&ChildOf>,
+            ),
+            With<ModularBuilding>,
+        >,
+        mut record: MessageWriter<RecordEdit>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Duplicate button was pressed.");
+        let InterfaceState::Selection(_, entities) = interface.as_ref() else {
             warn!("Expected InterfaceState::Selection: {interface:?}");
             return;
         };
-        let Ok(mut entity_state) = entity_states.get_mut(entity) else {
-            warn!("Failed to get EntityState for {entity:?}");
+        let mut factory = ModularBuildingFactory {
+            commands,
+            meshes,
+            building_meshes,
+            materials,
+        };
+        for entity in entities {
+            let Ok((template, transform, distributable, child_of)) = buildings.get(*entity) else {
+                warn!("Unable to duplicate a building that was not spawned from a template");
+                continue;
+            };
+            let Some(info) = templates.get(&template.name, &assets) else {
+                warn!("Failed to get building template: {}", template.name);
+                continue;
+            };
+            let copy = if template.is_mirrored {
+                factory.spawn_mirrored_template(&template.name, info.clone())
+            } else {
+                factory.spawn_template(&template.name, info.clone())
+            };
+            if let (Some(distributable), Some(child_of)) = (distributable, child_of) {
+                let parent = child_of.parent();
+                factory
+                    .commands
+                    .entity(copy)
+                    .insert((distributable.clone(), ChildOf(parent)));
+                factory
+                    .commands
+                    .entity(parent)
+                    .entry::<Distribution>()
+                    .and_modify(|mut distribution| distribution.set_changed());
+            } else {
+                let offset = transform.rotation * Vec3::X * (info.get_width() + DUPLICATE_GAP);
+                factory
+                    .commands
+                    .entity(copy)
+                    .insert(transform.with_translation(transform.translation + offset));
+            }
+        }
+        record.write(RecordEdit {
+            command: EditCommand::DuplicateBuildings,
+        });
+    }
+
+    /// Mirror every selected [`ModularBuilding`] from left to right on action button press.
+    ///
+    /// The order of the [`BuildingModuleStack`] is reversed and the root [`Distribution`]
+    /// is marked as changed so the stacks are redistributed.
+    #[allow(clippy::type_complexity)]
+    fn mirror_action(
+        trigger: On<Pointer<Release>>,
+        interface: Res<InterfaceState>,
+        mut buildings: Query<(Option<&mut BuildingTemplate>, &Children), With<ModularBuilding>>,
+        mut stacks: Query<&mut Distributable, With<BuildingModuleStack>>,
+        parents: Query<&ChildOf>,
+        mut roots: Query<&mut Distribution, Without<Distributable>>,
+        mut record: MessageWriter<RecordEdit>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Mirror button was pressed.");
+        let InterfaceState::Selection(_, entities) = interface.as_ref() else {
+            warn!("Expected InterfaceState::Selection: {interface:?}");
             return;
         };
-        *entity_state = EntityState::Default;
-        changed.write(EntityStateChanged {
-            entity,
-            state: EntityState::Default,
+        for entity in entities {
+            let Ok((template, children)) = buildings.get_mut(*entity) else {
+                warn!("Failed to get ModularBuilding: {entity:?}");
+                continue;
+            };
+            if let Some(mut template) = template {
+                template.is_mirrored = !template.is_mirrored;
+            }
+            let last = children
+                .iter()
+                .filter_map(|child| stacks.get(child).ok())
+                .map(|distributable| distributable.order)
+                .max()
+                .unwrap_or_default();
+            for child in children.iter() {
+                if let Ok(mut distributable) = stacks.get_mut(child) {
+                    distributable.order = last - distributable.order;
+                }
+            }
+            let root = once(*entity)
+                .chain(parents.iter_ancestors(*entity))
+                .find(|ancestor| roots.contains(*ancestor));
+            if let Some(mut distribution) = root.and_then(|root| roots.get_mut(root).ok()) {
+                distribution.set_changed();
+            }
+        }
+        record.write(RecordEdit {
+            command: EditCommand::MirrorBuildings,
         });
-        *interface = InterfaceState::Default;
     }
 
-    fn info_action(trigger: On<Pointer<Release>>) {
+    /// Toggle the [`InfoPanel`] of the first selected entity on action button press.
+    fn info_action(
+        trigger: On<Pointer<Release>>,
+        mut commands: Commands,
+        interface: Res<InterfaceState>,
+        mode: Option<Res<InfoMode>>,
+    ) {
         if trigger.button != PointerButton::Primary {
             return;
         }
         trace!("Info button was pressed.");
-        warn!("Info action not implemented");
+        if mode.is_some() {
+            commands.remove_resource::<InfoMode>();
+            return;
+        }
+        let InterfaceState::Selection(_, entities) = interface.as_ref() else {
+            warn!("Expected InterfaceState::Selection: {interface:?}");
+            return;
+        };
+        let Some(entity) = entities.first() else {
+            warn!("Expected a selected entity");
+            return;
+        };
+        commands.insert_resource(InfoMode::new(*entity));
     }
 
-    /// Remove the selected entity on action button press
+    /// Remove every selected entity on action button press
     fn remove_action(
         trigger: On<Pointer<Release>>,
         mut commands: Commands,
         mut interface: ResMut<InterfaceState>,
+        mut record: MessageWriter<RecordEdit>,
     ) {
         if trigger.button != PointerButton::Primary {
             return;
         }
         trace!("Remove button was pressed.");
-        let InterfaceState::Selection(_, entity) = *interface else {
+        let InterfaceState::Selection(_, entities) = interface.as_ref() else {
             warn!("Expected InterfaceState::Selection: {interface:?}");
             return;
         };
-        commands.entity(entity).despawn();
+        for entity in entities {
+            commands.entity(*entity).despawn();
+        }
+        record.write(RecordEdit {
+            command: EditCommand::Remove,
+        });
         *interface = InterfaceState::Default;
     }
 }
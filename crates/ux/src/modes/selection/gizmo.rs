@@ -0,0 +1,453 @@
+use crate::*;
+use bevy::color::palettes::tailwind;
+use bevy::prelude::*;
+use konstruo_architecture::ModularBuilding;
+use konstruo_beziers::constants::INTERSECTION_ACCURACY;
+use konstruo_distribution::{Distributable, Distribution};
+use konstruo_ui::{Cursor, PrimaryCamera, Snapping};
+use std::collections::BTreeSet;
+use std::f32::consts::FRAC_PI_2;
+use TransformHandle::*;
+
+/// Distance in metres from the center to the tip of each translate handle.
+const HANDLE_LENGTH: f32 = 8.0;
+
+/// Thickness in metres of each translate handle.
+const HANDLE_THICKNESS: f32 = 0.4;
+
+/// Width in metres of the square handle to translate on the XY plane.
+const PLANE_SIZE: f32 = 2.0;
+
+/// Radius in metres of the handle to rotate about Z.
+const RING_RADIUS: f32 = 10.0;
+
+/// Thickness in metres of the handle to rotate about Z.
+const RING_THICKNESS: f32 = 0.2;
+
+/// Distance in metres or angle in radians within which a building has not moved.
+const MOVE_TOLERANCE: f32 = 0.001;
+
+/// Elevation in metres of the gizmo above the ground to avoid z-fighting.
+const ELEVATION: f32 = 0.25;
+
+/// An in-viewport gizmo to translate the selected [`ModularBuilding`] on the XY plane
+/// and rotate them about Z.
+#[derive(Component)]
+#[require(InheritedVisibility, Transform)]
+pub struct TransformGizmo;
+
+/// A draggable handle of the [`TransformGizmo`].
+#[derive(Clone, Copy, Component, Debug, PartialEq)]
+pub enum TransformHandle {
+    /// Translate along the X axis.
+    TranslateX,
+    /// Translate along the Y axis.
+    TranslateY,
+    /// Translate freely on the XY plane.
+    TranslateXY,
+    /// Rotate about the Z axis.
+    RotateZ,
+}
+
+/// Meshes and materials of the [`TransformGizmo`].
+#[derive(Resource)]
+pub struct TransformGizmoAssets {
+    arrow: Handle<Mesh>,
+    plane: Handle<Mesh>,
+    ring: Handle<Mesh>,
+    x: Handle<StandardMaterial>,
+    y: Handle<StandardMaterial>,
+    xy: Handle<StandardMaterial>,
+    z: Handle<StandardMaterial>,
+}
+
+/// A drag of a [`TransformHandle`] in progress.
+#[derive(Resource)]
+struct TransformDrag {
+    handle: TransformHandle,
+    /// Center of the selected buildings when the drag started.
+    center: Vec3,
+    /// Cursor on the ground when the drag started.
+    start: Vec3,
+    /// Each selected building with its [`Transform`] and the center in its local space
+    /// when the drag started.
+    buildings: Vec<(Entity, Transform, Vec3)>,
+}
+
+impl TransformGizmoAssets {
+    /// System to insert the [`TransformGizmoAssets`] resource on startup.
+    pub(crate) fn startup_system(
+        mut commands: Commands,
+        mut meshes: ResMut<Assets<Mesh>>,
+        mut materials: ResMut<Assets<StandardMaterial>>,
+    ) {
+        let mut material = |color: Srgba| {
+            materials.add(StandardMaterial {
+                base_color: color.into(),
+                alpha_mode: AlphaMode::Opaque,
+                perceptual_roughness: 1.0,
+                unlit: true,
+                ..default()
+            })
+        };
+        let x = material(tailwind::RED_600);
+        let y = material(tailwind::GREEN_600);
+        let xy = material(tailwind::SKY_600);
+        let z = material(tailwind::AMBER_500);
+        commands.insert_resource(TransformGizmoAssets {
+            arrow: meshes.add(Cuboid::new(
+                HANDLE_LENGTH,
+                HANDLE_THICKNESS,
+                HANDLE_THICKNESS,
+            )),
+            plane: meshes.add(Cuboid::new(PLANE_SIZE, PLANE_SIZE, HANDLE_THICKNESS * 0.5)),
+            ring: meshes.add(Torus {
+                minor_radius: RING_THICKNESS,
+                major_radius: RING_RADIUS,
+            }),
+            x,
+            y,
+            xy,
+            z,
+        });
+    }
+}
+
+impl TransformGizmo {
+    /// System to spawn the [`TransformGizmo`] when [`SelectionMode::Building`] is active
+    /// and despawn it otherwise.
+    pub(crate) fn update_system(
+        mut commands: Commands,
+        interface: Res<InterfaceState>,
+        assets: Res<TransformGizmoAssets>,
+        gizmos: Query<Entity, With<TransformGizmo>>,
+    ) {
+        if !interface.is_changed() {
+            return;
+        }
+        let is_active = matches!(
+            interface.as_ref(),
+            InterfaceState::Selection(SelectionMode::Building, _)
+        );
+        if !is_active {
+            for entity in gizmos.iter() {
+                commands.entity(entity).despawn();
+            }
+            commands.remove_resource::<TransformDrag>();
+            return;
+        }
+        if !gizmos.is_empty() {
+            return;
+        }
+        commands
+            .spawn((TransformGizmo, Name::new("Transform Gizmo")))
+            .with_children(|parent| {
+                for handle in [TranslateX, TranslateY, TranslateXY, RotateZ] {
+                    parent
+                        .spawn(handle.bundle(&assets))
+                        .observe(TransformHandle::on_drag_start)
+                        .observe(TransformHandle::on_drag)
+                        .observe(TransformHandle::on_drag_end);
+                }
+            });
+    }
+
+    /// System to keep the [`TransformGizmo`] at the center of the selected buildings.
+    pub(crate) fn follow_system(
+        interface: Res<InterfaceState>,
+        mut gizmos: Query<&mut Transform, With<TransformGizmo>>,
+        buildings: Query<&GlobalTransform, With<ModularBuilding>>,
+    ) {
+        let InterfaceState::Selection(SelectionMode::Building, entities) = interface.as_ref()
+        else {
+            return;
+        };
+        let translations = entities
+            .iter()
+            .filter_map(|entity| buildings.get(*entity).ok())
+            .map(GlobalTransform::translation);
+        let Some(center) = get_center(translations) else {
+            return;
+        };
+        for mut transform in &mut gizmos {
+            transform.translation = center.with_z(ELEVATION);
+        }
+    }
+}
+
+impl TransformHandle {
+    /// Create a bundle for the [`TransformHandle`].
+    fn bundle(self, assets: &TransformGizmoAssets) -> impl Bundle {
+        let (mesh, material, transform) = match self {
+            TranslateX => (
+                &assets.arrow,
+                &assets.x,
+                Transform::from_xyz(HANDLE_LENGTH * 0.5, 0.0, 0.0),
+            ),
+            TranslateY => (
+                &assets.arrow,
+                &assets.y,
+                Transform::from_xyz(0.0, HANDLE_LENGTH * 0.5, 0.0)
+                    .with_rotation(Quat::from_rotation_z(FRAC_PI_2)),
+            ),
+            TranslateXY => (
+                &assets.plane,
+                &assets.xy,
+                Transform::from_xyz(PLANE_SIZE, PLANE_SIZE, 0.0),
+            ),
+            // The torus is created about the Y axis so it's rotated to be about Z.
+            RotateZ => (
+                &assets.ring,
+                &assets.z,
+                Transform::from_rotation(Quat::from_rotation_x(FRAC_PI_2)),
+            ),
+        };
+        (
+            self,
+            Mesh3d(mesh.clone()),
+            MeshMaterial3d(material.clone()),
+            transform,
+        )
+    }
+
+    /// Get the motion of a drag from `start` to `cursor`.
+    ///
+    /// The translation is constrained to the axis of the handle and snapped to the grid.
+    /// The rotation is about Z through the center and snapped to the angle increment.
+    fn get_motion(self, center: Vec3, start: Vec3, cursor: Vec3, snapping: &Snapping) -> Transform {
+        let delta = (cursor - start).with_z(0.0);
+        let translation = match self {
+            TranslateX => delta.with_y(0.0),
+            TranslateY => delta.with_x(0.0),
+            TranslateXY => delta,
+            RotateZ => {
+                let from = start - center;
+                let to = cursor - center;
+                let mut angle = to.y.atan2(to.x) - from.y.atan2(from.x);
+                if let (true, Some(increment)) = (snapping.is_enabled, snapping.angle) {
+                    angle = (angle / increment).round() * increment;
+                }
+                return Transform::from_rotation(Quat::from_rotation_z(angle));
+            }
+        };
+        let translation = match (snapping.is_enabled, snapping.grid) {
+            (true, Some(grid)) => {
+                let spacing = grid.get_spacing();
+                (translation / spacing).round() * spacing
+            }
+            _ => translation,
+        };
+        Transform::from_translation(translation)
+    }
+
+    /// Start a [`TransformDrag`] of the selected buildings.
+    fn on_drag_start(
+        trigger: On<Pointer<DragStart>>,
+        mut commands: Commands,
+        interface: Res<InterfaceState>,
+        handles: Query<&TransformHandle>,
+        buildings: Query<(&Transform, &GlobalTransform), With<ModularBuilding>>,
+        camera: Query<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        let InterfaceState::Selection(SelectionMode::Building, entities) = interface.as_ref()
+        else {
+            return;
+        };
+        let Ok(handle) = handles.get(trigger.original_event_target()) else {
+            error!("Failed to get TransformHandle");
+            return;
+        };
+        let Ok(start) = Cursor::from_position(&camera, trigger.pointer_location.position) else {
+            warn!("Failed to get cursor on ground");
+            return;
+        };
+        let translations = entities
+            .iter()
+            .filter_map(|entity| buildings.get(*entity).ok())
+            .map(|(_, global)| global.translation());
+        let Some(center) = get_center(translations) else {
+            return;
+        };
+        let buildings = entities
+            .iter()
+            .filter_map(|entity| {
+                let (transform, global) = buildings.get(*entity).ok()?;
+                // Parents of buildings are only translated so the offset is the same at any point.
+                let local_center = center - (global.translation() - transform.translation);
+                Some((*entity, *transform, local_center))
+            })
+            .collect();
+        commands.insert_resource(TransformDrag {
+            handle: *handle,
+            center,
+            start,
+            buildings,
+        });
+    }
+
+    /// Move the selected buildings with the cursor.
+    fn on_drag(
+        trigger: On<Pointer<Drag>>,
+        drag: Option<Res<TransformDrag>>,
+        snapping: Res<Snapping>,
+        camera: Query<(&Camera, &GlobalTransform), With<PrimaryCamera>>,
+        mut buildings: Query<&mut Transform, With<ModularBuilding>>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        let Some(drag) = drag else {
+            return;
+        };
+        let Ok(cursor) = Cursor::from_position(&camera, trigger.pointer_location.position) else {
+            warn!("Failed to get cursor on ground");
+            return;
+        };
+        let motion = drag
+            .handle
+            .get_motion(drag.center, drag.start, cursor, &snapping);
+        for (entity, start, local_center) in &drag.buildings {
+            let Ok(mut transform) = buildings.get_mut(*entity) else {
+                warn!("Failed to get ModularBuilding: {entity:?}");
+                continue;
+            };
+            let offset = start.translation - *local_center;
+            transform.translation = *local_center + motion.translation + motion.rotation * offset;
+            transform.rotation = motion.rotation * start.rotation;
+        }
+    }
+
+    /// Complete the [`TransformDrag`].
+    ///
+    /// Buildings in a [`Distribution`] are laid out by it so rather than keeping their
+    /// transform the order of each [`Distributable`] is updated to match the position
+    /// it was moved to. Any movement across the [`Distribution`] is kept as its offset and
+    /// any rotation along a spline is kept as its rotation.
+    ///
+    /// Nothing is recorded if no building was moved.
+    #[allow(clippy::type_complexity)]
+    fn on_drag_end(
+        trigger: On<Pointer<DragEnd>>,
+        mut commands: Commands,
+        drag: Option<Res<TransformDrag>>,
+        buildings: Query<&Transform, With<ModularBuilding>>,
+        mut siblings: Query<
+            (Entity, &ChildOf, &Transform, &mut Distributable),
+            With<ModularBuilding>,
+        >,
+        mut distributions: Query<&mut Distribution, Without<Distributable>>,
+        mut record: MessageWriter<RecordEdit>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        let Some(drag) = drag else {
+            return;
+        };
+        commands.remove_resource::<TransformDrag>();
+        let moved: Vec<(Entity, Vec3, Quat)> = drag
+            .buildings
+            .iter()
+            .filter_map(|(entity, start, _)| {
+                let transform = buildings.get(*entity).ok()?;
+                let is_moved = !transform
+                    .translation
+                    .abs_diff_eq(start.translation, MOVE_TOLERANCE)
+                    || !transform
+                        .rotation
+                        .abs_diff_eq(start.rotation, MOVE_TOLERANCE);
+                is_moved.then_some((
+                    *entity,
+                    transform.translation - start.translation,
+                    transform.rotation * start.rotation.inverse(),
+                ))
+            })
+            .collect();
+        if moved.is_empty() {
+            trace!("Buildings were not moved");
+            return;
+        }
+        let mut parents = BTreeSet::new();
+        for (entity, delta, turn) in moved {
+            let Ok((_, child_of, transform, mut distributable)) = siblings.get_mut(entity) else {
+                continue;
+            };
+            let parent = child_of.parent();
+            let Ok(distribution) = distributions.get(parent) else {
+                continue;
+            };
+            let rotation = get_rotation(distribution, transform.translation);
+            let across = (rotation.inverse() * delta).with_x(0.0);
+            distributable.offset = Some(distributable.offset.unwrap_or_default() + across);
+            if distribution.spline.is_some() && !turn.abs_diff_eq(Quat::IDENTITY, MOVE_TOLERANCE) {
+                distributable.rotation =
+                    Some((turn * distributable.rotation.unwrap_or_default()).normalize());
+            }
+            parents.insert(parent);
+        }
+        for parent in parents {
+            let Ok(mut distribution) = distributions.get_mut(parent) else {
+                warn!("Failed to get Distribution: {parent:?}");
+                continue;
+            };
+            reorder(parent, &distribution, &mut siblings);
+            distribution.set_changed();
+        }
+        record.write(RecordEdit {
+            command: EditCommand::TransformBuildings,
+        });
+    }
+}
+
+/// Get the center of the translations on the ground.
+fn get_center(translations: impl Iterator<Item = Vec3>) -> Option<Vec3> {
+    let translations: Vec<Vec3> = translations.collect();
+    if translations.is_empty() {
+        return None;
+    }
+    #[allow(clippy::as_conversions, clippy::cast_precision_loss)]
+    let count = translations.len() as f32;
+    let sum: Vec3 = translations.into_iter().sum();
+    Some((sum / count).with_z(0.0))
+}
+
+/// Get the rotation from the main axis of the [`Distribution`] to the direction of its
+/// spline at the point nearest to the translation.
+fn get_rotation(distribution: &Distribution, translation: Vec3) -> Quat {
+    let Some(spline) = &distribution.spline else {
+        return Quat::IDENTITY;
+    };
+    let param = spline.get_param_nearest_to(translation.with_z(0.0), INTERSECTION_ACCURACY);
+    Quat::from_rotation_arc(Vec3::X, spline.get_tangent_at_param(param))
+}
+
+/// Set the order of each [`Distributable`] child of the [`Distribution`] by its position
+/// along the spline, or along the X axis if it doesn't follow a spline.
+#[allow(clippy::type_complexity)]
+fn reorder(
+    parent: Entity,
+    distribution: &Distribution,
+    siblings: &mut Query<(Entity, &ChildOf, &Transform, &mut Distributable), With<ModularBuilding>>,
+) {
+    let mut positions: Vec<(f32, Entity)> = siblings
+        .iter()
+        .filter(|(_, child_of, _, _)| child_of.parent() == parent)
+        .map(|(entity, _, transform, _)| {
+            let position = match &distribution.spline {
+                Some(spline) => spline
+                    .get_param_nearest_to(transform.translation.with_z(0.0), INTERSECTION_ACCURACY),
+                None => transform.translation.x,
+            };
+            (position, entity)
+        })
+        .collect();
+    positions.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+    for (order, (_, entity)) in positions.into_iter().enumerate() {
+        if let Ok((_, _, _, mut distributable)) = siblings.get_mut(entity) {
+            distributable.order = order;
+        }
+    }
+}
@@ -1,4 +1,6 @@
+pub use gizmo::*;
 pub use r#enum::*;
 
 mod actions;
 mod r#enum;
+mod gizmo;
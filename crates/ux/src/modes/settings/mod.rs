@@ -0,0 +1,5 @@
+pub use panel::*;
+pub use resource::*;
+
+mod panel;
+mod resource;
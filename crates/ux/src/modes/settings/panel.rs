@@ -0,0 +1,164 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_ui::{
+    GridSnap, PrimaryCamera, Settings, Units, MAX_SHADOW_MAP_SIZE, MIN_SHADOW_MAP_SIZE,
+};
+
+const SPEED_STEP: f32 = 0.25;
+const MIN_SPEED: f32 = 0.25;
+const UNITS: [Units; 2] = [Units::Metric, Units::Imperial];
+const GRID_SNAPS: [GridSnap; 3] = [GridSnap::Minor, GridSnap::Medium, GridSnap::Major];
+
+/// Marker for the [`Panel`] of [`SettingsMode`].
+#[derive(Component)]
+pub struct SettingsPanel;
+
+impl SettingsMode {
+    /// System to respawn the [`SettingsPanel`] when [`SettingsMode`] or [`Settings`] are changed.
+    pub(crate) fn panel_system(
+        mut commands: Commands,
+        mode: Option<Res<SettingsMode>>,
+        settings: Res<Settings>,
+        assets: Res<AssetServer>,
+        panels: Query<Entity, With<SettingsPanel>>,
+        cameras: Query<Entity, With<PrimaryCamera>>,
+    ) {
+        let is_changed = mode.as_ref().is_none_or(Res::is_changed) || settings.is_changed();
+        if !is_changed {
+            return;
+        }
+        for entity in panels.iter() {
+            commands.entity(entity).despawn();
+        }
+        if mode.is_none() {
+            return;
+        }
+        let Ok(camera) = cameras.single() else {
+            warn!("Failed to get PrimaryCamera");
+            return;
+        };
+        let panel = Panel::spawn(&mut commands, &assets, camera, "Settings", rows(&settings));
+        commands.entity(panel).insert(SettingsPanel);
+    }
+}
+
+/// Get the [`PanelRow`] to edit the [`Settings`].
+fn rows(settings: &Settings) -> Vec<PanelRow> {
+    vec![
+        speed_row("Pan speed", settings.pan_speed, |settings| {
+            &mut settings.pan_speed
+        }),
+        speed_row("Zoom speed", settings.zoom_speed, |settings| {
+            &mut settings.zoom_speed
+        }),
+        speed_row("Orbit speed", settings.orbit_speed, |settings| {
+            &mut settings.orbit_speed
+        }),
+        toggle_row("Grid", settings.is_grid_visible, |settings| {
+            &mut settings.is_grid_visible
+        }),
+        toggle_row("Minor grid", settings.is_minor_grid_visible, |settings| {
+            &mut settings.is_minor_grid_visible
+        }),
+        toggle_row("Wireframe", settings.is_wireframe_visible, |settings| {
+            &mut settings.is_wireframe_visible
+        }),
+        toggle_row("Shadows", settings.is_shadows_enabled, |settings| {
+            &mut settings.is_shadows_enabled
+        }),
+        toggle_row("Snapping", settings.is_snapping_enabled, |settings| {
+            &mut settings.is_snapping_enabled
+        }),
+        PanelRow {
+            label: String::from("Grid snap"),
+            value: Some(
+                settings
+                    .units
+                    .format_length(settings.grid_snap.get_spacing()),
+            ),
+            buttons: GRID_SNAPS
+                .into_iter()
+                .map(|grid_snap| {
+                    edit_action(&format!("{grid_snap:?}"), move |settings| {
+                        settings.grid_snap = grid_snap;
+                    })
+                    .with_active(settings.grid_snap == grid_snap)
+                })
+                .collect(),
+        },
+        PanelRow {
+            label: String::from("Shadow map"),
+            value: Some(format!("{} px", settings.shadow_map_size)),
+            buttons: vec![
+                edit_action("-", |settings| {
+                    settings.shadow_map_size =
+                        (settings.shadow_map_size >> 1).max(MIN_SHADOW_MAP_SIZE);
+                }),
+                edit_action("+", |settings| {
+                    settings.shadow_map_size =
+                        (settings.shadow_map_size * 2).min(MAX_SHADOW_MAP_SIZE);
+                }),
+            ],
+        },
+        PanelRow {
+            label: String::from("SVG scale"),
+            value: Some(format!("{} m", settings.svg_scale)),
+            buttons: vec![
+                edit_action("-", |settings| settings.svg_scale *= 0.5),
+                edit_action("+", |settings| settings.svg_scale *= 2.0),
+            ],
+        },
+        PanelRow {
+            label: String::from("Units"),
+            value: None,
+            buttons: UNITS
+                .into_iter()
+                .map(|units| {
+                    edit_action(&format!("{units:?}"), move |settings| {
+                        settings.units = units;
+                    })
+                    .with_active(settings.units == units)
+                })
+                .collect(),
+        },
+    ]
+}
+
+/// Get a [`PanelRow`] to decrease or increase a speed.
+fn speed_row(label: &str, value: f32, field: fn(&mut Settings) -> &mut f32) -> PanelRow {
+    PanelRow {
+        label: label.to_owned(),
+        value: Some(format!("{value:.2}")),
+        buttons: vec![
+            edit_action("-", move |settings| {
+                let speed = field(settings);
+                *speed = (*speed - SPEED_STEP).max(MIN_SPEED);
+            }),
+            edit_action("+", move |settings| *field(settings) += SPEED_STEP),
+        ],
+    }
+}
+
+/// Get a [`PanelRow`] to turn a setting on or off.
+fn toggle_row(label: &str, value: bool, field: fn(&mut Settings) -> &mut bool) -> PanelRow {
+    PanelRow {
+        label: label.to_owned(),
+        value: None,
+        buttons: vec![
+            edit_action("On", move |settings| *field(settings) = true).with_active(value),
+            edit_action("Off", move |settings| *field(settings) = false).with_active(!value),
+        ],
+    }
+}
+
+fn edit_action(label: &str, edit: impl Fn(&mut Settings) + Send + Sync + 'static) -> PanelAction {
+    let observer = Observer::new(
+        move |trigger: On<Pointer<Release>>, mut settings: ResMut<Settings>| {
+            if trigger.button != PointerButton::Primary {
+                return;
+            }
+            edit(&mut settings);
+        },
+    );
+    PanelAction::new(label, observer)
+}
@@ -0,0 +1,19 @@
+use crate::*;
+use bevy::prelude::*;
+
+/// Display and edit the [`konstruo_ui::Settings`] in a [`SettingsPanel`].
+#[derive(Resource)]
+pub struct SettingsMode;
+
+impl SettingsMode {
+    /// System to remove [`SettingsMode`] when [`InterfaceState::Default`] is no longer active.
+    pub(crate) fn interface_system(
+        mut commands: Commands,
+        mode: Option<Res<SettingsMode>>,
+        interface: Res<InterfaceState>,
+    ) {
+        if mode.is_some() && *interface != InterfaceState::Default {
+            commands.remove_resource::<SettingsMode>();
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use crate::*;
+use bevy::prelude::*;
+use std::collections::BTreeSet;
+
+impl SurfacesMode {
+    /// Get the actions when [`SurfacesMode`] is active.
+    pub(crate) fn actions() -> Vec<Action> {
+        vec![Action {
+            label: String::from("Done"),
+            icon: Icon::font_awesome("check"),
+            on_press: Observer::new(SurfacesMode::done_action),
+        }]
+    }
+
+    /// Return to [`SelectionMode::Path`].
+    fn done_action(
+        trigger: On<Pointer<Release>>,
+        mut commands: Commands,
+        mut interface: ResMut<InterfaceState>,
+        mode: Res<SurfacesMode>,
+        mut record: MessageWriter<RecordEdit>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        trace!("Done button was pressed.");
+        *interface = InterfaceState::Selection(SelectionMode::Path, BTreeSet::from([mode.path]));
+        record.write(RecordEdit {
+            command: EditCommand::EditSurfaces,
+        });
+        commands.remove_resource::<SurfacesMode>();
+    }
+}
@@ -0,0 +1,257 @@
+use crate::*;
+use bevy::prelude::*;
+use konstruo_paths::*;
+use konstruo_ui::{PrimaryCamera, Settings, Units};
+use PathSurfacePosition::*;
+use PathSurfaceType::*;
+
+const WIDTH_STEP: f32 = 0.1;
+const DEPTH_STEP: f32 = 0.025;
+const OFFSET_STEP: f32 = 0.1;
+const MIN_WIDTH: f32 = 0.1;
+const MIN_DEPTH: f32 = 0.025;
+const PURPOSES: [PathSurfaceType; 3] = [Carriageway, Footway, Verge];
+const PROFILES: [(&str, PathSurfaceProfile); 5] = [
+    ("Flat", PathSurfaceProfile::Flat),
+    ("Camber", PathSurfaceProfile::Camber(0.050)),
+    ("Channel", PathSurfaceProfile::Channel(0.020)),
+    ("Kerb", PathSurfaceProfile::Kerb),
+    ("Dropped", PathSurfaceProfile::DroppedKerb(0.100)),
+];
+
+/// Marker for the [`Panel`] of [`SurfacesMode`].
+#[derive(Component)]
+pub struct SurfacesPanel;
+
+impl SurfacesMode {
+    /// System to respawn the [`SurfacesPanel`] when [`SurfacesMode`], [`Settings`] or a
+    /// [`PathSurface`] is changed.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn panel_system(
+        mut commands: Commands,
+        mode: Option<Res<SurfacesMode>>,
+        settings: Res<Settings>,
+        assets: Res<AssetServer>,
+        surfaces: Query<(Entity, Ref<PathSurface>, &ChildOf)>,
+        mut removed: RemovedComponents<PathSurface>,
+        panels: Query<Entity, With<SurfacesPanel>>,
+        cameras: Query<Entity, With<PrimaryCamera>>,
+    ) {
+        let is_removed = removed.read().count() > 0;
+        let is_changed = mode.as_ref().is_none_or(Res::is_changed)
+            || settings.is_changed()
+            || is_removed
+            || surfaces.iter().any(|(_, surface, _)| surface.is_changed());
+        if !is_changed {
+            return;
+        }
+        for entity in panels.iter() {
+            commands.entity(entity).despawn();
+        }
+        let Some(mode) = mode else {
+            return;
+        };
+        let Ok(camera) = cameras.single() else {
+            warn!("Failed to get PrimaryCamera");
+            return;
+        };
+        let surfaces: Vec<(Entity, &PathSurfaceInfo)> = surfaces
+            .iter()
+            .filter(|(_, _, child_of)| child_of.parent() == mode.path)
+            .map(|(entity, surface, _)| (entity, surface.into_inner().get_info()))
+            .collect();
+        let rows = mode.rows(&surfaces, settings.units);
+        let panel = Panel::spawn(&mut commands, &assets, camera, "Surfaces", rows);
+        commands.entity(panel).insert(SurfacesPanel);
+    }
+
+    /// Get the [`PanelRow`] to add, remove and edit surfaces.
+    fn rows(&self, surfaces: &[(Entity, &PathSurfaceInfo)], units: Units) -> Vec<PanelRow> {
+        let mut rows = Vec::new();
+        for (entity, info) in surfaces {
+            rows.push(PanelRow {
+                label: format!("{:?}", info.purpose),
+                value: Some(units.format_length(info.width)),
+                buttons: vec![
+                    select_action(*entity).with_active(self.selected == Some(*entity)),
+                    remove_action(*entity),
+                ],
+            });
+        }
+        rows.push(PanelRow {
+            label: String::from("Add"),
+            value: None,
+            buttons: PURPOSES.into_iter().map(add_action).collect(),
+        });
+        let Some((entity, info)) = surfaces
+            .iter()
+            .find(|(entity, _)| self.selected == Some(*entity))
+        else {
+            return rows;
+        };
+        rows.append(&mut edit_rows(*entity, info, units));
+        rows
+    }
+}
+
+/// Get the [`PanelRow`] to edit a single surface.
+fn edit_rows(entity: Entity, info: &PathSurfaceInfo, units: Units) -> Vec<PanelRow> {
+    let mut position_buttons = vec![
+        edit_action("Centered", entity, |info| info.position = Centered)
+            .with_active(info.position == Centered),
+        edit_action("Offset", entity, |info| {
+            if info.position == Centered {
+                info.position = Offset(0.0);
+            }
+        })
+        .with_active(info.position != Centered),
+    ];
+    if let Offset(_) = info.position {
+        position_buttons.push(edit_action("-", entity, |info| {
+            if let Offset(offset) = info.position {
+                info.position = Offset(offset - OFFSET_STEP);
+            }
+        }));
+        position_buttons.push(edit_action("+", entity, |info| {
+            if let Offset(offset) = info.position {
+                info.position = Offset(offset + OFFSET_STEP);
+            }
+        }));
+    }
+    let position = match info.position {
+        Centered => String::from("Centered"),
+        Offset(offset) => units.format_length(offset),
+    };
+    vec![
+        PanelRow {
+            label: String::from("Type"),
+            value: None,
+            buttons: PURPOSES
+                .into_iter()
+                .map(|purpose| {
+                    edit_action(&format!("{purpose:?}"), entity, move |info| {
+                        info.purpose = purpose;
+                    })
+                    .with_active(info.purpose == purpose)
+                })
+                .collect(),
+        },
+        PanelRow {
+            label: String::from("Width"),
+            value: Some(units.format_length(info.width)),
+            buttons: vec![
+                edit_action("-", entity, |info| {
+                    info.width = (info.width - WIDTH_STEP).max(MIN_WIDTH);
+                }),
+                edit_action("+", entity, |info| info.width += WIDTH_STEP),
+            ],
+        },
+        PanelRow {
+            label: String::from("Depth"),
+            value: Some(units.format_length(info.depth)),
+            buttons: vec![
+                edit_action("-", entity, |info| {
+                    info.depth = (info.depth - DEPTH_STEP).max(MIN_DEPTH);
+                }),
+                edit_action("+", entity, |info| info.depth += DEPTH_STEP),
+            ],
+        },
+        PanelRow {
+            label: String::from("Position"),
+            value: Some(position),
+            buttons: position_buttons,
+        },
+        PanelRow {
+            label: String::from("Profile"),
+            value: None,
+            buttons: PROFILES
+                .into_iter()
+                .map(|(label, profile)| {
+                    edit_action(label, entity, move |info| info.profile = profile)
+                        .with_active(info.profile == profile)
+                })
+                .collect(),
+        },
+    ]
+}
+
+fn select_action(entity: Entity) -> PanelAction {
+    let observer = Observer::new(
+        move |trigger: On<Pointer<Release>>, mut mode: ResMut<SurfacesMode>| {
+            if trigger.button != PointerButton::Primary {
+                return;
+            }
+            mode.selected = Some(entity);
+        },
+    );
+    PanelAction::new("Edit", observer)
+}
+
+fn remove_action(entity: Entity) -> PanelAction {
+    let observer = Observer::new(
+        move |trigger: On<Pointer<Release>>,
+              mut commands: Commands,
+              mut mode: ResMut<SurfacesMode>| {
+            if trigger.button != PointerButton::Primary {
+                return;
+            }
+            commands.entity(entity).despawn();
+            if mode.selected == Some(entity) {
+                mode.selected = None;
+            }
+        },
+    );
+    PanelAction::new("Remove", observer)
+}
+
+fn add_action(purpose: PathSurfaceType) -> PanelAction {
+    let observer = Observer::new(
+        move |trigger: On<Pointer<Release>>,
+              mut mode: ResMut<SurfacesMode>,
+              commands: Commands,
+              meshes: ResMut<Assets<Mesh>>,
+              path_meshes: Res<PathMeshes>,
+              materials: Res<PathMaterials>,
+              paths: Query<&Path>| {
+            if trigger.button != PointerButton::Primary {
+                return;
+            }
+            let Ok(path) = paths.get(mode.path) else {
+                warn!("Failed to get Path: {:?}", mode.path);
+                return;
+            };
+            let mut factory = PathFactory {
+                commands,
+                meshes,
+                path_meshes,
+                materials,
+            };
+            let surface = PathSurface::from_info(PathSurfaceInfo::from_purpose(purpose));
+            let entity = factory.spawn_surface(surface, path, mode.path);
+            mode.selected = Some(entity);
+        },
+    );
+    PanelAction::new(&format!("{purpose:?}"), observer)
+}
+
+fn edit_action(
+    label: &str,
+    entity: Entity,
+    edit: impl Fn(&mut PathSurfaceInfo) + Send + Sync + 'static,
+) -> PanelAction {
+    let observer = Observer::new(
+        move |trigger: On<Pointer<Release>>, mut surfaces: Query<&mut PathSurface>| {
+            if trigger.button != PointerButton::Primary {
+                return;
+            }
+            let Ok(mut surface) = surfaces.get_mut(entity) else {
+                warn!("Failed to get PathSurface: {entity:?}");
+                return;
+            };
+            let mut info = surface.get_info().clone();
+            edit(&mut info);
+            surface.set_info(info);
+        },
+    );
+    PanelAction::new(label, observer)
+}
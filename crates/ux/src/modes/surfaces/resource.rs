@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+
+/// Add, remove and edit the [`konstruo_paths::PathSurface`] of a [`konstruo_paths::Path`].
+#[derive(Resource)]
+pub struct SurfacesMode {
+    /// The path to edit.
+    pub(super) path: Entity,
+    /// The surface being edited.
+    pub(super) selected: Option<Entity>,
+}
+
+impl SurfacesMode {
+    /// Create a new [`SurfacesMode`] without a surface selected.
+    pub(crate) fn new(path: Entity) -> Self {
+        Self {
+            path,
+            selected: None,
+        }
+    }
+}
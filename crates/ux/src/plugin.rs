@@ -7,18 +7,48 @@ use bevy::prelude::*;
 ///
 /// Which handle different interaction modes including:
 /// - [`DrawMode`]
+/// - [`AddBuildingsMode`]
+/// - [`SurfacesMode`]
+/// - [`NodesMode`]
 /// - [`SelectionMode`]
+/// - [`InfoMode`]
+/// - [`SettingsMode`]
+///
+/// Selected buildings are moved with a [`TransformGizmo`].
+///
+/// Edits are recorded in [`History`] so they can be undone:
+/// - `Ctrl + Z` to undo
+/// - `Ctrl + Shift + Z` or `Ctrl + Y` to redo
 pub struct UxPlugin;
 
 impl Plugin for UxPlugin {
     fn build(&self, app: &mut App) {
-        app.insert_resource(InterfaceState::Default)
+        app.add_message::<RecordEdit>()
+            .add_message::<RedoEdit>()
+            .add_message::<UndoEdit>()
+            .init_resource::<History>()
+            .insert_resource(InterfaceState::Default)
             .add_systems(PostStartup, ActionBar::startup_system)
             .add_systems(PostStartup, Interceptor::startup_system)
+            .add_systems(Startup, TransformGizmoAssets::startup_system)
             .add_systems(Update, InterfaceState::on_entity_state_changed)
             .add_systems(Update, Interceptor::update_system)
+            .add_systems(Update, InterfaceState::box_select_system)
             .add_systems(Update, InterfaceState::on_entity_state_changed)
             .add_systems(Update, ActionBar::update_system)
-            .add_systems(Update, DrawMode::update_system);
+            .add_systems(Update, DrawMode::update_system)
+            .add_systems(Update, DrawMode::warning_system)
+            .add_systems(Update, AddBuildingsMode::panel_system)
+            .add_systems(Update, SurfacesMode::panel_system)
+            .add_systems(Update, InfoMode::selection_system)
+            .add_systems(Update, InfoMode::panel_system)
+            .add_systems(Update, SettingsMode::interface_system)
+            .add_systems(Update, SettingsMode::panel_system)
+            .add_systems(Update, TransformGizmo::update_system)
+            .add_systems(Update, TransformGizmo::follow_system)
+            .add_systems(Update, History::shortcut_system)
+            .add_systems(Update, History::on_undo)
+            .add_systems(Update, History::on_redo)
+            .add_systems(PostUpdate, History::record_system);
     }
 }
@@ -1,27 +1,40 @@
 use crate::*;
 use bevy::prelude::*;
 
+const TEMPLATE: &str = "4b8p2s2g";
+
 pub struct BuildingsExample;
 
 impl Plugin for BuildingsExample {
     fn build(&self, app: &mut App) {
-        app.add_systems(PostStartup, Self::startup_system);
+        app.add_systems(Update, Self::templates_loaded_system);
     }
 }
 
 impl BuildingsExample {
-    fn startup_system(
+    fn templates_loaded_system(
         commands: Commands,
         meshes: ResMut<Assets<Mesh>>,
         building_meshes: Res<BuildingMeshes>,
         materials: Res<BuildingMaterials>,
+        templates: Res<BuildingTemplates>,
+        assets: Res<Assets<ModularBuildingInfo>>,
+        mut spawned: Local<bool>,
     ) {
+        if *spawned || !templates.is_loaded() {
+            return;
+        }
+        let template = templates
+            .get(TEMPLATE, &assets)
+            .expect("template should exist")
+            .clone();
         let mut factory = ModularBuildingFactory {
             commands,
             meshes,
             building_meshes,
             materials,
         };
-        factory.spawn(BuildingTemplates::_4b8p2s2g());
+        factory.spawn_template(TEMPLATE, template);
+        *spawned = true;
     }
 }
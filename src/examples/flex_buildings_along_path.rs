@@ -16,7 +16,7 @@ struct State {
 impl Plugin for FlexBuildingsAlongPathExample {
     fn build(&self, app: &mut App) {
         app.insert_resource(State::default())
-            .add_systems(Update, path_added_system);
+            .add_systems(Update, templates_loaded_system);
     }
 }
 
@@ -26,11 +26,21 @@ impl Default for State {
     }
 }
 
-fn spawn_example(factory: &mut ModularBuildingFactory<'_>, entity: Entity, path: &Path) -> Entity {
+fn spawn_example(
+    factory: &mut ModularBuildingFactory<'_>,
+    templates: &BuildingTemplates,
+    assets: &Assets<ModularBuildingInfo>,
+    entity: Entity,
+    path: &Path,
+) -> Entity {
     let distribution = distribution_bundle(path, entity);
     let distribution_entity = factory.commands.spawn(distribution).id();
-    for (distributable, building) in get_items() {
-        let plot = factory.spawn(building);
+    for (distributable, name) in get_items() {
+        let building = templates
+            .get(name, assets)
+            .expect("template should exist")
+            .clone();
+        let plot = factory.spawn_template(name, building);
         factory
             .commands
             .entity(plot)
@@ -56,20 +66,24 @@ fn distribution_bundle(path: &Path, parent: Entity) -> impl Bundle {
             spline: Some(spline),
             spline_offset: Some(SPLINE_OFFSET),
             translate_to_ground: true,
+            ..default()
         },
         ChildOf(parent),
     )
 }
 
-fn path_added_system(
+#[allow(clippy::too_many_arguments)]
+fn templates_loaded_system(
     commands: Commands,
     mut state: ResMut<State>,
     meshes: ResMut<Assets<Mesh>>,
     building_meshes: Res<BuildingMeshes>,
     materials: Res<BuildingMaterials>,
-    query: Query<(Entity, &Path), Added<Path>>,
+    templates: Res<BuildingTemplates>,
+    assets: Res<Assets<ModularBuildingInfo>>,
+    query: Query<(Entity, &Path)>,
 ) {
-    if !state.enabled {
+    if !state.enabled || !templates.is_loaded() {
         return;
     }
     let mut factory = ModularBuildingFactory {
@@ -79,54 +93,25 @@ fn path_added_system(
         materials,
     };
     for (entity, path) in query.iter() {
-        spawn_example(&mut factory, entity, path);
+        spawn_example(&mut factory, &templates, &assets, entity, path);
         state.enabled = false;
     }
 }
 
-fn get_items() -> Vec<(Distributable, ModularBuildingInfo)> {
-    vec![
-        (
-            Distributable {
-                order: 0,
-                ..default()
-            },
-            BuildingTemplates::_2b4p1s0g(),
-        ),
-        (
-            Distributable {
-                order: 1,
-                ..default()
-            },
-            BuildingTemplates::_2b4p1s0g_perp(),
-        ),
-        (
-            Distributable {
-                order: 2,
-                ..default()
-            },
-            BuildingTemplates::_3b6p2s2g(),
-        ),
-        (
-            Distributable {
-                order: 3,
-                ..default()
-            },
-            BuildingTemplates::_3b6p2s2g_perp(),
-        ),
-        (
-            Distributable {
-                order: 4,
-                ..default()
-            },
-            BuildingTemplates::_4b8p2s2g(),
-        ),
-        (
-            Distributable {
-                order: 5,
-                ..default()
-            },
-            BuildingTemplates::_4b8p2s2g_perp(),
-        ),
+fn get_items() -> Vec<(Distributable, &'static str)> {
+    [
+        "2b4p1s0g",
+        "2b4p1s0g_perp",
+        "3b6p2s2g",
+        "3b6p2s2g_perp",
+        "4b8p2s2g",
+        "4b8p2s2g_perp",
     ]
+    .into_iter()
+    .enumerate()
+    .map(|(order, name)| {
+        let distributable = Distributable { order, ..default() };
+        (distributable, name)
+    })
+    .collect()
 }
@@ -4,6 +4,7 @@ use konstruo_paths::PathSurfacePosition::Centered;
 use konstruo_paths::PathSurfaceType::Carriageway;
 use konstruo_paths::{
     Path, PathFactory, PathIntersectionBuilder, PathMaterials, PathMeshes, PathSurfaceInfo,
+    PathSurfaceProfile,
 };
 
 pub struct IntersectionExample;
@@ -39,6 +40,7 @@ impl IntersectionExample {
             depth: 0.025,
             position: Centered,
             purpose: Carriageway,
+            profile: PathSurfaceProfile::Flat,
         };
         for spline in splines {
             let path = Path::new(spline);
@@ -1,6 +1,5 @@
 use crate::examples::ExampleMaterials;
 use bevy::prelude::*;
-use konstruo_core::{Vec3Extensions, VecVec3Extensions};
 use konstruo_geometry::*;
 
 pub struct SubdivisionExample;
@@ -18,21 +17,21 @@ impl SubdivisionExample {
         mut materials: ResMut<Assets<StandardMaterial>>,
     ) {
         let subdivision = Subdivision::example();
-        let regions = subdivision.execute().expect("should be valid");
-        for region in regions {
-            spawn_rectangle(&mut commands, &mut meshes, &mut materials, region);
+        let triangles = subdivision.execute().expect("should be valid");
+        for triangle in triangles.get_triangles() {
+            spawn_triangle(&mut commands, &mut meshes, &mut materials, triangle.clone());
         }
     }
 }
 
-fn spawn_rectangle(
+fn spawn_triangle(
     commands: &mut Commands,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<StandardMaterial>>,
-    rectangle: [Vec3; 4],
+    triangle: Triangle,
 ) {
-    let triangles = Triangle::from_rectangle(rectangle);
-    let is_ccw = rectangle.is_ccw(Vec3::Z).expect("should be valid");
+    let is_ccw = triangle.get_normal().z > 0.0;
+    let lines = LineList::from_lines(triangle.to_lines().to_vec());
     let material = if is_ccw {
         ExampleMaterials::blue_face_transparent()
     } else {
@@ -41,21 +40,18 @@ fn spawn_rectangle(
     let bundle = (
         Solid,
         MeshMaterial3d(materials.add(material)),
-        Mesh3d(meshes.add(TriangleList::new(triangles.to_vec()).to_mesh())),
+        Mesh3d(meshes.add(TriangleList::new(vec![triangle]).to_mesh())),
+    );
+    commands.spawn(bundle);
+    let material = if is_ccw {
+        ExampleMaterials::blue_edge()
+    } else {
+        ExampleMaterials::red_edge()
+    };
+    let bundle = (
+        Wireframe,
+        MeshMaterial3d(materials.add(material)),
+        Mesh3d(meshes.add(lines.to_mesh())),
     );
     commands.spawn(bundle);
-    for triangle in triangles {
-        let material = if is_ccw {
-            ExampleMaterials::blue_edge()
-        } else {
-            ExampleMaterials::red_edge()
-        };
-        let lines = LineList::from_lines(triangle.to_lines().to_vec());
-        let bundle = (
-            Wireframe,
-            MeshMaterial3d(materials.add(material)),
-            Mesh3d(meshes.add(lines.to_mesh())),
-        );
-        commands.spawn(bundle);
-    }
 }
@@ -12,6 +12,7 @@ use konstruo_architecture::*;
 use konstruo_distribution::DistributionPlugin;
 use konstruo_environment::*;
 use konstruo_paths::*;
+use konstruo_project::ProjectPlugin;
 use konstruo_ui::*;
 use konstruo_ux::*;
 use std::collections::HashMap;
@@ -29,8 +30,11 @@ fn main() {
         .add_plugins(OriginMarkerPlugin)
         .add_plugins(PanOrbitCameraPlugin)
         .add_plugins(PressedKeysPlugin)
+        .add_plugins(ProjectPlugin)
         .add_plugins(SkyPlugin)
         .add_plugins(SelectionPlugin)
+        .add_plugins(SettingsPlugin)
+        .add_plugins(SnapPlugin)
         .add_plugins(SunPlugin)
         .add_plugins(UxPlugin)
         .add_plugins(ViewCubePlugin)